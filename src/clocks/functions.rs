@@ -6,10 +6,11 @@ use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::clocks::{AllClocks, Clocks};
 use crate::Opts;
+use crate::output::{self, OutputFormat};
 
 impl AllClocks {
     pub async fn perform_snapshot(
@@ -43,17 +44,21 @@ impl AllClocks {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut clocks = AllClocks::read_http(host, port);
-                        clocks.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
-                        clocks.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
-                        tx.send(clocks).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut clocks = match AllClocks::read_http(host, port) {
+                        Ok(clocks) => clocks,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    clocks.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
+                    clocks.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
+                    tx.send(clocks).expect("error sending data via tx");
+                });
             }
         });
 
@@ -73,10 +78,10 @@ impl AllClocks {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<Clocks>
+    ) -> Result<Vec<Clocks>>
     {
-        let data_from_http = utility::http_get(host, port, "tablet-server-clocks");
-        AllClocks::parse_clocks(data_from_http)
+        let data_from_http = utility::http_get(host, port, "tablet-server-clocks").into_body()?;
+        Ok(AllClocks::parse_clocks(data_from_http))
     }
     fn parse_clocks(
         http_data: String,
@@ -157,14 +162,30 @@ impl AllClocks {
         }
         clocks
     }
+    /// The clocks rows of the master leader, or of all the masters if details are enabled.
+    fn leader_or_all_rows(
+        &self,
+        details_enable: &bool,
+        leader_hostname: &str,
+    ) -> Vec<&Clocks>
+    {
+        self.clocks.iter()
+            .filter(|row| row.hostname_port.as_deref() == Some(leader_hostname) || *details_enable)
+            .collect()
+    }
     pub fn print(
         &self,
         details_enable: &bool,
         leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
         info!("print tablet server clocks");
 
+        if !output.is_table()
+        {
+            return output::print_rows(output, "clocks", &self.leader_or_all_rows(details_enable, &leader_hostname));
+        }
 
         if *details_enable
         {
@@ -222,10 +243,15 @@ impl AllClocks {
         &self,
         details_enable: &bool,
         leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
         info!("print adhoc tablet servers clocks latency");
 
+        if !output.is_table()
+        {
+            return output::print_rows(output, "latencies", &self.leader_or_all_rows(details_enable, &leader_hostname));
+        }
 
         for row in &self.clocks {
             if row.hostname_port == Some(leader_hostname.clone())
@@ -255,12 +281,12 @@ pub async fn print_clocks(
             allclocks.clocks = snapshot::read_snapshot_json(snapshot_number, "clocks")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
 
-            allclocks.print(&options.details_enable, leader_hostname)?;
+            allclocks.print(&options.details_enable, leader_hostname, &options.output)?;
         },
         None => {
             let allclocks = AllClocks::read_clocks(&hosts, &ports, parallel).await?;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
-            allclocks.print(&options.details_enable, leader_hostname)?;
+            allclocks.print(&options.details_enable, leader_hostname, &options.output)?;
         },
     }
     Ok(())
@@ -279,13 +305,13 @@ pub async fn print_latencies(
             allclocks.clocks = snapshot::read_snapshot_json(snapshot_number, "clocks")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
 
-            allclocks.print_latency(&options.details_enable, leader_hostname).await?;
+            allclocks.print_latency(&options.details_enable, leader_hostname, &options.output).await?;
         },
         None => {
             let allstoredclocks = AllClocks::read_clocks(&hosts, &ports, parallel).await?;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
 
-            allstoredclocks.print_latency(&options.details_enable, leader_hostname).await?;
+            allstoredclocks.print_latency(&options.details_enable, leader_hostname, &options.output).await?;
         },
     }
     Ok(())
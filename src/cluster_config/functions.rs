@@ -5,10 +5,11 @@ use std::{sync::mpsc::channel, time::Instant};
 use log::*;
 use anyhow::{Result, Context};
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::cluster_config::{AllSysClusterConfigEntryPB, SysClusterConfigEntryPB};
 use crate::Opts;
+use crate::output::{self, OutputFormat};
 
 impl SysClusterConfigEntryPB {
     fn new() -> Self { Default::default() }
@@ -47,17 +48,21 @@ impl AllSysClusterConfigEntryPB {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut cluster_config = AllSysClusterConfigEntryPB::read_http(host, port);
-                        cluster_config.timestamp = Some(detail_snapshot_time);
-                        cluster_config.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(cluster_config).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut cluster_config = match AllSysClusterConfigEntryPB::read_http(host, port) {
+                        Ok(cluster_config) => cluster_config,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    cluster_config.timestamp = Some(detail_snapshot_time);
+                    cluster_config.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(cluster_config).expect("error sending data via tx");
+                });
             }
         });
 
@@ -76,10 +81,10 @@ impl AllSysClusterConfigEntryPB {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> SysClusterConfigEntryPB
+    ) -> Result<SysClusterConfigEntryPB>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/cluster-config");
-        AllSysClusterConfigEntryPB::parse_cluster_config(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "api/v1/cluster-config").into_body()?;
+        Ok(AllSysClusterConfigEntryPB::parse_cluster_config(data_from_http, host, port))
     }
     fn parse_cluster_config(
         http_data: String,
@@ -94,15 +99,19 @@ impl AllSysClusterConfigEntryPB {
     }
     pub fn print(
         &self,
-        leader_hostname: String
+        leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
-
-        println!("{}", serde_json::to_string_pretty( &self.sysclusterconfigentrypb
+        let leader_row = self.sysclusterconfigentrypb
             .iter()
             .find(|r| r.hostname_port == Some(leader_hostname.clone()))
-            .with_context(|| "Unable to find current master leader")?
-        )?);
+            .with_context(|| "Unable to find current master leader")?;
+        if !output.is_table()
+        {
+            return output::print_rows(output, "cluster_config", &[leader_row]);
+        }
+        println!("{}", serde_json::to_string_pretty(leader_row)?);
         Ok(())
     }
 }
@@ -121,13 +130,13 @@ pub async fn print_cluster_config(
             allsysclusterconfigentrypb.sysclusterconfigentrypb = snapshot::read_snapshot_json(snapshot_number, "cluster-config")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
 
-            allsysclusterconfigentrypb.print(leader_hostname)?;
+            allsysclusterconfigentrypb.print(leader_hostname, &options.output)?;
 
         }
         None => {
             let allsysclusterconfigentrypb = AllSysClusterConfigEntryPB::read_cluster_config(&hosts, &ports, parallel).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
-            allsysclusterconfigentrypb.print(leader_hostname)?;
+            allsysclusterconfigentrypb.print(leader_hostname, &options.output)?;
         }
     }
     Ok(())
@@ -0,0 +1,266 @@
+//! The impls and functions
+//!
+use std::{collections::HashMap, time::Instant};
+use log::*;
+use anyhow::{bail, Result};
+use rayon::prelude::*;
+use crate::utility::{self, Endpoint, Role};
+use crate::masters::{Masters, PeerRole};
+use crate::tablet_servers::AllTabletServers;
+use crate::vars::{AllVars, Vars};
+use crate::output;
+use crate::Opts;
+
+/// The node_exporter port, which cannot be discovered, and therefore is probed on every host.
+const NODE_EXPORTER_PORT: &str = "9300";
+/// The default YSQL webserver port, if the flag `pgsql_proxy_webserver_port` cannot be read.
+const YSQL_WEBSERVER_PORT: &str = "13000";
+/// The default YCQL webserver port, if the flag `cql_proxy_webserver_port` cannot be read.
+const YCQL_WEBSERVER_PORT: &str = "12000";
+
+/// Split a `hostname:port` into hostname and port.
+/// The split is done on the last colon, so an IPv6 address keeps its colons.
+fn split_hostname_port(
+    hostname_port: &str,
+) -> Option<(&str, &str)>
+{
+    hostname_port.rsplit_once(':')
+}
+
+/// Create the master endpoints from the http addresses in `/api/v1/masters`,
+/// and return the master leader http address too.
+fn master_endpoints(
+    masters: &Masters,
+) -> (Vec<Endpoint>, Option<(String, String)>)
+{
+    let mut endpoints = Vec::new();
+    let mut leader = None;
+    for master in &masters.masters {
+        let http_address = master.registration.as_ref()
+            .and_then(|registration| registration.http_addresses.as_ref())
+            .and_then(|http_addresses| http_addresses.first());
+        if let Some(http_address) = http_address {
+            let port = http_address.port.to_string();
+            if master.role == Some(PeerRole::LEADER) {
+                leader = Some((http_address.host.clone(), port.clone()));
+            }
+            endpoints.push(Endpoint::new(&http_address.host, &port, Role::Master));
+        }
+    }
+    (endpoints, leader)
+}
+
+/// Create the endpoints for a tablet server: the tablet server itself,
+/// and the YSQL and YCQL webservers if these are enabled in the flags read from `/api/v1/varz`.
+fn tablet_server_endpoints(
+    host: &str,
+    port: &str,
+    vars: &Vars,
+) -> Vec<Endpoint>
+{
+    let flag = |name: &str| vars.flags.iter()
+        .find(|flag| flag.name == name)
+        .map(|flag| flag.value.as_str());
+
+    let mut endpoints = vec![Endpoint::new(host, port, Role::Tserver)];
+    if flag("enable_ysql") != Some("false") {
+        endpoints.push(Endpoint::new(host, flag("pgsql_proxy_webserver_port").unwrap_or(YSQL_WEBSERVER_PORT), Role::Ysql));
+    }
+    if flag("start_cql_proxy") != Some("false") {
+        endpoints.push(Endpoint::new(host, flag("cql_proxy_webserver_port").unwrap_or(YCQL_WEBSERVER_PORT), Role::Ycql));
+    }
+    endpoints
+}
+
+/// Create the node_exporter endpoint for a host, if `/metrics` can be read on the port.
+///
+/// The probe uses [utility::http_fetch], so a host without node_exporter is not reported as a failed request.
+fn node_exporter_endpoint(
+    host: &str,
+    port: &str,
+) -> Option<Endpoint>
+{
+    let result = utility::http_fetch(host, port, "metrics");
+    if let Some(error_kind) = result.error_kind {
+        info!("No node_exporter found at: {}:{}, {:?}: {}", host, port, error_kind, result.error.unwrap_or_default());
+        return None;
+    }
+    Some(Endpoint::new(host, port, Role::NodeExporter))
+}
+
+/// Read the endpoints of the cluster from the given master.
+///
+/// This function performs blocking http reads, and therefore must not be called on the async runtime.
+fn read_endpoints(
+    hostname_port: &str,
+) -> Result<Vec<Endpoint>>
+{
+    let Some((host, port)) = split_hostname_port(hostname_port) else {
+        bail!("Invalid master address: {}, use hostname:port", hostname_port);
+    };
+    let masters = Masters::read_http(host, port)?;
+    if masters.masters.is_empty() {
+        bail!("No masters found at: {}:{}/api/v1/masters", host, port);
+    }
+    let (mut endpoints, leader) = master_endpoints(&masters);
+    let (leader_host, leader_port) = leader.unwrap_or_else(|| {
+        warn!("No master leader found, reading the tablet servers from: {}:{}", host, port);
+        (host.to_string(), port.to_string())
+    });
+    info!("master leader: {}:{}", leader_host, leader_port);
+
+    let tablet_servers = AllTabletServers::read_http(&leader_host, &leader_port)?;
+    let tablet_server_endpoints: Vec<Endpoint> = tablet_servers.tabletservers.par_iter()
+        .filter_map(|(tablet_server, _)| split_hostname_port(tablet_server))
+        .flat_map(|(host, port)| {
+            let vars = AllVars::read_http(host, port).unwrap_or_else(|error| {
+                warn!("{:#}, using the default ysql and ycql webserver ports for: {}:{}", error, host, port);
+                Vars::default()
+            });
+            tablet_server_endpoints(host, port, &vars)
+        })
+        .collect();
+    endpoints.extend(tablet_server_endpoints);
+
+    let mut hosts: Vec<String> = endpoints.iter().map(|endpoint| endpoint.host.clone()).collect();
+    hosts.sort();
+    hosts.dedup();
+    let node_exporter_endpoints: Vec<Endpoint> = hosts.par_iter()
+        .filter_map(|host| node_exporter_endpoint(host, NODE_EXPORTER_PORT))
+        .collect();
+    endpoints.extend(node_exporter_endpoints);
+
+    endpoints.sort_by(|a, b| (&a.host, a.role, &a.port).cmp(&(&b.host, b.role, &b.port)));
+    Ok(endpoints)
+}
+
+/// Return the unique hosts and ports of the endpoints, in the order in which they are encountered.
+pub fn hosts_and_ports(
+    endpoints: &[Endpoint],
+) -> (Vec<String>, Vec<String>)
+{
+    let mut hosts: Vec<String> = Vec::new();
+    let mut ports: Vec<String> = Vec::new();
+    for endpoint in endpoints {
+        if !hosts.contains(&endpoint.host) {
+            hosts.push(endpoint.host.clone());
+        }
+        if !ports.contains(&endpoint.port) {
+            ports.push(endpoint.port.clone());
+        }
+    }
+    (hosts, ports)
+}
+
+/// Discover the endpoints of the cluster via the master given with `--discover`,
+/// print them, and set the endpoints, hosts and ports to be written to '.env'.
+///
+/// The hosts and ports are written too, so these are the discovered ones when the endpoints are overridden with `--hosts` or `--ports`.
+pub async fn discover(
+    parallel: usize,
+    options: &Opts,
+    changed_options: &mut HashMap<&str, String>,
+) -> Result<()>
+{
+    info!("begin discover");
+    let timer = Instant::now();
+
+    let hostname_port = options.discover.as_ref().expect("--discover should be set");
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
+    let endpoints = pool.install(|| read_endpoints(hostname_port))?;
+
+    if options.output.is_table() {
+        for endpoint in &endpoints {
+            println!("{:40} {:6} {}", endpoint.host, endpoint.port, endpoint.role);
+        }
+    } else {
+        output::print_rows(&options.output, "discover", &endpoints)?;
+    }
+
+    changed_options.insert("YBSTATS_ENDPOINTS", endpoints.iter().map(|endpoint| endpoint.to_string()).collect::<Vec<_>>().join(","));
+    let (hosts, ports) = hosts_and_ports(&endpoints);
+    changed_options.insert("YBSTATS_HOSTS", hosts.join(","));
+    changed_options.insert("YBSTATS_PORTS", ports.join(","));
+
+    info!("end discover: {:?}", timer.elapsed());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vars::Var;
+
+    #[test]
+    fn unit_master_endpoints_with_leader() {
+        let json = r#"
+{
+  "masters": [
+    {
+      "instance_id": { "permanent_uuid": "3fc1141619304cffa2f0a345d37a51c2", "instance_seqno": 1657972299220554, "start_time_us": 1657972299220554 },
+      "registration": {
+        "private_rpc_addresses": [ { "host": "yb-1.local", "port": 7100 } ],
+        "http_addresses": [ { "host": "yb-1.local", "port": 7000 } ],
+        "cloud_info": { "placement_cloud": "local", "placement_region": "local", "placement_zone": "local" },
+        "placement_uuid": ""
+      },
+      "role": "FOLLOWER"
+    },
+    {
+      "instance_id": { "permanent_uuid": "f32d67fbf54545b18d3aef17fee4032b", "instance_seqno": 1657972325360336, "start_time_us": 1657972325360336 },
+      "registration": {
+        "private_rpc_addresses": [ { "host": "yb-2.local", "port": 7100 } ],
+        "http_addresses": [ { "host": "yb-2.local", "port": 7000 } ],
+        "cloud_info": { "placement_cloud": "local", "placement_region": "local", "placement_zone": "local" },
+        "placement_uuid": ""
+      },
+      "role": "LEADER"
+    }
+  ]
+}
+        "#;
+        let masters: Masters = serde_json::from_str(json).unwrap();
+        let (endpoints, leader) = master_endpoints(&masters);
+        assert_eq!(endpoints, vec![Endpoint::new("yb-1.local", "7000", Role::Master), Endpoint::new("yb-2.local", "7000", Role::Master)]);
+        assert_eq!(leader, Some(("yb-2.local".to_string(), "7000".to_string())));
+    }
+
+    #[test]
+    fn unit_tablet_server_endpoints_from_flags() {
+        let vars = Vars {
+            flags: vec![
+                Var { name: "pgsql_proxy_webserver_port".to_string(), value: "13001".to_string(), vars_type: "Custom".to_string() },
+                Var { name: "start_cql_proxy".to_string(), value: "false".to_string(), vars_type: "Custom".to_string() },
+            ],
+            ..Default::default()
+        };
+        let result = tablet_server_endpoints("yb-1.local", "9000", &vars);
+        assert_eq!(result, vec![Endpoint::new("yb-1.local", "9000", Role::Tserver), Endpoint::new("yb-1.local", "13001", Role::Ysql)]);
+    }
+
+    #[test]
+    fn unit_tablet_server_endpoints_without_flags() {
+        let result = tablet_server_endpoints("yb-1.local", "9000", &Vars::default());
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1], Endpoint::new("yb-1.local", "13000", Role::Ysql));
+        assert_eq!(result[2], Endpoint::new("yb-1.local", "12000", Role::Ycql));
+    }
+
+    #[test]
+    fn unit_hosts_and_ports_unique() {
+        let endpoints = vec![
+            Endpoint::new("yb-1.local", "7000", Role::Master),
+            Endpoint::new("yb-1.local", "9000", Role::Tserver),
+            Endpoint::new("yb-2.local", "9000", Role::Tserver),
+        ];
+        let (hosts, ports) = hosts_and_ports(&endpoints);
+        assert_eq!(hosts, vec!["yb-1.local", "yb-2.local"]);
+        assert_eq!(ports, vec!["7000", "9000"]);
+    }
+
+    #[test]
+    fn unit_node_exporter_endpoint_not_reachable() {
+        // nothing listens on port 1, so the host has no node_exporter endpoint.
+        assert_eq!(node_exporter_endpoint("127.0.0.1", "1"), None);
+    }
+}
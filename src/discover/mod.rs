@@ -0,0 +1,13 @@
+//! Module for discovering the endpoints of a cluster from a single master (`--discover`).
+//!
+//! The discovery reads:
+//! - `/api/v1/masters` from the given master, for the masters and the master leader.
+//! - `/api/v1/tablet-servers` from the master leader, for the tablet servers.
+//! - `/api/v1/varz` from every tablet server, for the YSQL and YCQL webserver ports.
+//!
+//! The node_exporter port cannot be discovered, and therefore the default port (9300) is probed on every host,
+//! and added for the hosts where `/metrics` can be read.
+//!
+mod functions;
+
+pub use functions::*;
@@ -6,10 +6,11 @@ use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
 use regex::Regex;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::drives::{AllDrives, Drives, Drive};
 use crate::Opts;
+use crate::output::{self, HostRow, OutputFormat};
 
 impl Drives {
     pub fn new() -> Self { Default::default() }
@@ -46,17 +47,21 @@ impl AllDrives {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut drives = AllDrives::read_http(host, port);
-                        drives.timestamp = Some(detail_snapshot_time);
-                        drives.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(drives).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut drives = match AllDrives::read_http(host, port) {
+                        Ok(drives) => drives,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    drives.timestamp = Some(detail_snapshot_time);
+                    drives.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(drives).expect("error sending data via tx");
+                });
             }
         });
 
@@ -74,10 +79,10 @@ impl AllDrives {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Drives
+    ) -> Result<Drives>
     {
-        let data_from_http = utility::http_get(host, port, "drives");
-        AllDrives::parse_drives(data_from_http)
+        let data_from_http = utility::http_get(host, port, "drives").into_body()?;
+        Ok(AllDrives::parse_drives(data_from_http))
     }
     fn parse_drives(
         http_data: String
@@ -124,9 +129,20 @@ impl AllDrives {
     }
     pub fn print(
         &self,
-        hostname_filter: &Regex
+        hostname_filter: &Regex,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.drives.iter()
+                .filter(|row| hostname_filter.is_match(row.hostname_port.as_ref().unwrap()))
+                .flat_map(|drives| drives.drive.iter()
+                    .flatten()
+                    .map(|drive| HostRow { hostname_port: drives.hostname_port.as_ref().unwrap(), row: drive }))
+                .collect();
+            return output::print_rows(output, "drives", &rows);
+        }
         for drives in self.drives.iter().filter(|row| hostname_filter.is_match(row.hostname_port.as_ref().unwrap()))
         {
             for drive in &drives.drive
@@ -155,11 +171,11 @@ pub async fn print_drives(
         Some(snapshot_number) => {
             let mut alldrives = AllDrives::new();
             alldrives.drives = snapshot::read_snapshot_json(snapshot_number, "drives")?;
-            alldrives.print(&hostname_filter)?;
+            alldrives.print(&hostname_filter, &options.output)?;
         },
         None => {
             let alldrives = AllDrives::read_drives(&hosts, &ports, parallel).await;
-            alldrives.print(&hostname_filter)?;
+            alldrives.print(&hostname_filter, &options.output)?;
         },
     }
     Ok(())
@@ -12,7 +12,7 @@
 //! The way these link together is:
 //! - `tables.keyspace_id` -> `keyspaces.keyspace_id` (keyspaces.keyspace_id must exist for tables.keyspace_id)
 //! - `tables.table_id` -> `tablets.table_id`, which contains the replicas as a nested array.
-//!     `tablets.table_id` might not exist for `tables.table_id`, because some tables do not have tablets, such as the postgres catalog entries.
+//!   `tablets.table_id` might not exist for `tables.table_id`, because some tables do not have tablets, such as the postgres catalog entries.
 //!
 //! # Special keyspaces:
 //!
@@ -73,9 +73,10 @@ use regex::Regex;
 use colored::*;
 use anyhow::{Result, bail};
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::entities::{Entities, AllEntities, EntitiesDiff, KeyspaceDiff, TablesDiff, TabletsDiff, ReplicasDiff};
+use crate::entities::{Entities, AllEntities, EntitiesDiff, KeyspaceDiff, TablesDiff, TabletsDiff, ReplicasDiff, EntitiesDiffRow};
+use crate::output::{self, Change, HostRow, OutputFormat};
 use crate::health_check::AllHealthCheck;
 use crate::Opts;
 
@@ -150,17 +151,21 @@ impl AllEntities
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_|  {
-                        let detail_snapshot_time = Local::now();
-                        let mut entities = AllEntities::read_http(host, port);
-                        entities.timestamp = Some(detail_snapshot_time);
-                        entities.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(entities).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_|  {
+                    let detail_snapshot_time = Local::now();
+                    let mut entities = match AllEntities::read_http(host, port) {
+                        Ok(entities) => entities,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    entities.timestamp = Some(detail_snapshot_time);
+                    entities.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(entities).expect("error sending data via tx");
+                });
             }
         });
 
@@ -177,10 +182,10 @@ impl AllEntities
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Entities
+    ) -> Result<Entities>
     {
-        let data_from_http = utility::http_get(host, port, "dump-entities");
-        AllEntities::parse_entities(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "dump-entities").into_body()?;
+        Ok(AllEntities::parse_entities(data_from_http, host, port))
     }
     fn parse_entities(
         entities_data: String,
@@ -282,6 +287,7 @@ impl AllEntities
         }
         bail!("Database name not found.")
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn print(
         &self,
         table_name_filter: &Regex,
@@ -290,8 +296,36 @@ impl AllEntities
         hostname_filter: &Regex,
         dead_nodes: Vec<String>,
         under_replicated_tablets: Vec<String>,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let entities: Vec<_> = self.entities.iter()
+                .filter(|entity| if *details_enable
+                {
+                    hostname_filter.is_match(entity.hostname_port.as_ref().unwrap())
+                }
+                else
+                {
+                    entity.hostname_port == Some(leader_hostname.clone())
+                })
+                .collect();
+            let keyspaces: Vec<_> = entities.iter()
+                .flat_map(|entity| entity.keyspaces.iter().map(|row| HostRow { hostname_port: entity.hostname_port.as_ref().unwrap(), row }))
+                .collect();
+            output::print_rows(output, "entities_keyspaces", &keyspaces)?;
+            let tables: Vec<_> = entities.iter()
+                .flat_map(|entity| entity.tables.iter()
+                    .filter(|row| table_name_filter.is_match(&row.table_name))
+                    .map(|row| HostRow { hostname_port: entity.hostname_port.as_ref().unwrap(), row }))
+                .collect();
+            output::print_rows(output, "entities_tables", &tables)?;
+            let tablets: Vec<_> = entities.iter()
+                .flat_map(|entity| entity.tablets.iter().map(|row| HostRow { hostname_port: entity.hostname_port.as_ref().unwrap(), row }))
+                .collect();
+            return output::print_rows(output, "entities_tablets", &tablets);
+        }
         let is_system_keyspace = |keyspace: &str| -> bool {
             matches!(keyspace, "00000000000000000000000000000001" |   // ycql system
                                "00000000000000000000000000000002" |   // ycql system_schema
@@ -358,8 +392,7 @@ impl AllEntities
                                 print!("{} ", entity.hostname_port.clone().unwrap());
                             }
                             let under_replication_warning = if under_replicated_tablets
-                                .iter()
-                                .any(|r| *r == tablet.tablet_id)
+                                .contains(&tablet.tablet_id)
                             {
                                 "[UNDER REPLICATED]".yellow()
                             }
@@ -515,8 +548,7 @@ impl AllEntities
                         print!("{} ", entity.hostname_port.clone().unwrap());
                     }
                     let under_replication_warning = if under_replicated_tablets
-                        .iter()
-                        .any(|r| *r == tablet.tablet_id)
+                        .contains(&tablet.tablet_id)
                     {
                         "[UNDER REPLICATED]".yellow()
                     }
@@ -795,14 +827,73 @@ impl EntitiesDiff {
         let master_leader= AllIsLeader::return_leader_http(hosts, ports, parallel).await;
         self.second_snapshot(allentities, master_leader);
     }
+    /// Print the keyspace, table, tablet and replica entries that are different between the snapshots as rows.
+    fn print_rows(
+        &self,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let keyspaces: Vec<_> = self.btreekeyspacediff.iter()
+            .filter(|(_, row)| row.first_keyspace_name != row.second_keyspace_name || row.first_keyspace_type != row.second_keyspace_type)
+            .map(|(keyspace_id, row)| EntitiesDiffRow {
+                change: Change::from_empty(row.first_keyspace_name.is_empty(), row.second_keyspace_name.is_empty()),
+                entity: "keyspace",
+                id: keyspace_id,
+                server_uuid: None,
+                fields: row,
+            })
+            .collect();
+        output::print_rows(output, "entities_diff", &keyspaces)?;
+        let tables: Vec<_> = self.btreetablesdiff.iter()
+            .filter(|(_, row)| row.first_keyspace_id != row.second_keyspace_id || row.first_table_name != row.second_table_name || row.first_state != row.second_state)
+            .map(|(table_id, row)| EntitiesDiffRow {
+                change: Change::from_empty(row.first_table_name.is_empty(), row.second_table_name.is_empty()),
+                entity: "table",
+                id: table_id,
+                server_uuid: None,
+                fields: row,
+            })
+            .collect();
+        output::print_rows(output, "entities_diff", &tables)?;
+        let tablets: Vec<_> = self.btreetabletsdiff.iter()
+            .filter(|(_, row)| row.first_table_id != row.second_table_id || row.first_state != row.second_state || row.first_leader != row.second_leader)
+            .map(|(tablet_id, row)| EntitiesDiffRow {
+                change: Change::from_empty(row.first_table_id.is_empty(), row.second_table_id.is_empty()),
+                entity: "tablet",
+                id: tablet_id,
+                server_uuid: None,
+                fields: row,
+            })
+            .collect();
+        output::print_rows(output, "entities_diff", &tablets)?;
+        let replicas: Vec<_> = self.btreereplicasdiff.iter()
+            .filter(|(_, row)| row.first_replica_type != row.second_replica_type || row.first_addr != row.second_addr)
+            .map(|((tablet_id, server_uuid), row)| EntitiesDiffRow {
+                change: Change::from_empty(row.first_addr.is_empty(), row.second_addr.is_empty()),
+                entity: "replica",
+                id: tablet_id,
+                server_uuid: Some(server_uuid),
+                fields: row,
+            })
+            .collect();
+        output::print_rows(output, "entities_diff", &replicas)
+    }
     pub fn print(
         &self,
-    )
+        output: &OutputFormat,
+    ) -> Result<()>
     {
         debug!("entering print function");
         if !self.master_found {
-            println!("Master leader was not found in hosts specified, skipping entity diff.");
-            return;
+            if output.is_table()
+            {
+                println!("Master leader was not found in hosts specified, skipping entity diff.");
+            }
+            return Ok(());
+        }
+        if !output.is_table()
+        {
+            return self.print_rows(output);
         }
         //let is_system_keyspace = |keyspace: &str| -> bool {
         //    matches!(keyspace, "00000000000000000000000000000001" |   // ycql system
@@ -1445,6 +1536,7 @@ impl EntitiesDiff {
                 };
             };
         }
+        Ok(())
     }
 }
 
@@ -1454,13 +1546,13 @@ pub async fn entity_diff(
 {
     info!("entity diff");
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
     let (begin_snapshot, end_snapshot, _begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let entity_diff = EntitiesDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    entity_diff.print();
+    entity_diff.print(&options.output)?;
 
     Ok(())
 }
@@ -1483,14 +1575,14 @@ pub async fn print_entities(
             allentities.entities = snapshot::read_snapshot_json(snapshot_number, "entities")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
             let (dead_nodes, under_replicated_tablets) = AllHealthCheck::return_dead_nodes_and_under_replicated_tablets_snapshot(snapshot_number, &leader_hostname)?;
-            allentities.print(&table_name_filter, &options.details_enable, leader_hostname, &hostname_filter, dead_nodes, under_replicated_tablets)?;
+            allentities.print(&table_name_filter, &options.details_enable, leader_hostname, &hostname_filter, dead_nodes, under_replicated_tablets, &options.output)?;
         },
         None =>
         {
             let allentities = AllEntities::read_entities(&hosts, &ports, parallel).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
             let (dead_nodes, under_replicated_tablets) = AllHealthCheck::return_dead_nodes_and_under_replicated_tablets_http(&hosts, &ports, parallel, &leader_hostname).await?;
-            allentities.print(&table_name_filter, &options.details_enable, leader_hostname, &hostname_filter, dead_nodes, under_replicated_tablets)?;
+            allentities.print(&table_name_filter, &options.details_enable, leader_hostname, &hostname_filter, dead_nodes, under_replicated_tablets, &options.output)?;
         },
     }
     Ok(())
@@ -1581,7 +1673,7 @@ mod tests {
         let hostname = utility::get_hostname_master();
         let port = utility::get_port_master();
 
-        let entities = AllEntities::read_http(&hostname, &port);
+        let entities = AllEntities::read_http(&hostname, &port).unwrap();
 
         assert!(!entities.keyspaces.is_empty());
         assert!(!entities.tables.is_empty());
@@ -2,6 +2,7 @@
 //!
 use chrono::{DateTime, Local};
 use std::collections::BTreeMap;
+use crate::output::Change;
 
 /// The root struct for deserializing `/dump-entities`
 ///
@@ -106,7 +107,7 @@ pub struct EntitiesDiff {
 }
 // String = keyspace_id
 type BTreeKeyspaceDiff = BTreeMap<String, KeyspaceDiff>;
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct KeyspaceDiff {
     pub first_keyspace_name: String,
     pub first_keyspace_type: String,
@@ -115,7 +116,7 @@ pub struct KeyspaceDiff {
 }
 // String = table_id
 type BTreeTablesDiff = BTreeMap<String, TablesDiff>;
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct TablesDiff {
     pub first_keyspace_id: String,
     pub first_table_name: String,
@@ -126,7 +127,7 @@ pub struct TablesDiff {
 }
 // String = tablet_id
 type BTreeTabletsDiff = BTreeMap<String, TabletsDiff>;
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct TabletsDiff {
     pub first_table_id: String,
     pub first_state: String,
@@ -137,10 +138,24 @@ pub struct TabletsDiff {
 }
 // (String, String) = (tablet_id, server_uuid)
 type  BTreeReplicasDiff = BTreeMap<(String, String), ReplicasDiff>;
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct ReplicasDiff {
     pub first_replica_type: String,
     pub first_addr: String,
     pub second_replica_type: String,
     pub second_addr: String,
-}
\ No newline at end of file
+}
+/// A row of the entities diff report, used for the machine readable output.
+///
+/// The entity is `keyspace`, `table`, `tablet` or `replica`, and the id is the id of the entity.
+/// For a replica, the id is the tablet id, and the server_uuid is set.
+#[derive(Debug, Serialize)]
+pub struct EntitiesDiffRow<'a, T: serde::Serialize> {
+    pub change: Change,
+    pub entity: &'static str,
+    pub id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_uuid: Option<&'a str>,
+    #[serde(flatten)]
+    pub fields: &'a T,
+}
@@ -6,9 +6,10 @@ use regex::Regex;
 use log::*;
 use anyhow::Result;
 use crate::Opts;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::gflags::{AllGFlags, GFlag};
+use crate::output::{self, OutputFormat};
 
 impl AllGFlags {
     pub fn new() -> Self { Default::default() }
@@ -42,17 +43,21 @@ impl AllGFlags {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut gflags = AllGFlags::read_http(host, port);
-                        gflags.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
-                        gflags.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
-                        tx.send(gflags).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut gflags = match AllGFlags::read_http(host, port) {
+                        Ok(gflags) => gflags,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    gflags.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
+                    gflags.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
+                    tx.send(gflags).expect("error sending data via tx");
+                });
             }
         });
 
@@ -73,10 +78,10 @@ impl AllGFlags {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<GFlag>
+    ) -> Result<Vec<GFlag>>
     {
-        let data_from_http = utility::http_get(host, port, "varz?raw");
-        AllGFlags::parse_gflags(data_from_http)
+        let data_from_http = utility::http_get(host, port, "varz?raw").into_body()?;
+        Ok(AllGFlags::parse_gflags(data_from_http))
     }
     fn parse_gflags(
         http_data: String
@@ -100,10 +105,20 @@ impl AllGFlags {
         &self,
         hostname_filter: &Regex,
         stat_name_filter: &Regex,
+        output: &OutputFormat,
     ) -> Result<()>
     {
         info!("print_gflags");
 
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.gflags.iter()
+                .filter(|row| hostname_filter.is_match(row.hostname_port.as_ref().expect("hostname:port should be set"))
+                    && stat_name_filter.is_match(&row.name))
+                .collect();
+            return output::print_rows(output, "gflags", &rows);
+        }
+
         let mut previous_hostname_port = String::from("");
         for row in &self.gflags {
             if hostname_filter.is_match(&row.hostname_port.clone().expect("hostname:port should be set")) &&
@@ -135,11 +150,11 @@ pub async fn print_gflags(
         Some(snapshot_number) => {
             let mut allgflags = AllGFlags::new();
             allgflags.gflags = snapshot::read_snapshot_json(snapshot_number, "gflags")?;
-            allgflags.print(&hostname_filter, &stat_name_filter)?;
+            allgflags.print(&hostname_filter, &stat_name_filter, &options.output)?;
         },
         None => {
             let allgflags = AllGFlags::read_gflags(&hosts, &ports, parallel).await;
-            allgflags.print(&hostname_filter, &stat_name_filter)?;
+            allgflags.print(&hostname_filter, &stat_name_filter, &options.output)?;
         }
     }
     Ok(())
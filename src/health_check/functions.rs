@@ -6,9 +6,10 @@ use log::*;
 use colored::*;
 use anyhow::{Result, Context};
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::health_check::{AllHealthCheck, Health_Check, HealthCheckDiff};
+use crate::health_check::{AllHealthCheck, Health_Check, HealthCheckDiff, HealthCheckDiffRow};
+use crate::output::{self, Change, OutputFormat};
 use crate::Opts;
 
 impl Health_Check {
@@ -50,17 +51,21 @@ impl AllHealthCheck {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut health_check = AllHealthCheck::read_http(host, port);
-                        health_check.timestamp = Some(detail_snapshot_time);
-                        health_check.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(health_check).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut health_check = match AllHealthCheck::read_http(host, port) {
+                        Ok(health_check) => health_check,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    health_check.timestamp = Some(detail_snapshot_time);
+                    health_check.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(health_check).expect("error sending data via tx");
+                });
             }
         });
 
@@ -79,10 +84,10 @@ impl AllHealthCheck {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Health_Check
+    ) -> Result<Health_Check>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/health-check");
-        AllHealthCheck::parse_health_check(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "api/v1/health-check").into_body()?;
+        Ok(AllHealthCheck::parse_health_check(data_from_http, host, port))
     }
     fn parse_health_check(
         http_data: String,
@@ -99,15 +104,19 @@ impl AllHealthCheck {
     }
     pub fn print(
         &self,
-        leader_hostname: String
+        leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
-
-        println!("{}", serde_json::to_string_pretty( &self.health_check
+        let leader_row = self.health_check
             .iter()
             .find(|r| r.hostname_port == Some(leader_hostname.clone()))
-            .with_context(|| "Unable to find current master leader")?
-        )?);
+            .with_context(|| "Unable to find current master leader")?;
+        if !output.is_table()
+        {
+            return output::print_rows(output, "health_check", &[leader_row]);
+        }
+        println!("{}", serde_json::to_string_pretty(leader_row)?);
         Ok(())
     }
     pub async fn return_dead_nodes_and_under_replicated_tablets_http(
@@ -153,13 +162,13 @@ pub async fn print_health_check(
             allhealthcheck.health_check = snapshot::read_snapshot_json(snapshot_number, "health-check")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
 
-            allhealthcheck.print(leader_hostname)?;
+            allhealthcheck.print(leader_hostname, &options.output)?;
 
         }
         None => {
             let allhealthcheck = AllHealthCheck::read_health_check(&hosts, &ports, parallel).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
-            allhealthcheck.print(leader_hostname)?;
+            allhealthcheck.print(leader_hostname, &options.output)?;
         }
     }
     Ok(())
@@ -240,43 +249,57 @@ impl HealthCheckDiff {
                 .clone();
         }
     }
-    pub fn print(
+    /// Create the rows for the dead nodes and under replicated tablets that appeared or disappeared between the snapshots.
+    pub fn rows(
         &self,
-    )
+    ) -> Vec<HealthCheckDiffRow<'_>>
     {
-        if !self.master_found
+        let mut rows = Vec::new();
+        for (check, first, second) in [
+            ("dead_node", &self.first_dead_nodes, &self.second_dead_nodes),
+            ("under_replicated_tablet", &self.first_under_replicated_tablets, &self.second_under_replicated_tablets),
+        ]
         {
-            println!("Master leader was not found, skipping health-check diff.");
-            return;
-        }
-        for first_dead_node in &self.first_dead_nodes
-        {
-            if ! self.second_dead_nodes.iter().any(|r| r == first_dead_node )
+            for first_entry in first.iter().filter(|r| !second.contains(r))
             {
-               println!("{} Health Check: dead node removed: {}", "-".to_string().green(), first_dead_node);
+                rows.push(HealthCheckDiffRow { change: Change::Removed, check, id: first_entry });
             }
-        }
-        for second_dead_node in &self.second_dead_nodes
-        {
-            if ! self.first_dead_nodes.iter().any(|r| r == second_dead_node )
+            for second_entry in second.iter().filter(|r| !first.contains(r))
             {
-                println!("{} Health Check: dead node found: {}", "+".to_string().red(), second_dead_node);
+                rows.push(HealthCheckDiffRow { change: Change::Added, check, id: second_entry });
             }
         }
-        for first_under_replicated_tablet in &self.first_under_replicated_tablets
+        rows
+    }
+    pub fn print(
+        &self,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        if !self.master_found
         {
-            if ! self.second_under_replicated_tablets.iter().any(|r| r == first_under_replicated_tablet )
+            if output.is_table()
             {
-                println!("{} Health Check: under replicated tablet removed: {}", "-".to_string().green(), first_under_replicated_tablet);
+                println!("Master leader was not found, skipping health-check diff.");
             }
+            return Ok(());
         }
-        for second_under_replicated_tablet in &self.second_under_replicated_tablets
+        let rows = self.rows();
+        if !output.is_table()
         {
-            if ! self.first_under_replicated_tablets.iter().any(|r| r == second_under_replicated_tablet )
+            return output::print_rows(output, "health_check_diff", &rows);
+        }
+        for row in rows
+        {
+            match (row.check, row.change)
             {
-                println!("{} Health Check: under replicated tablet found: {}", "+".to_string().red(), second_under_replicated_tablet);
+                ("dead_node", Change::Removed) => println!("{} Health Check: dead node removed: {}", "-".to_string().green(), row.id),
+                ("dead_node", _) => println!("{} Health Check: dead node found: {}", "+".to_string().red(), row.id),
+                (_, Change::Removed) => println!("{} Health Check: under replicated tablet removed: {}", "-".to_string().green(), row.id),
+                (_, _) => println!("{} Health Check: under replicated tablet found: {}", "+".to_string().red(), row.id),
             }
         }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
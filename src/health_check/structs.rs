@@ -3,6 +3,7 @@
 #![allow(non_camel_case_types)]
 
 use chrono::{DateTime, Local};
+use crate::output::Change;
 
 /// This struct is a wrapper for the HealthCheck struct.
 ///
@@ -54,4 +55,13 @@ pub struct HealthCheckDiff {
     pub second_under_replicated_tablets: Vec<String>,
     pub master_found: bool,
 }
+/// A row of the health check diff report, used for the table and the machine readable output.
+///
+/// The check is `dead_node` or `under_replicated_tablet`, and the id is the node or tablet.
+#[derive(Debug, Serialize)]
+pub struct HealthCheckDiffRow<'a> {
+    pub change: Change,
+    pub check: &'static str,
+    pub id: &'a str,
+}
 
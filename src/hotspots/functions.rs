@@ -0,0 +1,315 @@
+//! The impls and functions
+//!
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use chrono::{DateTime, Local};
+use regex::Regex;
+use anyhow::{bail, Result};
+use crate::entities::Entities;
+use crate::isleader::AllIsLeader;
+use crate::metrics::MetricEntityDiff;
+use crate::hotspots::{Hotspots, TabletHotspotRow, TableHotspotRow};
+use crate::output::{self, OutputFormat};
+use crate::{snapshot, utility};
+use crate::Opts;
+
+/// The default activity metrics for the hotspot report, the first one is used for the ranking.
+const DEFAULT_TOP_METRICS: &str = "rows_inserted,ql_read_latency,ql_write_latency,rocksdb_number_db_seek,rocksdb_bytes_written";
+
+impl Hotspots {
+    pub fn new() -> Self { Default::default() }
+    /// Create the hotspot report for the top tablets and tables between the begin and end snapshot.
+    ///
+    /// The tablet leaders are taken from the entities of the master leader in the end snapshot.
+    #[allow(clippy::too_many_arguments)]
+    pub fn snapshot_diff(
+        begin_snapshot: &String,
+        end_snapshot: &String,
+        begin_snapshot_time: &DateTime<Local>,
+        metric_names: &[String],
+        hostname_filter: &Regex,
+        table_name_filter: &Regex,
+        top: usize,
+    ) -> Result<Hotspots>
+    {
+        let metricsdiff = MetricEntityDiff::snapshot_diff(begin_snapshot, end_snapshot, begin_snapshot_time, &true)?;
+        let entities: Vec<Entities> = snapshot::read_snapshot_json(end_snapshot, "entities")?;
+        let master_leader = AllIsLeader::return_leader_snapshot(end_snapshot)?;
+        let leaders = tablet_leaders(&entities, &master_leader);
+        Hotspots::create(&metricsdiff, &leaders, metric_names, hostname_filter, table_name_filter, top)
+    }
+    /// Add up the activity metrics per tablet replica and per table, and rank these by the first metric.
+    ///
+    /// The tablet metric entities are used for the tablets and the tables, and the table metric entities for the tables.
+    fn create(
+        metricsdiff: &MetricEntityDiff,
+        leaders: &BTreeMap<String, String>,
+        metric_names: &[String],
+        hostname_filter: &Regex,
+        table_name_filter: &Regex,
+        top: usize,
+    ) -> Result<Hotspots>
+    {
+        let stat_name_filter = Regex::new(&format!("^({})$", metric_names.iter().map(|name| regex::escape(name)).collect::<Vec<_>>().join("|")))?;
+        // (hostname_port, metric_type, metric_id, table_name, metric_name, value)
+        let mut activity: Vec<(String, String, String, String, String, u64)> = Vec::new();
+        for row in metricsdiff.value_rows(hostname_filter, &stat_name_filter, table_name_filter, &false, &false) {
+            activity.push((row.hostname_port, row.metric_type, row.metric_id, row.table_name, row.metric_name, row.value.max(0) as u64));
+        }
+        for row in metricsdiff.countsum_rows(hostname_filter, &stat_name_filter, table_name_filter, &false) {
+            activity.push((row.hostname_port, row.metric_type, row.metric_id, row.table_name, row.metric_name, row.count));
+        }
+
+        let ranking_metric = &metric_names[0];
+        let mut tablets: BTreeMap<(String, String), TabletHotspotRow> = BTreeMap::new();
+        let mut tables: BTreeMap<String, TableHotspotRow> = BTreeMap::new();
+        let mut table_tablets: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        // the ranking metric in total, and on the tablet leaders, of the tablets of a table.
+        let mut table_leader_activity: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+        for (hostname_port, metric_type, metric_id, table_name, metric_name, value) in activity
+        {
+            if metric_type != "tablet" && metric_type != "table" { continue };
+            let table = tables.entry(table_name.clone())
+                .or_insert_with(|| TableHotspotRow { table_name: table_name.clone(), ..Default::default() });
+            *table.metrics.entry(metric_name.clone()).or_default() += value;
+            if metric_type == "table" { continue };
+
+            let role = tablet_role(leaders, &metric_id, &hostname_port);
+            if metric_name == *ranking_metric {
+                let leader_activity = table_leader_activity.entry(table_name.clone()).or_default();
+                leader_activity.0 += value;
+                if role == "LEADER" {
+                    leader_activity.1 += value;
+                }
+            }
+            table_tablets.entry(table_name.clone()).or_default().insert(metric_id.clone());
+            let tablet = tablets.entry((hostname_port.clone(), metric_id.clone()))
+                .or_insert_with(|| TabletHotspotRow {
+                    hostname_port,
+                    table_name,
+                    leader: leaders.get(&metric_id).cloned().unwrap_or_default(),
+                    tablet_id: metric_id,
+                    role: role.to_string(),
+                    ..Default::default()
+                });
+            *tablet.metrics.entry(metric_name).or_default() += value;
+        }
+        for (table_name, table) in tables.iter_mut() {
+            table.tablets = table_tablets.get(table_name).map(|tablet_ids| tablet_ids.len()).unwrap_or_default();
+            if let Some((total, leader)) = table_leader_activity.get(table_name) {
+                if *total > 0 {
+                    table.leader_percentage = *leader as f64 / *total as f64 * 100.0;
+                }
+            }
+        }
+
+        let mut hotspots = Hotspots::new();
+        hotspots.tablets = tablets.into_values().collect();
+        hotspots.tablets.sort_by_key(|row| Reverse(ranking(&row.metrics, metric_names)));
+        hotspots.tablets.truncate(top);
+        hotspots.tablets.iter_mut().enumerate().for_each(|(number, row)| row.rank = number + 1);
+        hotspots.tables = tables.into_values().collect();
+        hotspots.tables.sort_by_key(|row| Reverse(ranking(&row.metrics, metric_names)));
+        hotspots.tables.truncate(top);
+        hotspots.tables.iter_mut().enumerate().for_each(|(number, row)| row.rank = number + 1);
+        Ok(hotspots)
+    }
+    pub fn print(
+        &self,
+        metric_names: &[String],
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        if !output.is_table()
+        {
+            output::print_rows(output, "hotspots_tablets", &self.tablets)?;
+            output::print_rows(output, "hotspots_tables", &self.tables)?;
+            return Ok(());
+        }
+        let metrics_header = metric_names.iter()
+            .map(|name| format!("{:>width$}", name, width = name.len().max(12)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let metrics_columns = |metrics: &BTreeMap<String, u64>| metric_names.iter()
+            .map(|name| format!("{:>width$}", metrics.get(name).copied().unwrap_or_default(), width = name.len().max(12)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        println!("Top tablets by {}", metric_names[0]);
+        println!("{:>4} {:20} {:8} {:15} {:30} {:32} {}", "rank", "hostname_port", "role", "leader", "table", "tablet_id", metrics_header);
+        for row in &self.tablets
+        {
+            println!("{:>4} {:20} {:8} {:15} {:30} {:32} {}", row.rank, row.hostname_port, row.role, row.leader, row.table_name, row.tablet_id, metrics_columns(&row.metrics));
+        }
+        println!();
+        println!("Top tables by {}", metric_names[0]);
+        println!("{:>4} {:30} {:>7} {:>8} {}", "rank", "table", "tablets", "leader %", metrics_header);
+        for row in &self.tables
+        {
+            println!("{:>4} {:30} {:>7} {:>8.1} {}", row.rank, row.table_name, row.tablets, row.leader_percentage, metrics_columns(&row.metrics));
+        }
+        Ok(())
+    }
+}
+
+/// Find the host of the leader of every tablet in the entities of the master leader.
+///
+/// The leader is the replica with the server uuid of the tablet leader, and its host is taken from the replica address.
+fn tablet_leaders(
+    entities: &[Entities],
+    master_leader: &str,
+) -> BTreeMap<String, String>
+{
+    let mut leaders = BTreeMap::new();
+    for entity in entities.iter().filter(|entity| entity.hostname_port.as_deref() == Some(master_leader))
+    {
+        for tablet in &entity.tablets
+        {
+            let leader_host = tablet.replicas.iter().flatten()
+                .find(|replica| Some(&replica.server_uuid) == tablet.leader.as_ref())
+                .and_then(|replica| replica.addr.rsplit_once(':'))
+                .map(|(host, _)| host.to_string());
+            if let Some(leader_host) = leader_host {
+                leaders.insert(tablet.tablet_id.clone(), leader_host);
+            }
+        }
+    }
+    leaders
+}
+
+/// The role of the tablet replica on the server at `hostname_port`: LEADER or FOLLOWER, or empty if the leader is unknown.
+fn tablet_role(
+    leaders: &BTreeMap<String, String>,
+    tablet_id: &str,
+    hostname_port: &str,
+) -> &'static str
+{
+    let host = hostname_port.rsplit_once(':').map(|(host, _)| host).unwrap_or(hostname_port);
+    match leaders.get(tablet_id) {
+        Some(leader_host) if leader_host == host => "LEADER",
+        Some(_) => "FOLLOWER",
+        None => "",
+    }
+}
+
+/// The ranking of a row: its metrics in the order of the activity metrics, so the first metric ranks first.
+fn ranking(
+    metrics: &BTreeMap<String, u64>,
+    metric_names: &[String],
+) -> Vec<u64>
+{
+    metric_names.iter()
+        .map(|name| metrics.get(name).copied().unwrap_or_default())
+        .collect()
+}
+
+/// Parse the comma separated activity metrics set with `--top-metrics`, or use the default activity metrics.
+fn top_metrics(
+    top_metrics: &Option<String>,
+) -> Result<Vec<String>>
+{
+    let metric_names: Vec<String> = top_metrics.as_deref()
+        .unwrap_or(DEFAULT_TOP_METRICS)
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    if metric_names.is_empty() {
+        bail!("--top-metrics requires at least one metric name");
+    }
+    Ok(metric_names)
+}
+
+/// Print the top tablets and tables by activity between the begin and end snapshot.
+pub async fn print_top(
+    options: &Opts,
+) -> Result<()>
+{
+    if options.begin.is_none() || options.end.is_none() {
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
+    }
+    if options.snapshot_list { return Ok(()) };
+
+    let top = options.top.expect("--top should be set");
+    let metric_names = top_metrics(&options.top_metrics)?;
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+    let table_name_filter = utility::set_regex(&options.table_name_match);
+
+    let (begin_snapshot, end_snapshot, begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
+
+    let hotspots = Hotspots::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp, &metric_names, &hostname_filter, &table_name_filter, top)?;
+    hotspots.print(&metric_names, &options.output)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Replicas, Tablets};
+    use crate::metrics::MetricDiffValues;
+
+    /// Create the entities of the master leader with a tablet replicated on three servers, of which the second is the leader.
+    fn entities(tablet_id: &str) -> Entities {
+        let replica = |number: u32| Replicas { replica_type: "VOTER".to_string(), server_uuid: format!("uuid-{}", number), addr: format!("yb-{}.local:9100", number) };
+        Entities {
+            hostname_port: Some("yb-1.local:7000".to_string()),
+            tablets: vec![Tablets {
+                table_id: "table-a".to_string(),
+                tablet_id: tablet_id.to_string(),
+                state: "RUNNING".to_string(),
+                replicas: Some(vec![replica(1), replica(2), replica(3)]),
+                leader: Some("uuid-2".to_string()),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unit_tablet_leaders_and_roles() {
+        let leaders = tablet_leaders(&[entities("tablet-1")], "yb-1.local:7000");
+        assert_eq!(leaders.get("tablet-1"), Some(&"yb-2.local".to_string()));
+        assert_eq!(tablet_role(&leaders, "tablet-1", "yb-2.local:9000"), "LEADER");
+        assert_eq!(tablet_role(&leaders, "tablet-1", "yb-3.local:9000"), "FOLLOWER");
+        assert_eq!(tablet_role(&leaders, "tablet-2", "yb-3.local:9000"), "");
+        // only the entities of the master leader are used.
+        assert!(tablet_leaders(&[entities("tablet-1")], "yb-2.local:7000").is_empty());
+    }
+
+    #[test]
+    fn unit_hotspots_ranked_by_first_metric() {
+        let begin = Local::now();
+        let mut metricsdiff = MetricEntityDiff::new();
+        let mut insert = |hostname_port: &str, tablet_id: &str, metric_name: &str, value: i128| {
+            metricsdiff.btreemetricdiffvalue.insert(
+                (hostname_port.to_string(), "tablet".to_string(), tablet_id.to_string(), metric_name.to_string()),
+                MetricDiffValues { table_name: "a".to_string(), namespace: "yugabyte".to_string(), first_snapshot_time: begin, first_value: 0, second_snapshot_time: begin + chrono::Duration::seconds(10), second_value: value },
+            );
+        };
+        insert("yb-2.local:9000", "tablet-1", "rows_inserted", 100);
+        insert("yb-3.local:9000", "tablet-1", "rows_inserted", 300);
+        insert("yb-3.local:9000", "tablet-1", "rocksdb_number_db_seek", 5);
+        insert("yb-1.local:9000", "tablet-2", "rocksdb_number_db_seek", 1000);
+        let leaders = tablet_leaders(&[entities("tablet-1")], "yb-1.local:7000");
+        let metric_names = top_metrics(&None).unwrap();
+        let all = Regex::new(".*").unwrap();
+        let hotspots = Hotspots::create(&metricsdiff, &leaders, &metric_names, &all, &all, 2).unwrap();
+
+        assert_eq!(hotspots.tablets.iter().map(|row| (row.rank, row.hostname_port.as_str(), row.role.as_str())).collect::<Vec<_>>(),
+                   vec![(1, "yb-3.local:9000", "FOLLOWER"), (2, "yb-2.local:9000", "LEADER")]);
+        assert_eq!(hotspots.tablets[0].metrics.get("rocksdb_number_db_seek"), Some(&5));
+        assert_eq!(hotspots.tables.len(), 1);
+        assert_eq!(hotspots.tables[0].table_name, "yugabyte.a");
+        assert_eq!(hotspots.tables[0].tablets, 2);
+        assert_eq!(hotspots.tables[0].metrics.get("rows_inserted"), Some(&400));
+        // 100 of the 400 rows are inserted on the leader.
+        assert_eq!(hotspots.tables[0].leader_percentage, 25.0);
+    }
+
+    #[test]
+    fn unit_top_metrics() {
+        assert_eq!(top_metrics(&Some("rows_inserted, log_bytes_logged".to_string())).unwrap(), vec!["rows_inserted", "log_bytes_logged"]);
+        assert_eq!(top_metrics(&None).unwrap()[0], "rows_inserted");
+        assert!(top_metrics(&Some(",".to_string())).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! Module for the hotspot report: the tablets and tables with the highest activity between two snapshots.
+//!
+//! The activity is taken from the table and tablet metrics, such as rows inserted, read and write operations, rocksdb seeks and bytes written.
+//! The tablets are joined with the tablet leaders from `/dump-entities` of the master leader,
+//! to show whether the activity of a tablet falls on its leader.
+//!
+mod structs;
+mod functions;
+
+pub use structs::*;
+pub use functions::*;
@@ -0,0 +1,36 @@
+//! The structs
+//!
+use std::collections::BTreeMap;
+/// The hotspot report: the tablets and tables, ranked by the first activity metric.
+#[derive(Debug, Default)]
+pub struct Hotspots {
+    pub tablets: Vec<TabletHotspotRow>,
+    pub tables: Vec<TableHotspotRow>,
+}
+/// A row of the hotspot report for a tablet replica on a server.
+///
+/// The metrics are the difference between the snapshots for a value statistic, and the count for a countsum statistic.
+#[derive(Debug, Default, Serialize)]
+pub struct TabletHotspotRow {
+    pub rank: usize,
+    pub hostname_port: String,
+    pub table_name: String,
+    pub tablet_id: String,
+    /// LEADER if the server is the leader of the tablet, FOLLOWER if it is not, and empty if the leader is unknown.
+    pub role: String,
+    /// The host of the leader of the tablet.
+    pub leader: String,
+    #[serde(flatten)]
+    pub metrics: BTreeMap<String, u64>,
+}
+/// A row of the hotspot report for a table: the tablet replicas of the table added up.
+#[derive(Debug, Default, Serialize)]
+pub struct TableHotspotRow {
+    pub rank: usize,
+    pub table_name: String,
+    pub tablets: usize,
+    /// The percentage of the first activity metric that is done by the tablet leaders.
+    pub leader_percentage: f64,
+    #[serde(flatten)]
+    pub metrics: BTreeMap<String, u64>,
+}
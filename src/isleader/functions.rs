@@ -4,7 +4,7 @@ use chrono::Local;
 use std::{time::Instant, sync::mpsc::channel};
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::isleader::{AllIsLeader, IsLeader};
 
@@ -72,18 +72,22 @@ impl AllIsLeader {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut isleader = AllIsLeader::read_http(host, port);
-                        isleader.timestamp = Some(detail_snapshot_time);
-                        isleader.hostname_port = Some(format!("{}:{}", host, port));
-                        debug!("{:?}",&isleader);
-                        tx.send(isleader).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut isleader = match AllIsLeader::read_http(host, port) {
+                        Ok(isleader) => isleader,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    isleader.timestamp = Some(detail_snapshot_time);
+                    isleader.hostname_port = Some(format!("{}:{}", host, port));
+                    debug!("{:?}",&isleader);
+                    tx.send(isleader).expect("error sending data via tx");
+                });
             }
         });
 
@@ -101,10 +105,10 @@ impl AllIsLeader {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> IsLeader
+    ) -> Result<IsLeader>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/is-leader");
-        AllIsLeader::parse_isleader(data_from_http)
+        let data_from_http = utility::http_get(host, port, "api/v1/is-leader").into_body()?;
+        Ok(AllIsLeader::parse_isleader(data_from_http))
     }
     // This function parses the http output.
     // This is a separate function in order to allow integration tests to use it.
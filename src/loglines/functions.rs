@@ -10,8 +10,9 @@ use anyhow::Result;
 use scraper::{Html, Selector};
 use crate::snapshot;
 use crate::Opts;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::loglines::{AllLogLines, LogLine};
+use crate::output::{self, OutputFormat};
 
 impl AllLogLines {
     pub fn new() -> Self { Default::default() }
@@ -45,16 +46,20 @@ impl AllLogLines {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        // no detail_snapshot_time: the time of the logline is part of LogLine!
-                        let mut loglines = AllLogLines::read_http(host, port);
-                        loglines.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
-                        tx.send(loglines).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    // no detail_snapshot_time: the time of the logline is part of LogLine!
+                    let mut loglines = match AllLogLines::read_http(host, port) {
+                        Ok(loglines) => loglines,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    loglines.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
+                    tx.send(loglines).expect("error sending data via tx");
+                });
             }
         });
 
@@ -74,10 +79,10 @@ impl AllLogLines {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<LogLine>
+    ) -> Result<Vec<LogLine>>
     {
-        let data_from_http = utility::http_get(host, port, "logs");
-        AllLogLines::parse_loglines(data_from_http)
+        let data_from_http = utility::http_get(host, port, "logs").into_body()?;
+        Ok(AllLogLines::parse_loglines(data_from_http))
     }
     fn parse_loglines(
         http_data: String
@@ -173,31 +178,34 @@ impl AllLogLines {
         hostname_filter: &Regex,
         stat_name_filter: &Regex,
         log_severity: &str,
+        output: &OutputFormat,
     ) -> Result<()>
     {
         info!("print log");
 
         // create a copy of the stored_loglines vector and sort it based on the timestamp.
         let mut sorted_loglines = self.loglines.clone();
-        sorted_loglines.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        sorted_loglines.sort_by_key(|a| a.timestamp);
+        sorted_loglines.retain(|row| hostname_filter.is_match(&row.hostname_port.clone().expect("no hostname:port set"))
+            && log_severity.contains(&row.severity)
+            && ( stat_name_filter.is_match(&row.message) || stat_name_filter.is_match(&row.sourcefile_nr) ));
+        if !output.is_table()
+        {
+            return output::print_rows(output, "loglines", &sorted_loglines);
+        }
         // use the sorted vector to loop over.
         for row in &sorted_loglines
         {
-            if hostname_filter.is_match(&row.hostname_port.clone().expect("no hostname:port set"))
-                && log_severity.contains(&row.severity)
-                && ( stat_name_filter.is_match(&row.message) || stat_name_filter.is_match(&row.sourcefile_nr) )
+            print!("{:20} {:33} ", row.hostname_port.clone().expect("no hostname:port set"), row.timestamp);
+            match row.severity.as_str()
             {
-                print!("{:20} {:33} ", row.hostname_port.clone().expect("no hostname:port set"), row.timestamp);
-                match row.severity.as_str()
-                {
-                    "I" => print!("{} ", "I".green()),
-                    "W" => print!("{} ", "W".yellow()),
-                    "E" => print!("{} ", "E".red()),
-                    "F" => print!("{} ", "F".purple()),
-                    _   => print!("{} ", row.severity.underline()),
-                }
-                println!("{:20} {:50}",row.sourcefile_nr, row.message.trim());
+                "I" => print!("{} ", "I".green()),
+                "W" => print!("{} ", "W".yellow()),
+                "E" => print!("{} ", "E".red()),
+                "F" => print!("{} ", "F".purple()),
+                _   => print!("{} ", row.severity.underline()),
             }
+            println!("{:20} {:50}",row.sourcefile_nr, row.message.trim());
         }
         Ok(())
     }
@@ -217,11 +225,11 @@ pub async fn print_loglines(
         Some(snapshot_number) => {
             let mut allloglines = AllLogLines::new();
             allloglines.loglines = snapshot::read_snapshot_json(snapshot_number, "loglines")?;
-            allloglines.print(&hostname_filter, &stat_name_filter, &options.log_severity)?;
+            allloglines.print(&hostname_filter, &stat_name_filter, &options.log_severity, &options.output)?;
         },
         None => {
             let allloglines = AllLogLines::read_loglines(&hosts, &ports, parallel).await;
-            allloglines.print(&hostname_filter, &stat_name_filter, &options.log_severity)?;
+            allloglines.print(&hostname_filter, &stat_name_filter, &options.log_severity, &options.output)?;
         },
     }
     Ok(())
@@ -264,7 +272,7 @@ pub async fn tail_loglines(
         // add all loglines that are not found in the second loglines snapshot to display loglines
         for (key, value) in &second_loglines_btreemap
         {
-            if first_loglines_btreemap.get(key).is_none()
+            if !first_loglines_btreemap.contains_key(key)
             {
                 display_loglines_btreemap.insert( key.clone(), value.clone());
             }
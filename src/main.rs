@@ -6,6 +6,7 @@
 //!   and the adhoc snapshot mode.
 //! - Read yb_stats snapshots (CSV), and report the difference (`--*-diff`).
 //! - Read yb_stats snapshots (CSV), and report the snapshot data (`--print-* <NR>`).
+//! - Read YugabyteDB http endpoints continuously, and serve the difference as a Prometheus endpoint (`--serve`).
 //!
 //! This main file contains the [Opts] struct for commandline options via clap.
 //! It then calls the tasks using the opts structure.
@@ -49,6 +50,13 @@ mod tasks;
 mod tablet_replication;
 mod tablet_server_operations;
 mod drives;
+mod output;
+mod serve;
+mod discover;
+mod restarts;
+mod placements;
+mod hotspots;
+mod replication;
 
 // constants
 const DEFAULT_HOSTS: &str = "192.168.66.80,192.168.66.81,192.168.66.82";
@@ -69,9 +77,33 @@ pub struct Opts {
     /// Snapshot input port numbers (comma separated)
     #[arg(short = 'P', long, value_name = "port,port")]
     ports: Option<String>,
+    /// Snapshot input endpoints with their role: master, tserver, ysql, ycql or node_exporter (comma separated)
+    #[arg(long, value_name = "host:port:role,host:port:role")]
+    endpoints: Option<String>,
     /// Snapshot capture parallelism (default 1)
     #[arg(short = 'p', long, value_name = "nr")]
     parallel: Option<String>,
+    /// Http endpoint scheme: http, https, or auto to try https and fall back to http
+    #[arg(long, value_name = "http|https|auto")]
+    scheme: Option<String>,
+    /// Https CA certificate bundle (PEM) to verify the endpoint certificates
+    #[arg(long, value_name = "file")]
+    ca_cert: Option<String>,
+    /// Https client certificate (PEM), requires --client-key
+    #[arg(long, value_name = "file")]
+    client_cert: Option<String>,
+    /// Https client private key (PKCS#8 PEM), requires --client-cert
+    #[arg(long, value_name = "file")]
+    client_key: Option<String>,
+    /// Http basic authentication user name
+    #[arg(long, value_name = "user")]
+    auth_user: Option<String>,
+    /// Http basic authentication password (requires --auth-user)
+    #[arg(long, value_name = "password")]
+    auth_password: Option<String>,
+    /// Http bearer authentication token
+    #[arg(long, value_name = "token")]
+    auth_token: Option<String>,
     /// Output filter for statistic names as regex
     #[arg(short, long, value_name = "regex")]
     stat_name_match: Option<String>,
@@ -81,9 +113,24 @@ pub struct Opts {
     /// Output filter for hostname or ports as regex
     #[arg(long, value_name = "regex")]
     hostname_match: Option<String>,
+    /// Output filter for the SQL text of statements as regex (use with --print-statements)
+    #[arg(long, value_name = "regex")]
+    sql_match: Option<String>,
+    /// Output the statement with this query_id with the full SQL text (use with --print-statements)
+    #[arg(long, value_name = "query_id", allow_negative_numbers = true)]
+    query_id: Option<i64>,
     /// Output setting to add statistics that are not counters
     #[arg(short, long)]
     gauges_enable: bool,
+    /// Output setting to sort the countsum statistics by tail latency (99th percentile, then maximum)
+    #[arg(long)]
+    tail_latency_sort: bool,
+    /// Output setting for the level at which the metrics are added up (default host, or tablet with --details-enable)
+    #[arg(long, value_enum, value_name = "level")]
+    aggregation: Option<metrics::AggregationLevel>,
+    /// Output setting to add up the metrics per placement of the servers: per region or zone
+    #[arg(long, value_enum, value_name = "region|zone")]
+    group_by: Option<placements::GroupBy>,
     /// Output setting to increase detail, such as report each table and tablet individually
     #[arg(short, long)]
     details_enable: bool,
@@ -93,6 +140,42 @@ pub struct Opts {
     /// Perform a snapshot (creates stored JSON files)
     #[arg(long)]
     snapshot: bool,
+    /// Discover the masters, tablet servers and their webserver ports via a master, and write these to .env
+    #[arg(long, value_name = "hostname:port")]
+    discover: Option<String>,
+    /// Perform snapshots continuously every --interval, until interrupted
+    #[arg(long)]
+    daemon: bool,
+    /// Serve the derived metrics and statements statistics as a Prometheus endpoint on the address
+    #[arg(long, value_name = "address:port")]
+    serve: Option<String>,
+    /// Daemon and serve interval between snapshots, such as 60s, 5m or 1h
+    #[arg(long, value_name = "duration", default_value = "60s")]
+    interval: String,
+    /// Daemon retention: remove snapshots older than a duration (7d), or keep a number of snapshots (100)
+    #[arg(long, value_name = "duration|count")]
+    retain: Option<String>,
+    /// Export the snapshots in a range (such as 1,3,5-9 or all) into a single compressed bundle file
+    #[arg(long, num_args = 2, value_names = ["range", "file"])]
+    export_snapshots: Option<Vec<String>>,
+    /// Import the snapshots from a bundle file created with --export-snapshots, renumbered after the existing snapshots
+    #[arg(long, value_name = "file")]
+    import_snapshots: Option<String>,
+    /// Delete the snapshots in a range (such as 1,3,5-9 or all)
+    #[arg(long, value_name = "range")]
+    snapshot_delete: Option<String>,
+    /// Prune the snapshots of the current cluster with --keep or --older-than
+    #[arg(long)]
+    snapshot_prune: bool,
+    /// Prune setting to keep a number of most recent snapshots
+    #[arg(long, value_name = "n")]
+    keep: Option<usize>,
+    /// Prune setting to remove the snapshots older than a duration, such as 12h or 7d
+    #[arg(long, value_name = "duration")]
+    older_than: Option<String>,
+    /// Verify that the snapshot directories and files of the snapshots in the snapshot index are present and can be read
+    #[arg(long)]
+    snapshot_verify: bool,
     /// Snapshot add comment in snapshot overview
     #[arg(long, value_name = "\"comment\"")]
     snapshot_comment: Option<String>,
@@ -105,6 +188,30 @@ pub struct Opts {
     /// Create a metric diff report using a begin and end snapshot number.
     #[arg(long)]
     metrics_diff: bool,
+    /// Create a metric series report with the figures per interval of every snapshot between a begin and end snapshot number (requires --stat-name-match)
+    #[arg(long)]
+    metrics_series: bool,
+    /// Output setting to add an ASCII sparkline to each series of the metric series report
+    #[arg(long)]
+    sparkline: bool,
+    /// Create a report of the top n tablets and tables by activity using a begin and end snapshot number
+    #[arg(long, value_name = "n")]
+    top: Option<usize>,
+    /// Top report activity metrics (comma separated), the first is used for the ranking (default rows_inserted,ql_read_latency,ql_write_latency,rocksdb_number_db_seek,rocksdb_bytes_written)
+    #[arg(long, value_name = "name,name")]
+    top_metrics: Option<String>,
+    /// Create a CDC and xCluster replication report per stream and table using a begin and end snapshot number.
+    #[arg(long)]
+    replication_diff: bool,
+    /// Create a cluster-wide YSQL top SQL report, with the statements of all YSQL servers added up by query_id, using a begin and end snapshot number (limit with --top-sql-limit)
+    #[arg(long)]
+    top_sql: bool,
+    /// Top SQL report limit: show the top n statements only
+    #[arg(long, value_name = "n")]
+    top_sql_limit: Option<usize>,
+    /// Top SQL report order
+    #[arg(long, value_enum, value_name = "order", default_value = "total-time")]
+    sql_sort: statements::SqlSort,
     /// Create an entity diff report using a begin and end snapshot number.
     #[arg(long)]
     entity_diff: bool,
@@ -120,6 +227,9 @@ pub struct Opts {
     /// Create a node_exporter diff report using a begin and end snapshot number.
     #[arg(long)]
     node_exporter_diff: bool,
+    /// Create an OS summary report with CPU, disk, network and memory figures from node_exporter using a begin and end snapshot number.
+    #[arg(long)]
+    os_summary: bool,
     /// Create a (YSQL) statements diff report using a begin and end snapshot number.
     #[arg(long)]
     statements_diff: bool,
@@ -135,9 +245,18 @@ pub struct Opts {
     /// Create an adhoc diff report excluding metrics
     #[arg(long)]
     adhoc_nonmetrics_diff: bool,
-    /// Lists the snapshots in the yb_stats.snapshots in the current directory.
+    /// Lists the snapshots in the snapshot directory.
     #[arg(short = 'l', long)]
     snapshot_list: bool,
+    /// Snapshot directory to store and read the snapshots, by default yb_stats.snapshots in the current directory
+    #[arg(long, value_name = "directory")]
+    snapshot_dir: Option<String>,
+    /// Snapshot cluster name to save new snapshots with, so one snapshot directory can hold several clusters
+    #[arg(long, value_name = "name")]
+    cluster: Option<String>,
+    /// Output filter for the snapshot list by cluster name as regex
+    #[arg(long, value_name = "regex")]
+    cluster_match: Option<String>,
     /// Output setting to specify the begin snapshot number for diff report.
     #[arg(short = 'b', long, value_name = "snapshot number")]
     begin: Option<i32>,
@@ -207,6 +326,15 @@ pub struct Opts {
     /// Print the tablet detail the given snapshot number, or get current.
     #[arg(long, value_name = "snapshot number")]
     print_tablet_detail: Option<Option<String>>,
+    /// Print the YSQL statements for the given snapshot number, or get current.
+    #[arg(long, value_name = "snapshot number")]
+    print_statements: Option<Option<String>>,
+    /// List the value and countsum statistics that are not in the statistic catalog for the given snapshot number, or get current, in the statistics file format
+    #[arg(long, value_name = "snapshot number")]
+    list_unknown_statistics: Option<Option<String>>,
+    /// Statistics file to extend or override the statistic catalog, in the format of --list-unknown-statistics
+    #[arg(long, value_name = "file")]
+    statistics_file: Option<String>,
     /// UUID for table-detail
     #[arg(long, value_name = "uuid", default_value = "")]
     uuid: String,
@@ -219,6 +347,9 @@ pub struct Opts {
     /// Output setting for the length of the SQL text to display
     #[arg(long, value_name = "nr", default_value = "80")]
     sql_length: usize,
+    /// Output format for the print and diff reports
+    #[arg(long, value_enum, default_value_t = output::OutputFormat::Table)]
+    output: output::OutputFormat,
     /// Get the hostname for the tablet leader of a colocated YSQL database.
     #[arg(long, hide = true, value_name = "ysql colocated database name")]
     get_coloc_leader_host: Option<String>,
@@ -236,21 +367,45 @@ async fn main() -> Result<()>
     let hosts = utility::set_hosts(&options.hosts, &mut changed_options);
     let ports = utility::set_ports(&options.ports, &mut changed_options);
     let parallel = utility::set_parallel(&options.parallel, &mut changed_options);
+    utility::set_endpoints(&options, &mut changed_options)?;
+    utility::set_http_settings(&options, &mut changed_options)?;
+    utility::set_snapshot_settings(&options, &mut changed_options)?;
+    metrics::set_statistic_catalog(utility::set_statistics_file(&options, &mut changed_options))?;
 
+    // the hosts that could not be read are reported, like the collection report of a snapshot does.
+    // the modes that keep running do not record the fetch results, because these would grow without limit.
+    let report_fetch_failures = !(options.daemon || options.serve.is_some() || options.tail_log);
+    if report_fetch_failures {
+        utility::start_fetch_results();
+    }
     match &options {
+        Opts { discover, ..                 } if discover.is_some()              => discover::discover(parallel, &options, &mut changed_options).await?,
+        Opts { daemon, ..                   } if *daemon                         => snapshot::perform_snapshot_daemon(hosts, ports, parallel, &options).await?,
+        Opts { serve, ..                    } if serve.is_some()                 => serve::serve(hosts, ports, parallel, &options).await?,
         Opts { snapshot, ..                 } if *snapshot                       => snapshot::perform_snapshot(hosts, ports, parallel, &options).await?,
+        Opts { export_snapshots, ..         } if export_snapshots.is_some()      => snapshot::export_snapshots(&options).await?,
+        Opts { import_snapshots, ..         } if import_snapshots.is_some()      => snapshot::import_snapshots(&options).await?,
+        Opts { snapshot_delete, ..          } if snapshot_delete.is_some()       => snapshot::delete_snapshots(&options).await?,
+        Opts { snapshot_prune, ..           } if *snapshot_prune                 => snapshot::prune_snapshots(&options).await?,
+        Opts { snapshot_verify, ..          } if *snapshot_verify                => snapshot::verify_snapshots(&options).await?,
         Opts { snapshot_diff, ..            } if *snapshot_diff                  => snapshot::snapshot_diff(&options).await?,
         Opts { snapshot_nonmetrics_diff, .. } if *snapshot_nonmetrics_diff       => snapshot::snapshot_nonmetrics_diff(&options).await?,
         Opts { snapshot_list, ..            } if *snapshot_list                  => snapshot::snapshot_diff(&options).await?,
         Opts { metrics_diff, ..              } if *metrics_diff                    => metrics::metrics_diff(&options).await?,
+        Opts { metrics_series, ..           } if *metrics_series                 => metrics::metrics_series(&options).await?,
+        Opts { top_sql, ..                  } if *top_sql                        => statements::print_top_sql(&options).await?,
+        Opts { top, ..                      } if top.is_some()                   => hotspots::print_top(&options).await?,
+        Opts { replication_diff, ..         } if *replication_diff               => replication::replication_diff(&options).await?,
         Opts { entity_diff, ..              } if *entity_diff                    => entities::entity_diff(&options).await?,
         Opts { masters_diff, ..             } if *masters_diff                   => masters::masters_diff(&options).await?,
         Opts { tablet_servers_diff, ..             } if *tablet_servers_diff                   => tablet_servers::tablet_servers_diff(&options).await?,
         Opts { vars_diff, ..             } if *vars_diff                   => vars::vars_diff(&options).await?,
         Opts { node_exporter_diff, ..             } if *node_exporter_diff                   => node_exporter::node_exporter_diff(&options).await?,
+        Opts { os_summary, ..                     } if *os_summary                           => node_exporter::os_summary(&options).await?,
         Opts { statements_diff, ..             } if *statements_diff                   => statements::statements_diff(&options).await?,
         Opts { versions_diff, ..            } if *versions_diff                  => versions::versions_diff(&options).await?,
         Opts { print_memtrackers, ..        } if print_memtrackers.is_some()     => memtrackers::print_memtrackers(hosts, ports, parallel, &options).await?,
+        Opts { list_unknown_statistics, ..  } if list_unknown_statistics.is_some() => metrics::list_unknown_statistics(hosts, ports, parallel, &options).await?,
         Opts { print_version, ..            } if print_version.is_some()         => versions::print_version(hosts, ports, parallel, &options).await?,
         Opts { print_threads, ..            } if print_threads.is_some()         => threads::print_threads(hosts, ports, parallel, &options).await?,
         Opts { print_entities, ..           } if print_entities.is_some()        => entities::print_entities(hosts, ports, parallel, &options).await?,
@@ -273,9 +428,13 @@ async fn main() -> Result<()>
         Opts { print_master_tasks, ..       } if print_master_tasks.is_some()    => tasks::print_tasks(hosts, ports, parallel, &options).await?,
         Opts { print_table_detail, ..       } if print_table_detail.is_some()    => table_detail::print_table_detail(hosts, ports, parallel, &options).await?,
         Opts { print_tablet_detail, ..       } if print_tablet_detail.is_some()    => tablet_detail::print_tablet_detail(hosts, ports, parallel, &options).await?,
+        Opts { print_statements, ..          } if print_statements.is_some()       => statements::print_statements(hosts, ports, parallel, &options).await?,
         Opts { get_coloc_leader_host, ..    } if get_coloc_leader_host.is_some() => entities::print_coloc_leader_host(hosts, ports, parallel, &options).await?,
         _                                                                        => snapshot::adhoc_diff(hosts, ports, parallel, &options).await?,
     };
+    if report_fetch_failures {
+        snapshot::print_fetch_failures();
+    }
     // if we are allowed to write, and changed_options does contain values, write them to '.env'
     utility::dotenv_writer(WRITE_DOTENV, changed_options)?;
 
@@ -7,9 +7,10 @@ use colored::*;
 use anyhow::Result;
 
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::masters::{Masters, MastersDiff, PeerRole, MastersDiffFields};
+use crate::masters::{Masters, MastersDiff, PeerRole, MastersDiffFields, MastersDiffRow};
+use crate::output::{self, Change, OutputFormat};
 use crate::Opts;
 use crate::snapshot::read_snapshot_json;
 
@@ -51,17 +52,21 @@ impl Masters {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut masters = Masters::read_http(host, port);
-                        masters.masters.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
-                        masters.masters.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
-                        tx.send(masters).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut masters = match Masters::read_http(host, port) {
+                        Ok(masters) => masters,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    masters.masters.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
+                    masters.masters.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
+                    tx.send(masters).expect("error sending data via tx");
+                });
             }
         });
 
@@ -80,10 +85,10 @@ impl Masters {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Masters
+    ) -> Result<Masters>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/masters");
-        Masters::parse_masters(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "api/v1/masters").into_body()?;
+        Ok(Masters::parse_masters(data_from_http, host, port))
     }
     fn parse_masters(
         http_data: String,
@@ -100,8 +105,16 @@ impl Masters {
         &self,
         details_enable: &bool,
         leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.masters.iter()
+                .filter(|row| row.hostname_port == Some(leader_hostname.clone()) || *details_enable)
+                .collect();
+            return output::print_rows(output, "masters", &rows);
+        }
         for row in &self.masters {
             // if details_enable is true then always continue
             // if details_enable is false, then hostname_port must equal to leader_hostname,
@@ -192,17 +205,14 @@ impl Masters {
             };
             println!(")");
             // fifth row: only if errors are reported
-            if row.error.is_some() {
+            if let Some(error) = &row.error {
                 if *details_enable {
                     print!("{} ", row.hostname_port
                         .as_ref()
                         .unwrap()
                     );
                 };
-                println!("{:#?}", row.error
-                    .as_ref()
-                    .unwrap()
-                );
+                println!("{:#?}", error);
             };
         }
         Ok(())
@@ -416,13 +426,61 @@ impl MastersDiff {
                 });
         }
     }
+    /// Create the rows for the masters that are changed between the snapshots.
+    pub fn rows(
+        &self,
+    ) -> Vec<MastersDiffRow<'_>>
+    {
+        let mut rows = Vec::new();
+        for (permanent_uuid, row) in &self.btreemastersdiff {
+            #[allow(clippy::nonminimal_bool)]
+            if row.first_instance_seqno == row.second_instance_seqno
+                && row.first_start_time_us == row.second_start_time_us
+                && row.first_placement_cloud == row.second_placement_cloud
+                && row.first_placement_region == row.second_placement_region
+                && row.first_placement_cloud == row.second_placement_cloud
+                && row.first_role == row.second_role
+                && row.first_private_rpc_addresses == row.second_private_rpc_addresses
+                && row.first_http_addresses == row.second_http_addresses
+            {
+                continue;
+            }
+            // a zero instance_seqno means the master is not found in that snapshot.
+            let change = if row.second_instance_seqno == 0
+            {
+                Change::Removed
+            }
+            else if row.first_instance_seqno == 0
+            {
+                Change::Added
+            }
+            else
+            {
+                Change::Changed
+            };
+            rows.push(MastersDiffRow {
+                change,
+                permanent_uuid,
+                fields: row,
+            });
+        }
+        rows
+    }
     pub fn print(
         &self,
-    )
+        output: &OutputFormat,
+    ) -> Result<()>
     {
         if ! self.master_found {
-            println!("Master leader was not found in the hosts specified, skipping masters diff.");
-            return;
+            if output.is_table()
+            {
+                println!("Master leader was not found in the hosts specified, skipping masters diff.");
+            }
+            return Ok(());
+        }
+        if !output.is_table()
+        {
+            return output::print_rows(output, "masters_diff", &self.rows());
         }
         for (permanent_uuid, row) in &self.btreemastersdiff {
             debug!("uuid: {}", permanent_uuid);
@@ -625,6 +683,7 @@ impl MastersDiff {
                     }
             }
         }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
@@ -655,14 +714,14 @@ pub async fn masters_diff(
 ) -> Result<()>
 {
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
     let (begin_snapshot, end_snapshot, _begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let mastersdiff = MastersDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    mastersdiff.print();
+    mastersdiff.print(&options.output)?;
 
     Ok(())
 }
@@ -680,13 +739,13 @@ pub async fn print_masters(
             let mut masters = Masters::new();
             masters.masters = snapshot::read_snapshot_json(snapshot_number, "masters")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
-            masters.print(&options.details_enable, leader_hostname)?;
+            masters.print(&options.details_enable, leader_hostname, &options.output)?;
 
         }
         None => {
             let masters = Masters::read_masters(&hosts, &ports, parallel).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
-            masters.print(&options.details_enable, leader_hostname)?;
+            masters.print(&options.details_enable, leader_hostname, &options.output)?;
         }
     }
     Ok(())
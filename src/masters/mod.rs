@@ -25,8 +25,8 @@
 //! - [crate] -> [print_masters] (prints adhoc (live) or snapshot masters info)
 //! - [crate] -> [masters_diff] (prints masters diff only, based on snapshot data)
 //! - [crate::snapshot::adhoc_diff] -> [crate::masters::MastersDiff::adhoc_read_first_snapshot],
-//! [crate::masters::MastersDiff::adhoc_read_second_snapshot],
-//! [crate::masters::MastersDiff::print] (prints masters diff, based on live data)
+//!   [crate::masters::MastersDiff::adhoc_read_second_snapshot],
+//!   [crate::masters::MastersDiff::print] (prints masters diff, based on live data)
 //!
 mod structs;
 mod functions;
@@ -4,6 +4,7 @@
 
 use std::collections::BTreeMap;
 use chrono::{DateTime, Local};
+use crate::output::Change;
 
 /// The root struct for deserializing `/api/v1/masters`.
 ///
@@ -164,7 +165,7 @@ pub struct MastersDiff {
 ///
 /// This performs a very simple way of diffing:
 /// For every field that makes sense to see the difference, create a first and second (snapshot) field.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct MastersDiffFields {
     pub first_instance_seqno: i64,
     pub first_start_time_us: u64,
@@ -184,4 +185,12 @@ pub struct MastersDiffFields {
     pub second_role: PeerRole,
     pub second_private_rpc_addresses: String,
     pub second_http_addresses: String,
-}
\ No newline at end of file
+}
+/// A row of the masters diff report, used for the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct MastersDiffRow<'a> {
+    pub change: Change,
+    pub permanent_uuid: &'a str,
+    #[serde(flatten)]
+    pub fields: &'a MastersDiffFields,
+}
@@ -1,9 +1,9 @@
 //! Module for reading the /memz output for the master and tablet server.
 //!
-use std::{fs, io::Write, sync::mpsc::channel, time::Instant, env};
+use std::{fs, io::Write, sync::mpsc::channel, time::Instant};
 use log::*;
 use anyhow::{Result, Context};
-use crate::utility;
+use crate::utility::{self, Role};
 
 pub struct Mems;
 
@@ -38,14 +38,18 @@ impl Mems {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let mems_data = Mems::read_http(host, port);
-                        tx.send((format!("{}:{}", host, port), mems_data)).expect("error sending data via tx (mems)");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver, Role::Ycql]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let mems_data = match Mems::read_http(host, port) {
+                        Ok(mems_data) => mems_data,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tx.send((format!("{}:{}", host, port), mems_data)).expect("error sending data via tx (mems)");
+                });
             }
         });
 
@@ -55,13 +59,13 @@ impl Mems {
             if mems_data.starts_with("------------------------------------------------")
                 && snapshot_number >= 0
             {
-                let current_directory = env::current_dir()?;
-                let current_snapshot_directory = current_directory.join("yb_stats.snapshots").join(snapshot_number.to_string());
+                let current_snapshot_directory = utility::snapshot_directory()?.join(snapshot_number.to_string());
 
                 let mems_file = &current_snapshot_directory.join(format!("mems_{}.txt", hostname_port));
                 let mut file = fs::OpenOptions::new()
                     .create(true)
                     .write(true)
+                    .truncate(true)
                     .open(mems_file)
                     .with_context(|| format!("Cannot create file: {}", mems_file.display()))?;
 
@@ -74,9 +78,9 @@ impl Mems {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> String
+    ) -> Result<String>
     {
-        utility::http_get(host, port, "memz?raw=true")
+        utility::http_get(host, port, "memz?raw=true").into_body()
     }
 }
 
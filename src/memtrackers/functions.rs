@@ -9,7 +9,8 @@ use anyhow::Result;
 use crate::snapshot;
 use crate::memtrackers::{MemTrackers, AllMemTrackers};
 use crate::Opts;
-use crate::utility;
+use crate::utility::{self, Role};
+use crate::output::{self, OutputFormat};
 
 impl AllMemTrackers {
     pub async fn perform_snapshot(
@@ -43,17 +44,21 @@ impl AllMemTrackers {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut memtrackers = AllMemTrackers::read_http(host, port);
-                        memtrackers.iter_mut().for_each(|r| r.timestamp = detail_snapshot_time);
-                        memtrackers.iter_mut().for_each(|r| r.hostname_port = format!("{}:{}", host, port));
-                        tx.send(memtrackers).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut memtrackers = match AllMemTrackers::read_http(host, port) {
+                        Ok(memtrackers) => memtrackers,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    memtrackers.iter_mut().for_each(|r| r.timestamp = detail_snapshot_time);
+                    memtrackers.iter_mut().for_each(|r| r.hostname_port = format!("{}:{}", host, port));
+                    tx.send(memtrackers).expect("error sending data via tx");
+                });
             }
         });
 
@@ -74,10 +79,10 @@ impl AllMemTrackers {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<MemTrackers>
+    ) -> Result<Vec<MemTrackers>>
     {
-        let data_from_http = utility::http_get(host, port, "mem-trackers");
-        AllMemTrackers::parse_memtrackers(data_from_http)
+        let data_from_http = utility::http_get(host, port, "mem-trackers").into_body()?;
+        Ok(AllMemTrackers::parse_memtrackers(data_from_http))
     }
     fn parse_memtrackers(
         http_data: String
@@ -152,10 +157,19 @@ impl AllMemTrackers {
         &self,
         hostname_filter: &Regex,
         stat_name_filter: &Regex,
+        output: &OutputFormat,
     ) -> Result<()>
     {
         info!("print_memtrackers");
 
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.memtrackers.iter()
+                .filter(|row| hostname_filter.is_match(&row.hostname_port) && stat_name_filter.is_match(&row.id))
+                .collect();
+            return output::print_rows(output, "memtrackers", &rows);
+        }
+
         let mut previous_hostname_port = String::from("");
         for row in &self.memtrackers
         {
@@ -198,11 +212,11 @@ pub async fn print_memtrackers(
         Some(snapshot_number) => {
             let mut allmemtrackers = AllMemTrackers::new();
             allmemtrackers.memtrackers = snapshot::read_snapshot_json(snapshot_number, "memtrackers")?;
-            allmemtrackers.print(&hostname_filter, &stat_name_filter)?;
+            allmemtrackers.print(&hostname_filter, &stat_name_filter, &options.output)?;
         },
         None => {
             let allmemtrackers = AllMemTrackers::read_memtrackers(&hosts, &ports, parallel).await;
-            allmemtrackers.print(&hostname_filter, &stat_name_filter)?;
+            allmemtrackers.print(&hostname_filter, &stat_name_filter, &options.output)?;
         },
     }
     Ok(())
@@ -0,0 +1,105 @@
+//! Utility module for derived statistics, which are calculated from the value and countsum statistics, such as ratios and averages.
+use DerivedTerm::{Count, Sum, Value};
+/// A figure of a value or countsum statistic in the metrics diff, used to calculate a derived statistic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivedTerm {
+    /// The difference of a value statistic.
+    Value(&'static str),
+    /// The count of a countsum statistic.
+    Count(&'static str),
+    /// The sum of a countsum statistic.
+    Sum(&'static str),
+}
+/// The struct that contains all the details for a derived statistic:
+/// the statistic is the sum of the numerator terms divided by the sum of the denominator terms, multiplied by the factor.
+#[derive(Debug, Clone)]
+pub struct DerivedStatisticDetails {
+    pub name: String,
+    pub unit: String,
+    pub factor: f64,
+    pub numerator: Vec<DerivedTerm>,
+    pub denominator: Vec<DerivedTerm>,
+}
+/// This struct is the main struct that provides the functionality for derived statistics.
+#[derive(Debug)]
+pub struct DerivedStatistics {
+    pub derivedstatisticdetails: Vec<DerivedStatisticDetails>
+}
+
+impl DerivedStatistics {
+    /// Create a struct holding a vector with all the derived statistics, in the order in which they are reported.
+    pub fn create() -> DerivedStatistics
+    {
+        let mut table = DerivedStatistics { derivedstatisticdetails: Vec::new() };
+        table.insert("block_cache_hit_ratio", "%", 100.0, &[Value("rocksdb_block_cache_hit")], &[Value("rocksdb_block_cache_hit"), Value("rocksdb_block_cache_miss")]);
+        table.insert("block_cache_data_hit_ratio", "%", 100.0, &[Value("rocksdb_block_cache_data_hit")], &[Value("rocksdb_block_cache_data_hit"), Value("rocksdb_block_cache_data_miss")]);
+        table.insert("block_cache_index_hit_ratio", "%", 100.0, &[Value("rocksdb_block_cache_index_hit")], &[Value("rocksdb_block_cache_index_hit"), Value("rocksdb_block_cache_index_miss")]);
+        table.insert("block_cache_filter_hit_ratio", "%", 100.0, &[Value("rocksdb_block_cache_filter_hit")], &[Value("rocksdb_block_cache_filter_hit"), Value("rocksdb_block_cache_filter_miss")]);
+        table.insert("bloom_filter_useful_ratio", "%", 100.0, &[Value("rocksdb_bloom_filter_useful")], &[Value("rocksdb_bloom_filter_checked")]);
+        table.insert("bloom_filter_prefix_useful_ratio", "%", 100.0, &[Value("rocksdb_bloom_filter_prefix_useful")], &[Value("rocksdb_bloom_filter_prefix_checked")]);
+        table.insert("seek_found_ratio", "%", 100.0, &[Value("rocksdb_number_db_seek_found")], &[Value("rocksdb_number_db_seek")]);
+        table.insert("bytes_per_key_written", "bytes", 1.0, &[Value("rocksdb_bytes_written")], &[Value("rocksdb_number_keys_written")]);
+        table.insert("wal_bytes_per_append", "bytes", 1.0, &[Value("log_bytes_logged")], &[Count("log_append_latency")]);
+        table.insert("wal_append_latency_average", "us", 1.0, &[Sum("log_append_latency")], &[Count("log_append_latency")]);
+        table.insert("wal_sync_latency_average", "us", 1.0, &[Sum("log_sync_latency")], &[Count("log_sync_latency")]);
+        table
+    }
+    fn insert(
+        &mut self,
+        name: &str,
+        unit: &str,
+        factor: f64,
+        numerator: &[DerivedTerm],
+        denominator: &[DerivedTerm],
+    )
+    {
+        self.derivedstatisticdetails.push(DerivedStatisticDetails {
+            name: name.to_string(),
+            unit: unit.to_string(),
+            factor,
+            numerator: numerator.to_vec(),
+            denominator: denominator.to_vec(),
+        });
+    }
+}
+
+impl DerivedStatisticDetails {
+    /// Calculate the derived statistic with the figures of the terms, which are zero if the statistic is not found.
+    /// If the denominator is zero, the derived statistic cannot be calculated, and None is returned.
+    pub fn calculate(
+        &self,
+        figure: impl Fn(&DerivedTerm) -> f64,
+    ) -> Option<f64>
+    {
+        let denominator: f64 = self.denominator.iter().map(&figure).sum();
+        if denominator == 0.0 {
+            return None;
+        }
+        let numerator: f64 = self.numerator.iter().map(&figure).sum();
+        Some(numerator / denominator * self.factor)
+    }
+}
+/// These are the unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculate_derived_statistic_ratio() {
+        let derived_statistics = DerivedStatistics::create();
+        let block_cache_hit_ratio = derived_statistics.derivedstatisticdetails.iter().find(|derived| derived.name == "block_cache_hit_ratio").unwrap();
+        let figure = |term: &DerivedTerm| match term {
+            Value("rocksdb_block_cache_hit") => 75.0,
+            Value("rocksdb_block_cache_miss") => 25.0,
+            _ => 0.0,
+        };
+        assert_eq!(block_cache_hit_ratio.calculate(figure), Some(75.0));
+    }
+
+    #[test]
+    fn calculate_derived_statistic_without_denominator() {
+        let derived_statistics = DerivedStatistics::create();
+        let wal_sync_latency_average = derived_statistics.derivedstatisticdetails.iter().find(|derived| derived.name == "wal_sync_latency_average").unwrap();
+        assert_eq!(wal_sync_latency_average.calculate(|_| 0.0), None);
+    }
+}
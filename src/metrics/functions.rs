@@ -1,15 +1,23 @@
 //! The  impls and functions
 //!
-use std::{sync::mpsc::channel, time::Instant};
+use std::{collections::{BTreeMap, BTreeSet, HashMap}, io, sync::mpsc::channel, time::Instant};
 use chrono::{DateTime, Local};
 use regex::Regex;
 use log::*;
-use anyhow::Result;
+use colored::*;
+use anyhow::{bail, Result};
 use crate::{metrics, utility};
+use crate::utility::Role;
 use crate::snapshot;
-use crate::metrics::{Metrics::{MetricValue, MetricCountSum, MetricCountSumRows}, MetricEntity, AllMetricEntity, MetricEntityDiff, MetricDiffValues, Attributes, MetricDiffCountSum, MetricDiffCountSumRows};
+use crate::restarts::Restarts;
+use crate::placements::{GroupBy, Placements};
+use crate::metrics::{Metrics::{self, MetricValue, MetricCountSum, MetricCountSumRows, RejectedU64MetricValue, RejectedBooleanMetricValue}, MetricEntity, AllMetricEntity, MetricEntityDiff, MetricDiffValues, Attributes, MetricDiffCountSum, MetricDiffCountSumRows, MetricValueDiffRow, MetricCountSumDiffRow, MetricCountSumRowsDiffRow, MetricSeriesRow, AggregationLevel, MetricDerivedDiffRow, DerivedTerm, StatisticCatalogEntry, StatisticKind, MetricStatesDiff, MetricStateDiffFields, MetricStateDiffRow};
+use crate::output::{self, Change, OutputFormat};
 use crate::Opts;
 
+/// The figures of the statistics of a server and table to calculate the derived statistics: the value differences, and the countsum count and sum.
+type DerivedFigures = (HashMap<String, f64>, HashMap<String, (f64, f64)>);
+
 impl AllMetricEntity {
     pub fn new() -> Self {
         Default::default()
@@ -42,17 +50,21 @@ impl AllMetricEntity {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut metrics = AllMetricEntity::read_http(host, port);
-                        metrics.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
-                        metrics.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
-                        tx.send(metrics).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver, Role::Ysql, Role::Ycql]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut metrics = match AllMetricEntity::read_http(host, port) {
+                        Ok(metrics) => metrics,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    metrics.iter_mut().for_each(|r| r.hostname_port = Some(format!("{}:{}", host, port)));
+                    metrics.iter_mut().for_each(|r| r.timestamp = Some(detail_snapshot_time));
+                    tx.send(metrics).expect("error sending data via tx");
+                });
             }
         });
 
@@ -73,10 +85,10 @@ impl AllMetricEntity {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<MetricEntity>
+    ) -> Result<Vec<MetricEntity>>
     {
-        let data_from_http = utility::http_get(host, port, "metrics");
-        AllMetricEntity::parse_metrics(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "metrics").into_body()?;
+        Ok(AllMetricEntity::parse_metrics(data_from_http, host, port))
     }
     fn parse_metrics(
         http_data: String,
@@ -90,6 +102,51 @@ impl AllMetricEntity {
                 Vec::<MetricEntity>::new()
             })
     }
+    /// Return the value and countsum statistics that are not in the statistic catalog, as catalog entries with the unit and type '?',
+    /// sorted by kind and name.
+    fn unknown_statistics(
+        &self,
+    ) -> Vec<StatisticCatalogEntry>
+    {
+        let value_statistics = metrics::ValueStatistics::create();
+        let countsum_statistics = metrics::CountSumStatistics::create();
+
+        let mut unknown = BTreeSet::new();
+        for metric in self.metricentity.iter().flat_map(|metricentity| &metricentity.metrics)
+        {
+            match metric
+            {
+                MetricValue { name, .. } if !value_statistics.valuestatisticdetails.contains_key(name) => { unknown.insert((StatisticKind::Value, name)); },
+                MetricCountSum { name, .. } if !countsum_statistics.countsumstatisticsdetails.contains_key(name) => { unknown.insert((StatisticKind::CountSum, name)); },
+                _ => {},
+            }
+        }
+        unknown.into_iter()
+            .map(|(kind, name)| StatisticCatalogEntry {
+                kind,
+                name: name.clone(),
+                unit: "?".to_string(),
+                stat_type: "?".to_string(),
+                version: String::new(),
+                description: String::new(),
+            })
+            .collect()
+    }
+}
+
+impl Metrics {
+    /// Return the name and the value of a value statistic, for which a value that only fits in an u64 is a value too.
+    pub fn wide_value(
+        &self,
+    ) -> Option<(String, i128)>
+    {
+        match self
+        {
+            MetricValue { name, value } => Some((name.clone(), *value as i128)),
+            RejectedU64MetricValue { name, value } => Some((name.clone(), *value as i128)),
+            _ => None,
+        }
+    }
 }
 
 impl MetricEntityDiff {
@@ -111,6 +168,9 @@ impl MetricEntityDiff {
         allmetricentity.metricentity = snapshot::read_snapshot_json(end_snapshot, "metrics")?;
         metricentitydiff.second_snapshot(allmetricentity, details_enable, begin_snapshot_time);
 
+        metricentitydiff.restarts = Restarts::snapshot_diff(begin_snapshot, end_snapshot)?;
+        metricentitydiff.placements = Placements::snapshot(end_snapshot)?;
+
         Ok(metricentitydiff)
     }
     fn first_snapshot(
@@ -125,8 +185,9 @@ impl MetricEntityDiff {
             {
                 match metric
                 {
-                    MetricValue { name, value } =>
+                    MetricValue { .. } | RejectedU64MetricValue { .. } =>
                         {
+                            let (name, value) = metric.wide_value().expect("a value statistic should have a value");
                             let changed_metrics_id = if !*details_enable
                                 && (metricentity.metrics_type.clone() == "table"
                                 || metricentity.metrics_type.clone() == "tablet"
@@ -303,9 +364,9 @@ impl MetricEntityDiff {
                                     ..Default::default()
                                 });
                         }
-                    _ =>
+                    RejectedBooleanMetricValue { name, value } =>
                         {
-                            debug!("Encountered unknown metric type: {:?}", metric);
+                            self.states.first_state(MetricStatesDiff::key(&metricentity.hostname_port, &metricentity.metrics_type, &metricentity.id, name), value);
                         }
                 }
             }
@@ -324,8 +385,9 @@ impl MetricEntityDiff {
             {
                 match metric
                 {
-                    MetricValue { name, value } =>
+                    MetricValue { .. } | RejectedU64MetricValue { .. } =>
                         {
+                            let (name, value) = metric.wide_value().expect("a value statistic should have a value");
                             let changed_metrics_id = if !*details_enable
                                 && (metricentity.metrics_type.clone() == "table"
                                 || metricentity.metrics_type.clone() == "tablet"
@@ -372,7 +434,7 @@ impl MetricEntityDiff {
                                     ..Default::default()
                                 });
                         }
-                    MetricCountSum { name, total_count, total_sum, .. } =>
+                    MetricCountSum { name, total_count, total_sum, percentile_95, percentile_99, max, .. } =>
                         {
                             let changed_metrics_id = if !*details_enable
                                 && (metricentity.metrics_type.clone() == "table"
@@ -398,6 +460,9 @@ impl MetricEntityDiff {
                                     row.second_snapshot_time = metricentity.timestamp.unwrap_or_default();
                                     row.second_total_count += total_count;
                                     row.second_total_sum += total_sum;
+                                    row.second_percentile_95 = row.second_percentile_95.max(percentile_95);
+                                    row.second_percentile_99 = row.second_percentile_99.max(percentile_99);
+                                    row.second_max = row.second_max.max(max);
                                 })
                                 .or_insert(MetricDiffCountSum {
                                     table_name: metricentity.attributes
@@ -419,6 +484,9 @@ impl MetricEntityDiff {
                                         .unwrap_or_default(),
                                     second_total_count: total_count,
                                     second_total_sum: total_sum,
+                                    second_percentile_95: percentile_95,
+                                    second_percentile_99: percentile_99,
+                                    second_max: max,
                                     ..Default::default()
                                 });
                         }
@@ -464,203 +532,419 @@ impl MetricEntityDiff {
                                     ..Default::default()
                                 });
                         }
-                    _ =>
+                    RejectedBooleanMetricValue { name, value } =>
                         {
-                            debug!("Encountered unknown metric type: {:?}", metric);
+                            self.states.second_state(MetricStatesDiff::key(&metricentity.hostname_port, &metricentity.metrics_type, &metricentity.id, name), value);
                         }
                 }
             }
         }
     }
-    pub async fn print(
+    /// Create the rows for the value statistics, filtered and with the counter/gauge logic applied.
+    ///
+    /// If zero_enable is set, the statistics that did not change or are zero are included too,
+    /// so every statistic in the snapshots gets a row, such as for a series or the exposition.
+    pub fn value_rows(
         &self,
         hostname_filter: &Regex,
         stat_name_filter: &Regex,
         table_name_filter: &Regex,
-        details_enable: &bool,
         gauges_enable: &bool,
-    )
+        zero_enable: &bool,
+    ) -> Vec<MetricValueDiffRow>
     {
-        // value_diff
         let value_statistics = metrics::ValueStatistics::create();
+        let mut rows = Vec::new();
         for ((hostname, metric_type, metric_id, metric_name), row) in &self.btreemetricdiffvalue
         {
             let metadata = value_statistics.lookup(metric_name);
             // if second_value = 0, the statistic was zero, meaning no use,
             // or it wasn't filled out for the second snapshot, meaning the endppoint went away.
-            // in both cases do not report.
-            if row.second_value > 0
+            // in both cases do not report, unless zero_enable is set.
+            if (row.second_value > 0 || *zero_enable)
                 && hostname_filter.is_match(hostname)
                 && stat_name_filter.is_match(metric_name)
                 && table_name_filter.is_match(&row.table_name)
             {
+                // a counter is reset if the server restarted, or if it went down.
+                // if so, the second value is the difference.
+                let reset = metadata.stat_type != "gauge"
+                    && (self.restarts.is_restarted(hostname) || row.second_value < row.first_value);
+                let difference = if reset { row.second_value } else { row.second_value - row.first_value };
+                let diff_row = MetricValueDiffRow {
+                    hostname_port: hostname.to_string(),
+                    metric_type: metric_type.to_string(),
+                    metric_id: metric_id.to_string(),
+                    table_name: table_info(&row.namespace, &row.table_name),
+                    metric_name: metric_name.to_string(),
+                    stat_type: metadata.stat_type.clone(),
+                    unit: metadata.unit.clone(),
+                    unit_suffix: metadata.unit_suffix.clone(),
+                    ..Default::default()
+                };
                 // show as counter.
                 // the choice of != gauge is deliberate here: if it's unknown, it'll be shown as counter.
                 if metadata.stat_type != "gauge"
-                    && row.second_value - row.first_value != 0
+                    && difference != 0
                 {
-                    if *details_enable
-                    {
-                        let table_info = if row.namespace.is_empty() && row.table_name.is_empty()
-                        {
-                            "".to_string()
-                        }
-                        else if row.namespace.is_empty()
-                        {
-                            row.table_name.clone()
-                        }
-                        else
-                        {
-                            format!("{}.{}", row.namespace, row.table_name)
-                        };
-
-                        //println!("{:20} {:8} {:32} {:15} {:30} {:70} {:15} {:6} {:>15.3} /s",
-                        println!("{:20} {:8} {:32} {:30} {:70} {:15} {:6} {:>15.3} /s",
-                                 hostname,
-                                 metric_type,
-                                 metric_id,
-                                 table_info,
-                                 metric_name,
-                                 row.second_value - row.first_value,
-                                 metadata.unit_suffix,
-                                 ((row.second_value - row.first_value) as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64)
-                        );
-                    }
-                    else
-                    {
-                        println!("{:20} {:8} {:70} {:15} {:6} {:>15.3} /s",
-                                 hostname,
-                                 metric_type,
-                                 metric_name,
-                                 row.second_value - row.first_value,
-                                 metadata.unit_suffix,
-                                 ((row.second_value - row.first_value) as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64)
-                        );
-                    }
+                    rows.push(MetricValueDiffRow {
+                        value: difference,
+                        difference,
+                        reset,
+                        rate: difference as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64,
+                        ..diff_row
+                    });
+                }
+                else if metadata.stat_type != "gauge"
+                    && *zero_enable
+                {
+                    rows.push(MetricValueDiffRow {
+                        reset,
+                        ..diff_row
+                    });
                 }
                 // show as gauge
                 // gauges are shown when the difference between first and second snapshot is zero,
                 // provided the absolute second value is higher than 0.
-                if metadata.stat_type == "gauge"
+                else if metadata.stat_type == "gauge"
                     && *gauges_enable
                 {
-                    if *details_enable
-                    {
-                        let table_info = if row.namespace.is_empty() && row.table_name.is_empty()
-                        {
-                            "".to_string()
-                        }
-                        else if row.namespace.is_empty()
-                        {
-                           row.table_name.clone()
-                        }
-                        else
-                        {
-                            format!("{}.{}", row.namespace, row.table_name)
-                        };
-                        println!("{:20} {:8} {:32} {:30} {:70} {:15} {:6} {:+15}",
-                                 hostname,
-                                 metric_type,
-                                 metric_id,
-                                 table_info,
-                                 metric_name,
-                                 row.second_value,
-                                 metadata.unit_suffix,
-                                 row.second_value - row.first_value
-                        );
-                    }
-                    else
-                    {
-                        println!("{:20} {:8} {:70} {:15} {:6} {:+15}",
-                                 hostname,
-                                 metric_type,
-                                 metric_name,
-                                 row.second_value,
-                                 metadata.unit_suffix,
-                                 row.second_value - row.first_value
-                        );
-                    }
+                    rows.push(MetricValueDiffRow {
+                        value: row.second_value,
+                        difference,
+                        ..diff_row
+                    });
                 }
             }
         }
-        // countsum_diff
+        rows
+    }
+    /// Create the rows for the countsum statistics.
+    /// If zero_enable is set, the statistics without count between the snapshots are included too.
+    pub fn countsum_rows(
+        &self,
+        hostname_filter: &Regex,
+        stat_name_filter: &Regex,
+        table_name_filter: &Regex,
+        zero_enable: &bool,
+    ) -> Vec<MetricCountSumDiffRow>
+    {
         let countsum_statistics = metrics::CountSumStatistics::create();
+        let mut rows = Vec::new();
         for ((hostname, metric_type, metric_id, metric_name), row) in &self.btreemetricdiffcountsum
         {
             let metadata = countsum_statistics.lookup(metric_name);
-            if row.second_total_count > 0
+            // if second_total_count = 0, the statistic was zero, meaning no use,
+            // or it wasn't filled out for the second snapshot, meaning the endpoint went away.
+            // in both cases do not report.
+            // if the server restarted, or the count went down, the statistic is reset,
+            // and the second values are the difference.
+            let reset = self.restarts.is_restarted(hostname) || row.second_total_count < row.first_total_count;
+            let (count, total) = if reset {
+                (row.second_total_count, row.second_total_sum)
+            } else {
+                (row.second_total_count - row.first_total_count, row.second_total_sum.saturating_sub(row.first_total_sum))
+            };
+            if ((row.second_total_count > 0 && count != 0) || *zero_enable)
                 && hostname_filter.is_match(hostname)
                 && stat_name_filter.is_match(metric_name)
                 && table_name_filter.is_match(&row.table_name)
             {
-                // if second_total_count = 0, the statistic was zero, meaning no use,
-                // or it wasn't filled out for the second snapshot, meaning the endpoint went away.
-                // in both cases do not report.
-                //if row.second_total_count - row.first_total_count != 0
-                // saturating_sub halts the value at 0 the first value happens to be higher.
-                if row.second_total_count.saturating_sub(row.first_total_count) != 0
+                rows.push(MetricCountSumDiffRow {
+                    hostname_port: hostname.to_string(),
+                    metric_type: metric_type.to_string(),
+                    metric_id: metric_id.to_string(),
+                    table_name: table_info(&row.namespace, &row.table_name),
+                    metric_name: metric_name.to_string(),
+                    stat_type: metadata.stat_type.clone(),
+                    count,
+                    rate: if count != 0 { count as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64 } else { 0_f64 },
+                    average: if count != 0 { total as f64 / count as f64 } else { 0_f64 },
+                    total,
+                    percentile_95: row.second_percentile_95,
+                    percentile_99: row.second_percentile_99,
+                    max: row.second_max,
+                    unit: metadata.unit.clone(),
+                    reset,
+                    unit_suffix: metadata.unit_suffix.clone(),
+                });
+            }
+        }
+        rows
+    }
+    /// Create the rows for the countsumrows statistics.
+    /// If zero_enable is set, the statistics without count between the snapshots are included too.
+    pub fn countsumrows_rows(
+        &self,
+        hostname_filter: &Regex,
+        stat_name_filter: &Regex,
+        zero_enable: &bool,
+    ) -> Vec<MetricCountSumRowsDiffRow>
+    {
+        let mut rows = Vec::new();
+        for ((hostname, metric_type, metric_id, metric_name), row) in &self.btreemetricdiffcountsumrows
+        {
+            // if the server restarted, or the count went down, the statistic is reset,
+            // and the second values are the difference.
+            let reset = self.restarts.is_restarted(hostname) || row.second_count < row.first_count;
+            let (count, sum, total_rows) = if reset {
+                (row.second_count, row.second_sum as f64, row.second_rows)
+            } else {
+                (row.second_count - row.first_count, row.second_sum as f64 - row.first_sum as f64, row.second_rows.saturating_sub(row.first_rows))
+            };
+            if hostname_filter.is_match(hostname)
+                && stat_name_filter.is_match(metric_name)
+                && (count != 0 || *zero_enable)
+            {
+                rows.push(MetricCountSumRowsDiffRow {
+                    hostname_port: hostname.to_string(),
+                    metric_type: metric_type.to_string(),
+                    metric_id: metric_id.to_string(),
+                    table_name: table_info(&row.namespace, &row.table_name),
+                    metric_name: metric_name.to_string(),
+                    count,
+                    rate: if count != 0 { count as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64 } else { 0_f64 },
+                    average_time_ms: if count != 0 { (sum / 1000.0) / count as f64 } else { 0_f64 },
+                    total_time_ms: sum / 1000.0,
+                    average_rows: total_rows.checked_div(count).unwrap_or_default(),
+                    total_rows,
+                    reset,
+                });
+            }
+        }
+        rows
+    }
+    /// The server of a statistic in the report: the placement of the server with group by, otherwise the `hostname_port` itself.
+    fn server(
+        &self,
+        hostname_port: &str,
+        group_by: &Option<GroupBy>,
+    ) -> String
+    {
+        match group_by {
+            Some(group_by) => self.placements.placement(hostname_port, group_by),
+            None => hostname_port.to_string(),
+        }
+    }
+    /// Create the rows for the derived statistics, such as the block cache hit ratio, calculated per server and table at the aggregation level.
+    ///
+    /// The derived statistics are calculated from all the value and countsum statistics, the statistic name filter is applied to the name of the derived statistic.
+    pub fn derived_rows(
+        &self,
+        hostname_filter: &Regex,
+        stat_name_filter: &Regex,
+        table_name_filter: &Regex,
+        aggregation: &AggregationLevel,
+        group_by: &Option<GroupBy>,
+    ) -> Vec<MetricDerivedDiffRow>
+    {
+        let all = Regex::new(".*").unwrap();
+        let mut value_rows = self.value_rows(hostname_filter, &all, table_name_filter, &false, &false);
+        let mut countsum_rows = self.countsum_rows(hostname_filter, &all, table_name_filter, &false);
+        value_rows.iter_mut().for_each(|row| row.hostname_port = self.server(&row.hostname_port, group_by));
+        countsum_rows.iter_mut().for_each(|row| row.hostname_port = self.server(&row.hostname_port, group_by));
+
+        // the figures per (hostname_port, metric_type, metric_id, table_name).
+        let mut figures: BTreeMap<(String, String, String, String), DerivedFigures> = BTreeMap::new();
+        for row in aggregate_value_rows(value_rows, aggregation)
+        {
+            figures.entry((row.hostname_port, row.metric_type, row.metric_id, row.table_name)).or_default()
+                .0.insert(row.metric_name, row.value as f64);
+        }
+        for row in aggregate_countsum_rows(countsum_rows, aggregation)
+        {
+            figures.entry((row.hostname_port, row.metric_type, row.metric_id, row.table_name)).or_default()
+                .1.insert(row.metric_name, (row.count as f64, row.total as f64));
+        }
+
+        let derived_statistics = metrics::DerivedStatistics::create();
+        let mut rows = Vec::new();
+        for ((hostname_port, metric_type, metric_id, table_name), (values, countsums)) in &figures
+        {
+            let figure = |term: &DerivedTerm| match term {
+                DerivedTerm::Value(name) => values.get(*name).copied().unwrap_or_default(),
+                DerivedTerm::Count(name) => countsums.get(*name).map(|(count, _)| *count).unwrap_or_default(),
+                DerivedTerm::Sum(name) => countsums.get(*name).map(|(_, sum)| *sum).unwrap_or_default(),
+            };
+            for derived in derived_statistics.derivedstatisticdetails.iter().filter(|derived| stat_name_filter.is_match(&derived.name))
+            {
+                if let Some(value) = derived.calculate(figure)
                 {
-                    if *details_enable
-                    {
-                        let table_info = if row.namespace.is_empty() && row.table_name.is_empty()
-                        {
-                            "".to_string()
-                        }
-                        else if row.namespace.is_empty()
-                        {
-                            row.table_name.clone()
-                        }
-                        else
-                        {
-                            format!("{}.{}", row.namespace, row.table_name)
-                        };
-                        println!("{:20} {:8} {:32} {:30} {:70} {:15}        {:>15.3} /s avg: {:9.0} tot: {:>15.3} {:10}",
-                                 hostname,
-                                 metric_type,
-                                 metric_id,
-                                 table_info,
-                                 metric_name,
-                                 row.second_total_count - row.first_total_count,
-                                 (row.second_total_count - row.first_total_count) as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64,
-                                 ((row.second_total_sum - row.first_total_sum) / (row.second_total_count - row.first_total_count)) as f64,
-                                 row.second_total_sum - row.first_total_sum,
-                                 metadata.unit_suffix
-                        );
-                    }
-                    else
-                    {
-                        println!("{:20} {:8} {:70} {:15}        {:>15.3} /s avg: {:9.0} tot: {:>15.3} {:10}",
-                                 hostname,
-                                 metric_type,
-                                 metric_name,
-                                 row.second_total_count - row.first_total_count,
-                                 (row.second_total_count - row.first_total_count) as f64 / (row.second_snapshot_time - row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64,
-                                 ((row.second_total_sum - row.first_total_sum) / (row.second_total_count - row.first_total_count)) as f64,
-                                 row.second_total_sum - row.first_total_sum,
-                                 metadata.unit_suffix
-                        );
-                    }
+                    rows.push(MetricDerivedDiffRow {
+                        hostname_port: hostname_port.clone(),
+                        metric_type: metric_type.clone(),
+                        metric_id: metric_id.clone(),
+                        table_name: table_name.clone(),
+                        metric_name: derived.name.clone(),
+                        value,
+                        unit: derived.unit.clone(),
+                    });
                 }
             }
         }
+        rows
+    }
+    /// Print the metrics diff report, with the statistics added up at the aggregation level.
+    ///
+    /// With group by, the server of each statistic is replaced by its placement before adding up, so the statistics are added up per placement.
+    /// The table and tablet levels print the metric id and table name too.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn print(
+        &self,
+        hostname_filter: &Regex,
+        stat_name_filter: &Regex,
+        table_name_filter: &Regex,
+        aggregation: &AggregationLevel,
+        group_by: &Option<GroupBy>,
+        gauges_enable: &bool,
+        tail_latency_sort: &bool,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let details_enable = &aggregation.needs_details();
+        let mut value_rows = self.value_rows(hostname_filter, stat_name_filter, table_name_filter, gauges_enable, &false);
+        let mut countsum_rows = self.countsum_rows(hostname_filter, stat_name_filter, table_name_filter, &false);
+        let mut countsumrows_rows = self.countsumrows_rows(hostname_filter, stat_name_filter, &false);
+        if group_by.is_some()
+        {
+            let servers = value_rows.iter().map(|row| row.hostname_port.as_str())
+                .chain(countsum_rows.iter().map(|row| row.hostname_port.as_str()))
+                .chain(countsumrows_rows.iter().map(|row| row.hostname_port.as_str()));
+            for hostname_port in self.placements.unknown_servers(servers)
+            {
+                output::print_message(output, &format!("Warning: no placement found for {}, its statistics are added up as unknown. Use the hostnames as registered with the masters for --hosts.", hostname_port));
+            }
+        }
+        value_rows.iter_mut().for_each(|row| row.hostname_port = self.server(&row.hostname_port, group_by));
+        countsum_rows.iter_mut().for_each(|row| row.hostname_port = self.server(&row.hostname_port, group_by));
+        countsumrows_rows.iter_mut().for_each(|row| row.hostname_port = self.server(&row.hostname_port, group_by));
+        let value_rows = aggregate_value_rows(value_rows, aggregation);
+        let mut countsum_rows = aggregate_countsum_rows(countsum_rows, aggregation);
+        if *tail_latency_sort
+        {
+            sort_by_tail_latency(&mut countsum_rows);
+        }
+        let countsumrows_rows = aggregate_countsumrows_rows(countsumrows_rows, aggregation);
+        let derived_rows = self.derived_rows(hostname_filter, stat_name_filter, table_name_filter, aggregation, group_by);
+
+        if !output.is_table()
+        {
+            output::print_rows(output, "metrics_value", &value_rows)?;
+            output::print_rows(output, "metrics_countsum", &countsum_rows)?;
+            output::print_rows(output, "metrics_countsumrows", &countsumrows_rows)?;
+            output::print_rows(output, "metrics_derived", &derived_rows)?;
+            return Ok(());
+        }
+
+        if !self.restarts.servers.is_empty()
+        {
+            println!("restarted between the snapshots: {}, the statistics of these are since the restart (reset)", self.restarts.list());
+        }
+        // value_diff
+        for row in &value_rows
+        {
+            if row.stat_type != "gauge"
+            {
+                if *details_enable
+                {
+                    println!("{:20} {:8} {:32} {:30} {:70} {:15} {:6} {:>15.3} /s{}", row.hostname_port, row.metric_type, row.metric_id, row.table_name, row.metric_name, row.value, row.unit_suffix, row.rate, reset_marker(row.reset));
+                }
+                else
+                {
+                    println!("{:20} {:8} {:70} {:15} {:6} {:>15.3} /s{}", row.hostname_port, row.metric_type, row.metric_name, row.value, row.unit_suffix, row.rate, reset_marker(row.reset));
+                }
+            }
+            else if *details_enable
+            {
+                println!("{:20} {:8} {:32} {:30} {:70} {:15} {:6} {:+15}{}", row.hostname_port, row.metric_type, row.metric_id, row.table_name, row.metric_name, row.value, row.unit_suffix, row.difference, reset_marker(row.reset));
+            }
+            else
+            {
+                println!("{:20} {:8} {:70} {:15} {:6} {:+15}{}", row.hostname_port, row.metric_type, row.metric_name, row.value, row.unit_suffix, row.difference, reset_marker(row.reset));
+            }
+        }
+        // countsum_diff
+        for row in &countsum_rows
+        {
+            // the tail latency is shown for the latency histograms only.
+            let tail_latency = if row.metric_name.contains("latency")
+            {
+                format!(" p95: {:9} p99: {:9} max: {:9}", row.percentile_95, row.percentile_99, row.max)
+            }
+            else
+            {
+                String::new()
+            };
+            if *details_enable
+            {
+                println!("{:20} {:8} {:32} {:30} {:70} {:15}        {:>15.3} /s avg: {:9.0} tot: {:>15.3} {:10}{}{}", row.hostname_port, row.metric_type, row.metric_id, row.table_name, row.metric_name, row.count, row.rate, row.average, row.total, row.unit_suffix, tail_latency, reset_marker(row.reset));
+            }
+            else
+            {
+                println!("{:20} {:8} {:70} {:15}        {:>15.3} /s avg: {:9.0} tot: {:>15.3} {:10}{}{}", row.hostname_port, row.metric_type, row.metric_name, row.count, row.rate, row.average, row.total, row.unit_suffix, tail_latency, reset_marker(row.reset));
+            }
+        }
         // countsumrows_diff
-        for ((hostname, _metric_type, _metric_id, metric_name), row) in &self.btreemetricdiffcountsumrows
+        for row in &countsumrows_rows
         {
-            if hostname_filter.is_match(hostname)
-                && stat_name_filter.is_match(metric_name)
-                && row.second_count - row.first_count != 0
+            println!("{:20} {:70} {:>15} avg: {:>15.3} tot: {:>15.3} ms, avg: {:>15} tot: {:>15} rows{}", row.hostname_port, row.metric_name, row.count, row.average_time_ms, row.total_time_ms, row.average_rows, row.total_rows, reset_marker(row.reset));
+        }
+        // derived
+        for row in &derived_rows
+        {
+            if *details_enable
             {
-                println!("{:20} {:70} {:>15} avg: {:>15.3} tot: {:>15.3} ms, avg: {:>15} tot: {:>15} rows",
-                         hostname,
-                         metric_name,
-                         row.second_count - row.first_count,
-                         ((row.second_sum as f64 - row.first_sum as f64) / 1000.0) / (row.second_count - row.first_count) as f64,
-                         (row.second_sum as f64 - row.first_sum as f64) / 1000.0,
-                         (row.second_rows - row.first_rows) / (row.second_count - row.first_count),
-                         row.second_rows - row.first_rows
-                );
+                println!("{:20} {:8} {:32} {:30} {:70} {:>15.3} {}", row.hostname_port, row.metric_type, row.metric_id, row.table_name, row.metric_name, row.value, row.unit);
             }
+            else
+            {
+                println!("{:20} {:8} {:70} {:>15.3} {}", row.hostname_port, row.metric_type, row.metric_name, row.value, row.unit);
+            }
+        }
+        Ok(())
+    }
+    /// Create the rows for the interval between the begin and end snapshot of this diff for the metrics series report.
+    ///
+    /// The counters and countsum statistics are added up per server and statistic name as rates per second,
+    /// and the gauges as the value in the end snapshot, if gauges are enabled.
+    /// Every statistic in the snapshots gets a row, with zero if it did not change, so the series has no gaps.
+    pub fn series_rows(
+        &self,
+        begin_snapshot: &snapshot::Snapshot,
+        end_snapshot: &snapshot::Snapshot,
+        hostname_filter: &Regex,
+        stat_name_filter: &Regex,
+        table_name_filter: &Regex,
+        gauges_enable: &bool,
+    ) -> Vec<MetricSeriesRow>
+    {
+        let mut series: BTreeMap<(String, String), MetricSeriesRow> = BTreeMap::new();
+        let mut add = |hostname_port: &str, metric_name: &str, stat_type: &str, unit: &str, unit_suffix: &str, value: f64, reset: bool| {
+            let row = series.entry((hostname_port.to_string(), metric_name.to_string()))
+                .or_insert_with(|| MetricSeriesRow {
+                    hostname_port: hostname_port.to_string(),
+                    metric_name: metric_name.to_string(),
+                    stat_type: stat_type.to_string(),
+                    begin_snapshot: begin_snapshot.number,
+                    end_snapshot: end_snapshot.number,
+                    timestamp: end_snapshot.timestamp,
+                    unit: unit.to_string(),
+                    unit_suffix: unit_suffix.to_string(),
+                    ..Default::default()
+                });
+            row.value += value;
+            row.reset |= reset;
+        };
+        for row in self.value_rows(hostname_filter, stat_name_filter, table_name_filter, gauges_enable, &true)
+        {
+            let value = if row.stat_type == "gauge" { row.value as f64 } else { row.rate };
+            add(&row.hostname_port, &row.metric_name, &row.stat_type, &row.unit, &row.unit_suffix, value, row.reset);
         }
+        for row in self.countsum_rows(hostname_filter, stat_name_filter, table_name_filter, &true)
+        {
+            add(&row.hostname_port, &row.metric_name, &row.stat_type, &row.unit, &row.unit_suffix, row.rate, row.reset);
+        }
+        series.into_values().collect()
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
@@ -687,12 +971,383 @@ impl MetricEntityDiff {
     }
 }
 
+impl MetricStatesDiff {
+    pub fn new() -> Self { Default::default() }
+    pub fn snapshot_diff(
+        begin_snapshot: &String,
+        end_snapshot: &String,
+    ) -> Result<MetricStatesDiff>
+    {
+        let mut metricstatesdiff = MetricStatesDiff::new();
+
+        let mut allmetricentity = AllMetricEntity::new();
+        allmetricentity.metricentity = snapshot::read_snapshot_json(begin_snapshot, "metrics")?;
+        metricstatesdiff.first_snapshot(allmetricentity);
+
+        let mut allmetricentity = AllMetricEntity::new();
+        allmetricentity.metricentity = snapshot::read_snapshot_json(end_snapshot, "metrics")?;
+        metricstatesdiff.second_snapshot(allmetricentity);
+
+        Ok(metricstatesdiff)
+    }
+    /// Create the key of a state: `hostname_port`, `metric_type`, `metric_id`, `metric_name`.
+    fn key(
+        hostname_port: &Option<String>,
+        metric_type: &str,
+        metric_id: &str,
+        metric_name: String,
+    ) -> (String, String, String, String)
+    {
+        (hostname_port.clone().expect("hostname:port should be set"), metric_type.to_string(), metric_id.to_string(), metric_name)
+    }
+    fn first_state(
+        &mut self,
+        key: (String, String, String, String),
+        value: bool,
+    )
+    {
+        self.btreemetricstatesdiff
+            .entry(key.clone())
+            .and_modify(|_| error!("Duplicate metric state entry: {:?}", key))
+            .or_insert(MetricStateDiffFields {
+                first_value: Some(value),
+                ..Default::default()
+            });
+    }
+    fn second_state(
+        &mut self,
+        key: (String, String, String, String),
+        value: bool,
+    )
+    {
+        self.btreemetricstatesdiff
+            .entry(key)
+            .or_default()
+            .second_value = Some(value);
+    }
+    fn first_snapshot(
+        &mut self,
+        allmetricentity: AllMetricEntity,
+    )
+    {
+        for metricentity in allmetricentity.metricentity
+        {
+            for metric in metricentity.metrics
+            {
+                if let RejectedBooleanMetricValue { name, value } = metric
+                {
+                    self.first_state(MetricStatesDiff::key(&metricentity.hostname_port, &metricentity.metrics_type, &metricentity.id, name), value);
+                }
+            }
+        }
+    }
+    fn second_snapshot(
+        &mut self,
+        allmetricentity: AllMetricEntity,
+    )
+    {
+        for metricentity in allmetricentity.metricentity
+        {
+            for metric in metricentity.metrics
+            {
+                if let RejectedBooleanMetricValue { name, value } = metric
+                {
+                    self.second_state(MetricStatesDiff::key(&metricentity.hostname_port, &metricentity.metrics_type, &metricentity.id, name), value);
+                }
+            }
+        }
+    }
+    /// Create the rows for the states that flipped between the snapshots.
+    /// A state that is only found in one of the snapshots is not reported, because the server or entity was added or removed.
+    pub fn rows(
+        &self,
+        hostname_filter: &Regex,
+    ) -> Vec<MetricStateDiffRow<'_>>
+    {
+        self.btreemetricstatesdiff.iter()
+            .filter(|((hostname_port, ..), _)| hostname_filter.is_match(hostname_port))
+            .filter(|(_, row)| row.first_value.is_some()
+                && row.second_value.is_some()
+                && row.first_value != row.second_value)
+            .map(|((hostname_port, metric_type, metric_id, metric_name), row)| MetricStateDiffRow {
+                change: Change::Changed,
+                hostname_port,
+                metric_type,
+                metric_id,
+                metric_name,
+                fields: row,
+            })
+            .collect()
+    }
+    pub fn print(
+        &self,
+        hostname_filter: &Regex,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let rows = self.rows(hostname_filter);
+        if !output.is_table()
+        {
+            return output::print_rows(output, "metric_states_diff", &rows);
+        }
+        for MetricStateDiffRow { hostname_port, metric_type, metric_name, fields: row, .. } in rows
+        {
+            println!("{} {:20} State: {:8} {:50} {}->{}",
+                     "=".to_string().yellow(),
+                     hostname_port,
+                     metric_type,
+                     metric_name,
+                     row.first_value.unwrap_or_default().to_string().yellow(),
+                     row.second_value.unwrap_or_default().to_string().yellow(),
+            );
+        }
+        Ok(())
+    }
+    pub async fn adhoc_read_first_snapshot(
+        &mut self,
+        hosts: &Vec<&str>,
+        ports: &Vec<&str>,
+        parallel: usize,
+    )
+    {
+        let allmetricentity = AllMetricEntity::read_metrics(hosts, ports, parallel).await;
+        self.first_snapshot(allmetricentity);
+    }
+    pub async fn adhoc_read_second_snapshot(
+        &mut self,
+        hosts: &Vec<&str>,
+        ports: &Vec<&str>,
+        parallel: usize,
+    )
+    {
+        let allmetricentity = AllMetricEntity::read_metrics(hosts, ports, parallel).await;
+        self.second_snapshot(allmetricentity);
+    }
+}
+
+impl AggregationLevel {
+    /// The aggregation level set with `--aggregation`, or otherwise tablet with `--details-enable`, and host without.
+    ///
+    /// The cluster and table levels add up the statistics of all servers, so these cannot be grouped by placement with `--group-by`.
+    pub fn from_options(
+        aggregation: &Option<AggregationLevel>,
+        details_enable: &bool,
+        group_by: &Option<GroupBy>,
+    ) -> Result<Self>
+    {
+        let aggregation = match aggregation {
+            Some(aggregation) => *aggregation,
+            None if *details_enable => AggregationLevel::Tablet,
+            None => AggregationLevel::Host,
+        };
+        if group_by.is_some() && matches!(aggregation, AggregationLevel::Cluster | AggregationLevel::Table) {
+            bail!("Invalid --group-by: the cluster and table aggregation levels add up the statistics of all servers, use --group-by with --aggregation host or tablet");
+        }
+        Ok(aggregation)
+    }
+    /// The table and tablet levels need the statistics per table and tablet,
+    /// which the diff only keeps with details enabled.
+    pub fn needs_details(
+        &self,
+    ) -> bool
+    {
+        matches!(self, AggregationLevel::Table | AggregationLevel::Tablet)
+    }
+    /// Create the key of a row at this aggregation level: `hostname_port`, `metric_type`, `metric_id` and `table_name`.
+    ///
+    /// The fields that are added up are replaced: the server by "-" for the cluster and table levels,
+    /// and the table and tablet of a table, tablet or cdc statistic by "-" and an empty table name for the cluster and host levels.
+    fn key(
+        &self,
+        hostname_port: &str,
+        metric_type: &str,
+        metric_id: &str,
+        table_name: &str,
+    ) -> (String, String, String, String)
+    {
+        let table_type = is_table_metric_type(metric_type);
+        let hostname_port = match self {
+            AggregationLevel::Cluster | AggregationLevel::Table => "-",
+            AggregationLevel::Host | AggregationLevel::Tablet => hostname_port,
+        };
+        let (metric_id, table_name) = match self {
+            AggregationLevel::Cluster | AggregationLevel::Host if table_type => ("-", ""),
+            AggregationLevel::Table if table_type => ("-", table_name),
+            _ => (metric_id, table_name),
+        };
+        (hostname_port.to_string(), metric_type.to_string(), metric_id.to_string(), table_name.to_string())
+    }
+}
+
+/// The table, tablet and cdc statistics are the statistics that are added up per server without details.
+fn is_table_metric_type(
+    metric_type: &str,
+) -> bool
+{
+    matches!(metric_type, "table" | "tablet" | "cdc" | "cdcsdk")
+}
+
+/// Add up the value rows at the aggregation level.
+fn aggregate_value_rows(
+    rows: Vec<MetricValueDiffRow>,
+    aggregation: &AggregationLevel,
+) -> Vec<MetricValueDiffRow>
+{
+    let mut aggregated: BTreeMap<(String, String, String, String, String), MetricValueDiffRow> = BTreeMap::new();
+    for row in rows
+    {
+        let (hostname_port, metric_type, metric_id, table_name) = aggregation.key(&row.hostname_port, &row.metric_type, &row.metric_id, &row.table_name);
+        aggregated.entry((hostname_port.clone(), metric_type.clone(), metric_id.clone(), table_name.clone(), row.metric_name.clone()))
+            .and_modify(|aggregated_row| {
+                aggregated_row.value += row.value;
+                aggregated_row.difference += row.difference;
+                aggregated_row.rate += row.rate;
+                aggregated_row.reset |= row.reset;
+            })
+            .or_insert(MetricValueDiffRow { hostname_port, metric_type, metric_id, table_name, ..row });
+    }
+    aggregated.into_values().collect()
+}
+
+/// Add up the countsum rows at the aggregation level.
+/// The average is calculated from the added up count and total, and the tail latency is the highest of the rows.
+fn aggregate_countsum_rows(
+    rows: Vec<MetricCountSumDiffRow>,
+    aggregation: &AggregationLevel,
+) -> Vec<MetricCountSumDiffRow>
+{
+    let mut aggregated: BTreeMap<(String, String, String, String, String), MetricCountSumDiffRow> = BTreeMap::new();
+    for row in rows
+    {
+        let (hostname_port, metric_type, metric_id, table_name) = aggregation.key(&row.hostname_port, &row.metric_type, &row.metric_id, &row.table_name);
+        aggregated.entry((hostname_port.clone(), metric_type.clone(), metric_id.clone(), table_name.clone(), row.metric_name.clone()))
+            .and_modify(|aggregated_row| {
+                aggregated_row.count += row.count;
+                aggregated_row.total += row.total;
+                aggregated_row.rate += row.rate;
+                aggregated_row.average = aggregated_row.total as f64 / aggregated_row.count as f64;
+                aggregated_row.percentile_95 = aggregated_row.percentile_95.max(row.percentile_95);
+                aggregated_row.percentile_99 = aggregated_row.percentile_99.max(row.percentile_99);
+                aggregated_row.max = aggregated_row.max.max(row.max);
+                aggregated_row.reset |= row.reset;
+            })
+            .or_insert(MetricCountSumDiffRow { hostname_port, metric_type, metric_id, table_name, ..row });
+    }
+    aggregated.into_values().collect()
+}
+
+/// Add up the countsumrows rows at the aggregation level.
+/// The averages are calculated from the added up count and totals.
+fn aggregate_countsumrows_rows(
+    rows: Vec<MetricCountSumRowsDiffRow>,
+    aggregation: &AggregationLevel,
+) -> Vec<MetricCountSumRowsDiffRow>
+{
+    let mut aggregated: BTreeMap<(String, String, String, String, String), MetricCountSumRowsDiffRow> = BTreeMap::new();
+    for row in rows
+    {
+        let (hostname_port, metric_type, metric_id, table_name) = aggregation.key(&row.hostname_port, &row.metric_type, &row.metric_id, &row.table_name);
+        aggregated.entry((hostname_port.clone(), metric_type.clone(), metric_id.clone(), table_name.clone(), row.metric_name.clone()))
+            .and_modify(|aggregated_row| {
+                aggregated_row.count += row.count;
+                aggregated_row.rate += row.rate;
+                aggregated_row.total_time_ms += row.total_time_ms;
+                aggregated_row.total_rows += row.total_rows;
+                aggregated_row.average_time_ms = aggregated_row.total_time_ms / aggregated_row.count as f64;
+                aggregated_row.average_rows = aggregated_row.total_rows / aggregated_row.count;
+                aggregated_row.reset |= row.reset;
+            })
+            .or_insert(MetricCountSumRowsDiffRow { hostname_port, metric_type, metric_id, table_name, ..row });
+    }
+    aggregated.into_values().collect()
+}
+
+/// The marker for a statistic that is reset between the snapshots in the table output.
+fn reset_marker(
+    reset: bool,
+) -> &'static str
+{
+    if reset { " reset" } else { "" }
+}
+
+/// The characters for the ASCII sparkline, from low to high.
+const SPARKLINE_CHARACTERS: [char; 8] = ['_', '.', '-', '~', '=', '+', '*', '#'];
+
+/// Create an ASCII sparkline for the values, scaled between the lowest and the highest value.
+/// If all values are equal, the sparkline is flat at the bottom.
+fn sparkline(
+    values: &[f64],
+) -> String
+{
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    values.iter()
+        .map(|value| {
+            if max > min {
+                SPARKLINE_CHARACTERS[((value - min) / (max - min) * (SPARKLINE_CHARACTERS.len() - 1) as f64).round() as usize]
+            } else {
+                SPARKLINE_CHARACTERS[0]
+            }
+        })
+        .collect()
+}
+
+/// Print the metrics series rows, grouped per server and statistic, with optionally a sparkline for each of these.
+///
+/// The rows must be sorted by server and statistic.
+fn print_series(
+    rows: &[MetricSeriesRow],
+    sparkline_enable: &bool,
+)
+{
+    for group in rows.chunk_by(|a, b| a.hostname_port == b.hostname_port && a.metric_name == b.metric_name)
+    {
+        println!("{:20} {:70} {}", group[0].hostname_port, group[0].metric_name, group[0].stat_type);
+        for row in group
+        {
+            let unit = if row.stat_type == "gauge" { row.unit_suffix.clone() } else { format!("{} /s", row.unit_suffix) };
+            println!("{:>6} - {:<6} {:30} {:>15.3} {}{}", row.begin_snapshot, row.end_snapshot, row.timestamp, row.value, unit, reset_marker(row.reset));
+        }
+        if *sparkline_enable
+        {
+            let values: Vec<f64> = group.iter().map(|row| row.value).collect();
+            let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            println!("{:>15} {} min: {:.3} max: {:.3}", "sparkline:", sparkline(&values), min, max);
+        }
+    }
+}
+
+/// Sort the countsum rows by the tail latency: the 99th percentile, then the maximum, highest first.
+fn sort_by_tail_latency(
+    rows: &mut [MetricCountSumDiffRow],
+)
+{
+    rows.sort_by_key(|row| std::cmp::Reverse((row.percentile_99, row.max)));
+}
+
+/// Create the table info as `namespace.table_name`, or the table name only if there is no namespace.
+fn table_info(
+    namespace: &str,
+    table_name: &str,
+) -> String
+{
+    if namespace.is_empty()
+    {
+        table_name.to_string()
+    }
+    else
+    {
+        format!("{}.{}", namespace, table_name)
+    }
+}
+
 pub async fn metrics_diff(
     options: &Opts,
 ) -> Result<()>
 {
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
@@ -702,8 +1357,77 @@ pub async fn metrics_diff(
 
     let (begin_snapshot, end_snapshot, begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
-    let metricsdiff = MetricEntityDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp, &options.details_enable)?;
-    metricsdiff.print(&hostname_filter, &stat_name_filter, &table_name_filter, &options.details_enable, &options.gauges_enable).await;
+    let aggregation = AggregationLevel::from_options(&options.aggregation, &options.details_enable, &options.group_by)?;
+    let metricsdiff = MetricEntityDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp, &aggregation.needs_details())?;
+    metricsdiff.print(&hostname_filter, &stat_name_filter, &table_name_filter, &aggregation, &options.group_by, &options.gauges_enable, &options.tail_latency_sort, &options.output).await?;
+
+    Ok(())
+}
+
+/// Walk every snapshot between the begin and end snapshot, and report the figures of the statistics matched by `--stat-name-match` per interval.
+pub async fn metrics_series(
+    options: &Opts,
+) -> Result<()>
+{
+    if options.begin.is_none() || options.end.is_none() {
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
+    }
+    if options.snapshot_list { return Ok(()) };
+    // without a filter, the series report would be a series for every statistic of every server.
+    if options.stat_name_match.is_none() {
+        bail!("--metrics-series requires --stat-name-match to select the statistics");
+    }
+
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+    let stat_name_filter = utility::set_regex(&options.stat_name_match);
+    let table_name_filter = utility::set_regex(&options.table_name_match);
+
+    let (begin_snapshot, end_snapshot, _) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
+    let snapshots = snapshot::Snapshot::series(begin_snapshot.parse()?, end_snapshot.parse()?)?;
+
+    let mut rows = Vec::new();
+    for interval in snapshots.windows(2)
+    {
+        let metricsdiff = MetricEntityDiff::snapshot_diff(&interval[0].number.to_string(), &interval[1].number.to_string(), &interval[0].timestamp, &options.details_enable)?;
+        rows.extend(metricsdiff.series_rows(&interval[0], &interval[1], &hostname_filter, &stat_name_filter, &table_name_filter, &options.gauges_enable));
+    }
+    // the intervals are added in snapshot order, so a stable sort keeps them in that order per server and statistic.
+    rows.sort_by(|a, b| (&a.hostname_port, &a.metric_name).cmp(&(&b.hostname_port, &b.metric_name)));
+
+    if !options.output.is_table()
+    {
+        return output::print_rows(&options.output, "metrics_series", &rows);
+    }
+    print_series(&rows, &options.sparkline);
+
+    Ok(())
+}
+
+/// List the value and countsum statistics of a snapshot, or of the current metrics, that are not in the statistic catalog,
+/// in the csv format of the statistics file, so these can be completed and used with `--statistics-file`.
+pub async fn list_unknown_statistics(
+    hosts: Vec<&str>,
+    ports: Vec<&str>,
+    parallel: usize,
+    options: &Opts,
+) -> Result<()>
+{
+    let allmetricentity = match options.list_unknown_statistics.as_ref().unwrap() {
+        Some(snapshot_number) => {
+            let mut allmetricentity = AllMetricEntity::new();
+            allmetricentity.metricentity = snapshot::read_snapshot_json(snapshot_number, "metrics")?;
+            allmetricentity
+        },
+        None => AllMetricEntity::read_metrics(&hosts, &ports, parallel).await,
+    };
+
+    // stderr, so the catalog entries can be redirected to a statistics file.
+    eprintln!("{}", metrics::statistic_catalog_coverage());
+    let mut writer = csv::Writer::from_writer(io::stdout().lock());
+    for entry in allmetricentity.unknown_statistics() {
+        writer.serialize(entry)?;
+    }
+    writer.flush()?;
 
     Ok(())
 }
@@ -1099,6 +1823,311 @@ mod tests {
             };
         }
 
+        /// Create a metric entity for a table with a log_sync_latency countsum, as it's stored in a snapshot.
+        fn table_countsum(id: &str, total_count: u64, percentile_99: u64, max: u64, timestamp: DateTime<Local>) -> MetricEntity {
+            let json = format!(r#"
+    [
+        {{
+            "type": "table",
+            "id": "{}",
+            "attributes": {{ "namespace_name": "yugabyte", "table_name": "benchmark_table", "table_id": "{}" }},
+            "metrics": [
+                {{ "name": "log_sync_latency", "total_count": {}, "min": 0, "mean": 0.0, "percentile_75": 0, "percentile_95": {}, "percentile_99": {}, "percentile_99_9": 0, "percentile_99_99": 0, "max": {}, "total_sum": {} }}
+            ]
+        }}
+    ]"#, id, id, total_count, percentile_99 / 2, percentile_99, max, total_count * 10);
+            let mut result = AllMetricEntity::parse_metrics(json, "", "");
+            result[0].hostname_port = Some("yb-1.local:9000".to_string());
+            result[0].timestamp = Some(timestamp);
+            result.remove(0)
+        }
+
+        #[test]
+        fn unit_countsum_rows_tail_latency() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 10, 100, 200, begin), table_countsum("b", 10, 300, 400, begin)] }, &false);
+            diff.second_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 20, 500, 900, end), table_countsum("b", 30, 700, 800, end)] }, &false, &begin);
+            let all = Regex::new(".*").unwrap();
+            let rows = diff.countsum_rows(&all, &all, &all, &false);
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].count, 30);
+            // the tail latency of the added up tables is the highest of the end snapshot.
+            assert_eq!((rows[0].percentile_95, rows[0].percentile_99, rows[0].max), (350, 700, 900));
+        }
+
+        /// Create the cluster and server entities with a boolean and an u64 statistic.
+        fn cluster_and_server(load_balancing: bool, bytes_sent: u64, timestamp: DateTime<Local>) -> Vec<MetricEntity> {
+            let json = format!(r#"
+    [
+        {{
+            "type": "cluster",
+            "id": "yb.cluster",
+            "attributes": {{}},
+            "metrics": [
+                {{ "name": "is_load_balancing_enabled", "value": {} }}
+            ]
+        }},
+        {{
+            "type": "server",
+            "id": "yb.master",
+            "attributes": {{}},
+            "metrics": [
+                {{ "name": "tcp_bytes_sent", "value": {} }}
+            ]
+        }}
+    ]"#, load_balancing, bytes_sent);
+            let mut result = AllMetricEntity::parse_metrics(json, "", "");
+            for metricentity in result.iter_mut() {
+                metricentity.hostname_port = Some("yb-1.local:7000".to_string());
+                metricentity.timestamp = Some(timestamp);
+            }
+            result
+        }
+
+        #[test]
+        fn unit_value_rows_u64_value() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: cluster_and_server(true, 18446744073709551500, begin) }, &false);
+            diff.second_snapshot(AllMetricEntity { metricentity: cluster_and_server(true, 18446744073709551600, end) }, &false, &begin);
+            let all = Regex::new(".*").unwrap();
+            let rows = diff.value_rows(&all, &all, &all, &false, &false);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].metric_name.as_str(), rows[0].value, rows[0].reset), ("tcp_bytes_sent", 100, false));
+        }
+
+        #[test]
+        fn unit_rows_zero_enable() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            let mut first = cluster_and_server(true, 100, begin);
+            first.push(table_countsum("a", 10, 100, 200, begin));
+            let mut second = cluster_and_server(true, 100, end);
+            second.push(table_countsum("a", 10, 100, 200, end));
+            diff.first_snapshot(AllMetricEntity { metricentity: first }, &false);
+            diff.second_snapshot(AllMetricEntity { metricentity: second }, &false, &begin);
+            let all = Regex::new(".*").unwrap();
+            assert!(diff.value_rows(&all, &all, &all, &false, &false).is_empty());
+            assert!(diff.countsum_rows(&all, &all, &all, &false).is_empty());
+            let rows = diff.value_rows(&all, &all, &all, &false, &true);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].metric_name.as_str(), rows[0].value, rows[0].rate), ("tcp_bytes_sent", 0, 0_f64));
+            let rows = diff.countsum_rows(&all, &all, &all, &true);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].metric_name.as_str(), rows[0].rate, rows[0].average), ("log_sync_latency", 0_f64, 0_f64));
+        }
+
+        #[test]
+        fn unit_metric_states_flipped() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: cluster_and_server(true, 100, begin) }, &false);
+            diff.second_snapshot(AllMetricEntity { metricentity: cluster_and_server(false, 200, end) }, &false, &begin);
+            let all = Regex::new(".*").unwrap();
+            let rows = diff.states.rows(&all);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].metric_type, rows[0].metric_name), ("cluster", "is_load_balancing_enabled"));
+            assert_eq!((rows[0].fields.first_value, rows[0].fields.second_value), (Some(true), Some(false)));
+            assert!(diff.states.rows(&Regex::new("yb-2").unwrap()).is_empty());
+
+            let mut states = MetricStatesDiff::new();
+            states.first_snapshot(AllMetricEntity { metricentity: cluster_and_server(true, 100, begin) });
+            states.second_snapshot(AllMetricEntity { metricentity: cluster_and_server(true, 200, end) });
+            assert!(states.rows(&all).is_empty());
+        }
+
+        #[test]
+        fn unit_countsum_rows_reset() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 20, 100, 200, begin)] }, &false);
+            diff.second_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 5, 100, 200, end)] }, &false, &begin);
+            let all = Regex::new(".*").unwrap();
+            // the count went down, so the second values are the difference.
+            let rows = diff.countsum_rows(&all, &all, &all, &false);
+            assert_eq!((rows[0].count, rows[0].total, rows[0].reset), (5, 50, true));
+        }
+
+        #[test]
+        fn unit_countsum_rows_restarted_server_is_reset() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 20, 100, 200, begin)] }, &false);
+            diff.second_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 30, 100, 200, end)] }, &false, &begin);
+            let all = Regex::new(".*").unwrap();
+            assert_eq!((diff.countsum_rows(&all, &all, &all, &false)[0].count, diff.countsum_rows(&all, &all, &all, &false)[0].reset), (10, false));
+            // a restarted server did 30 since the restart, not 10.
+            diff.restarts.servers.insert("yb-1.local:9000".to_string());
+            let rows = diff.countsum_rows(&all, &all, &all, &false);
+            assert_eq!((rows[0].count, rows[0].reset), (30, true));
+        }
+
+        #[test]
+        fn unit_series_rows_add_up_tables() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 10, 100, 200, begin), table_countsum("b", 10, 300, 400, begin)] }, &true);
+            diff.second_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 20, 500, 900, end), table_countsum("b", 50, 700, 800, end)] }, &true, &begin);
+            let snapshot = |number: i32, timestamp: DateTime<Local>| snapshot::Snapshot { number, timestamp, comment: String::new(), cluster: String::new() };
+            let all = Regex::new(".*").unwrap();
+            let rows = diff.series_rows(&snapshot(1, begin), &snapshot(2, end), &all, &all, &all, &false);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].begin_snapshot, rows[0].end_snapshot, rows[0].timestamp), (1, 2, end));
+            // (10 + 40) in 10 seconds.
+            assert_eq!(rows[0].value, 5.0);
+        }
+
+        #[test]
+        fn unit_series_rows_zero_interval() {
+            let begin = Local::now();
+            let end = begin + chrono::Duration::seconds(10);
+            let mut diff = MetricEntityDiff::new();
+            diff.first_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 10, 100, 200, begin)] }, &true);
+            diff.second_snapshot(AllMetricEntity { metricentity: vec![table_countsum("a", 10, 100, 200, end)] }, &true, &begin);
+            let snapshot = |number: i32, timestamp: DateTime<Local>| snapshot::Snapshot { number, timestamp, comment: String::new(), cluster: String::new() };
+            let all = Regex::new(".*").unwrap();
+            // an interval without change is a zero in the series, not a gap.
+            let rows = diff.series_rows(&snapshot(1, begin), &snapshot(2, end), &all, &all, &all, &false);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].metric_name.as_str(), rows[0].value), ("log_sync_latency", 0.0));
+        }
+
+        /// Create a value row for a table counter of a tablet on a server.
+        fn tablet_value_row(hostname_port: &str, metric_id: &str, table_name: &str, value: i128) -> MetricValueDiffRow {
+            MetricValueDiffRow {
+                hostname_port: hostname_port.to_string(),
+                metric_type: "tablet".to_string(),
+                metric_id: metric_id.to_string(),
+                table_name: table_name.to_string(),
+                metric_name: "rocksdb_number_db_seek".to_string(),
+                stat_type: "counter".to_string(),
+                value,
+                difference: value,
+                rate: value as f64,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn unit_aggregate_value_rows_levels() {
+            let rows = || vec![
+                tablet_value_row("yb-1.local:9000", "tablet-a1", "yugabyte.a", 1),
+                tablet_value_row("yb-1.local:9000", "tablet-b1", "yugabyte.b", 2),
+                tablet_value_row("yb-2.local:9000", "tablet-a2", "yugabyte.a", 4),
+            ];
+            let table = aggregate_value_rows(rows(), &AggregationLevel::Table);
+            assert_eq!(table.iter().map(|row| (row.hostname_port.as_str(), row.metric_id.as_str(), row.table_name.as_str(), row.value)).collect::<Vec<_>>(),
+                       vec![("-", "-", "yugabyte.a", 5), ("-", "-", "yugabyte.b", 2)]);
+            let host = aggregate_value_rows(rows(), &AggregationLevel::Host);
+            assert_eq!(host.iter().map(|row| (row.hostname_port.as_str(), row.table_name.as_str(), row.value)).collect::<Vec<_>>(),
+                       vec![("yb-1.local:9000", "", 3), ("yb-2.local:9000", "", 4)]);
+            let cluster = aggregate_value_rows(rows(), &AggregationLevel::Cluster);
+            assert_eq!((cluster.len(), cluster[0].value, cluster[0].rate), (1, 7, 7.0));
+            assert_eq!(aggregate_value_rows(rows(), &AggregationLevel::Tablet).len(), 3);
+        }
+
+        #[test]
+        fn unit_aggregate_countsum_rows_cluster() {
+            let row = |hostname_port: &str, count: u64, total: u64, max: u64| MetricCountSumDiffRow {
+                hostname_port: hostname_port.to_string(),
+                metric_type: "server".to_string(),
+                metric_id: "yb.tabletserver".to_string(),
+                metric_name: "handler_latency_yb_tserver_TabletServerService_Read".to_string(),
+                count,
+                total,
+                max,
+                ..Default::default()
+            };
+            let rows = aggregate_countsum_rows(vec![row("yb-1.local:9000", 10, 100, 50), row("yb-2.local:9000", 30, 500, 20)], &AggregationLevel::Cluster);
+            assert_eq!(rows.len(), 1);
+            assert_eq!((rows[0].count, rows[0].total, rows[0].average, rows[0].max), (40, 600, 15.0, 50));
+        }
+
+        #[test]
+        fn unit_derived_rows_block_cache_hit_ratio() {
+            let begin = Local::now();
+            let mut diff = MetricEntityDiff::new();
+            for (hostname_port, metric_name, value) in [("yb-1.local:9000", "rocksdb_block_cache_hit", 90), ("yb-1.local:9000", "rocksdb_block_cache_miss", 10), ("yb-2.local:9000", "rocksdb_block_cache_miss", 10)] {
+                diff.btreemetricdiffvalue.insert(
+                    (hostname_port.to_string(), "tablet".to_string(), "-".to_string(), metric_name.to_string()),
+                    MetricDiffValues { first_snapshot_time: begin, second_snapshot_time: begin + chrono::Duration::seconds(10), second_value: value, ..Default::default() },
+                );
+            }
+            let all = Regex::new(".*").unwrap();
+            let rows = diff.derived_rows(&all, &Regex::new("block_cache_hit_ratio").unwrap(), &all, &AggregationLevel::Host, &None);
+            assert_eq!(rows.iter().map(|row| (row.hostname_port.as_str(), row.value)).collect::<Vec<_>>(), vec![("yb-1.local:9000", 90.0), ("yb-2.local:9000", 0.0)]);
+            // added up for the cluster: 90 hits of 110.
+            let rows = diff.derived_rows(&all, &Regex::new("block_cache_hit_ratio").unwrap(), &all, &AggregationLevel::Cluster, &None);
+            assert_eq!(rows.len(), 1);
+            assert_eq!(format!("{:.3}", rows[0].value), "81.818");
+        }
+
+        #[test]
+        fn unit_aggregation_level_from_options() {
+            assert_eq!(AggregationLevel::from_options(&None, &false, &None).unwrap(), AggregationLevel::Host);
+            assert_eq!(AggregationLevel::from_options(&None, &true, &None).unwrap(), AggregationLevel::Tablet);
+            assert_eq!(AggregationLevel::from_options(&Some(AggregationLevel::Table), &false, &None).unwrap(), AggregationLevel::Table);
+            assert_eq!(AggregationLevel::from_options(&None, &false, &Some(GroupBy::Zone)).unwrap(), AggregationLevel::Host);
+            assert!(AggregationLevel::from_options(&Some(AggregationLevel::Cluster), &false, &Some(GroupBy::Region)).is_err());
+            assert!(AggregationLevel::from_options(&Some(AggregationLevel::Table), &true, &Some(GroupBy::Zone)).is_err());
+            assert!(AggregationLevel::Table.needs_details());
+            assert!(!AggregationLevel::Cluster.needs_details());
+        }
+
+        #[test]
+        fn unit_sparkline_scaled() {
+            assert_eq!(sparkline(&[0.0, 1.0, 7.0, 3.5]), "_.#=");
+            assert_eq!(sparkline(&[2.0, 2.0]), "__");
+            assert_eq!(sparkline(&[]), "");
+        }
+
+        #[test]
+        fn unit_unknown_statistics() {
+            let json = r#"
+    [
+        {
+            "type": "server",
+            "id": "yb.tabletserver",
+            "attributes": {},
+            "metrics": [
+                { "name": "block_cache_hits", "value": 10 },
+                { "name": "new_statistic", "value": 1 },
+                { "name": "new_latency", "total_count": 1, "min": 1, "mean": 1.0, "percentile_75": 1, "percentile_95": 1, "percentile_99": 1, "percentile_99_9": 1, "percentile_99_99": 1, "max": 1, "total_sum": 1 },
+                { "name": "is_load_balancing_enabled", "value": true }
+            ]
+        },
+        {
+            "type": "tablet",
+            "id": "16add7b1248a45d2880e5527b2059b54",
+            "attributes": {},
+            "metrics": [
+                { "name": "new_statistic", "value": 2 }
+            ]
+        }
+    ]"#.to_string();
+            let allmetricentity = AllMetricEntity { metricentity: AllMetricEntity::parse_metrics(json, "", "") };
+            let unknown = allmetricentity.unknown_statistics();
+            assert_eq!(unknown.len(), 2);
+            assert_eq!((unknown[0].kind, unknown[0].name.as_str(), unknown[0].unit.as_str()), (StatisticKind::Value, "new_statistic", "?"));
+            assert_eq!((unknown[1].kind, unknown[1].name.as_str(), unknown[1].stat_type.as_str()), (StatisticKind::CountSum, "new_latency", "?"));
+        }
+
+        #[test]
+        fn unit_sort_by_tail_latency() {
+            let row = |metric_name: &str, percentile_99: u64, max: u64| MetricCountSumDiffRow { metric_name: metric_name.to_string(), percentile_99, max, ..Default::default() };
+            let mut rows = vec![row("a", 10, 20), row("b", 30, 40), row("c", 30, 50)];
+            sort_by_tail_latency(&mut rows);
+            assert_eq!(rows.iter().map(|row| row.metric_name.as_str()).collect::<Vec<_>>(), vec!["c", "b", "a"]);
+        }
+
         #[tokio::test]
         async fn integration_parse_metrics_master()
         {
@@ -4,8 +4,12 @@ mod structs;
 mod functions;
 mod value_statistic_details;
 mod countsum_statistic_details;
+mod derived_statistic_details;
+mod statistic_catalog;
 
 pub use structs::*;
 pub use functions::*;
 pub use value_statistic_details::*;
 pub use countsum_statistic_details::*;
+pub use derived_statistic_details::*;
+pub use statistic_catalog::*;
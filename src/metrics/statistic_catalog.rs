@@ -0,0 +1,189 @@
+//! Utility module for the catalog of the value and countsum statistics, with helper functions.
+//!
+//! The catalog holds the unit and type of every known statistic, which determine how a statistic is reported,
+//! and the YugabyteDB version in which a statistic appeared, if it's known.
+//! Most entries only have the unit and type: the version is known for a minority of the statistics,
+//! and a description is only filled in for the statistics used by the reports and the derived statistics, and a number of common statistics.
+//! The catalog is embedded from `statistics.csv`, and can be extended or overridden with a file in the same format,
+//! set with `--statistics-file` or YBSTATS_STATISTICS_FILE.
+use std::{collections::HashMap, fs, sync::OnceLock};
+use log::*;
+use anyhow::{bail, Context, Result};
+
+/// The embedded catalog.
+const EMBEDDED_CATALOG: &str = include_str!("statistics.csv");
+/// The catalog in use: the embedded catalog with the entries of the statistics file applied, set by [set_statistic_catalog].
+static STATISTIC_CATALOG: OnceLock<Vec<StatisticCatalogEntry>> = OnceLock::new();
+
+/// The kind of statistic: the type of the metric in the `/metrics` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatisticKind {
+    Value,
+    CountSum,
+}
+/// The struct that contains a row of the catalog, which is a line in `statistics.csv` or the statistics file:
+/// ```text
+/// kind,name,unit,type,version,description
+/// value,block_cache_hits,blocks,counter,,Number of lookups that found the block in the block cache.
+/// ```
+/// The version is the YugabyteDB version in which the statistic appeared, and can be empty, as can the description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatisticCatalogEntry {
+    pub kind: StatisticKind,
+    pub name: String,
+    pub unit: String,
+    #[serde(rename = "type")]
+    pub stat_type: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Parse the catalog entries from csv data with a header.
+fn parse_catalog(
+    data: &str,
+) -> Result<Vec<StatisticCatalogEntry>>
+{
+    let mut entries = Vec::new();
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+    for row in reader.deserialize() {
+        let entry: StatisticCatalogEntry = row
+            .with_context(|| "Error deserialize row")?;
+        if entry.name == "?" {
+            bail!("The statistic name '?' is reserved for unknown statistics");
+        }
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Apply the entries of a statistics file to the catalog:
+/// an entry for a statistic that is in the catalog replaces it, any other entry is added.
+fn merge_catalog(
+    catalog: &mut Vec<StatisticCatalogEntry>,
+    entries: Vec<StatisticCatalogEntry>,
+)
+{
+    let mut positions: HashMap<(StatisticKind, String), usize> = catalog.iter()
+        .enumerate()
+        .map(|(position, entry)| ((entry.kind, entry.name.clone()), position))
+        .collect();
+    for entry in entries {
+        match positions.get(&(entry.kind, entry.name.clone())) {
+            Some(position) => catalog[*position] = entry,
+            None => {
+                positions.insert((entry.kind, entry.name.clone()), catalog.len());
+                catalog.push(entry);
+            },
+        }
+    }
+}
+
+/// Parse the embedded catalog.
+fn embedded_catalog() -> Vec<StatisticCatalogEntry>
+{
+    parse_catalog(EMBEDDED_CATALOG).expect("The embedded statistics.csv should be valid")
+}
+
+/// Set the catalog from the embedded catalog, with the entries of the statistics file applied if a file is given.
+pub fn set_statistic_catalog(
+    statistics_file: Option<String>,
+) -> Result<()>
+{
+    let mut catalog = embedded_catalog();
+    if let Some(statistics_file) = statistics_file {
+        let data = fs::read_to_string(&statistics_file)
+            .with_context(|| format!("Error reading statistics file: {}", statistics_file))?;
+        let entries = parse_catalog(&data)
+            .with_context(|| format!("Error parsing statistics file: {}", statistics_file))?;
+        info!("statistics file: {}, entries: {}", statistics_file, entries.len());
+        merge_catalog(&mut catalog, entries);
+    }
+    if STATISTIC_CATALOG.set(catalog).is_err() {
+        bail!("The statistic catalog is already set");
+    }
+    Ok(())
+}
+
+/// Return the catalog entries of a kind, set by [set_statistic_catalog].
+/// If it's not set, the embedded catalog is used.
+pub fn statistic_catalog(
+    kind: StatisticKind,
+) -> impl Iterator<Item = &'static StatisticCatalogEntry>
+{
+    STATISTIC_CATALOG.get_or_init(embedded_catalog)
+        .iter()
+        .filter(move |entry| entry.kind == kind)
+}
+
+/// Return how many entries of the catalog in use, set by [set_statistic_catalog], have a version and a description.
+pub fn statistic_catalog_coverage() -> String
+{
+    catalog_coverage(STATISTIC_CATALOG.get_or_init(embedded_catalog))
+}
+
+fn catalog_coverage(
+    catalog: &[StatisticCatalogEntry],
+) -> String
+{
+    let with_version = catalog.iter().filter(|entry| !entry.version.is_empty()).count();
+    let with_description = catalog.iter().filter(|entry| !entry.description.is_empty()).count();
+    format!("statistic catalog: {} statistics, {} with a version, {} with a description; the other entries only have a unit and type.", catalog.len(), with_version, with_description)
+}
+
+/// These are the unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_embedded_catalog() {
+        let catalog = embedded_catalog();
+        let block_cache_hits = catalog.iter()
+            .find(|entry| entry.kind == StatisticKind::Value && entry.name == "block_cache_hits")
+            .unwrap();
+        assert_eq!(block_cache_hits.unit, "blocks");
+        assert_eq!(block_cache_hits.stat_type, "counter");
+        assert!(catalog.iter().any(|entry| entry.kind == StatisticKind::CountSum && entry.name == "deadlock_size" && entry.version == "2.17.2"));
+    }
+
+    #[test]
+    fn embedded_catalog_describes_derived_statistic_terms() {
+        use crate::metrics::{DerivedStatistics, DerivedTerm};
+        let catalog = embedded_catalog();
+        for term in DerivedStatistics::create().derivedstatisticdetails.iter().flat_map(|details| details.numerator.iter().chain(&details.denominator))
+        {
+            let (kind, name) = match term {
+                DerivedTerm::Value(name) => (StatisticKind::Value, *name),
+                DerivedTerm::Count(name) | DerivedTerm::Sum(name) => (StatisticKind::CountSum, *name),
+            };
+            assert!(catalog.iter().any(|entry| entry.kind == kind && entry.name == name && !entry.description.is_empty()), "no description for {}", name);
+        }
+    }
+
+    #[test]
+    fn merge_statistics_file_replaces_and_adds() {
+        let mut catalog = parse_catalog("kind,name,unit,type,version,description\nvalue,known,?,?,,\ncountsum,known,microseconds,counter,,\n").unwrap();
+        let entries = parse_catalog("kind,name,unit,type,version,description\nvalue,known,bytes,gauge,2.19.0,Some bytes.\nvalue,new,rows,counter,,\n").unwrap();
+        merge_catalog(&mut catalog, entries);
+        assert_eq!(catalog.len(), 3);
+        assert_eq!(catalog[0].unit, "bytes");
+        assert_eq!(catalog[0].version, "2.19.0");
+        assert_eq!(catalog[0].description, "Some bytes.");
+        assert_eq!(catalog[1].unit, "microseconds");
+        assert_eq!(catalog[2].name, "new");
+    }
+
+    #[test]
+    fn catalog_coverage_counts_version_and_description() {
+        let catalog = parse_catalog("kind,name,unit,type,version,description\nvalue,a,bytes,gauge,2.19.0,Some bytes.\nvalue,b,rows,counter,2.17.2,\ncountsum,c,microseconds,counter,,\n").unwrap();
+        assert_eq!(catalog_coverage(&catalog), "statistic catalog: 3 statistics, 2 with a version, 1 with a description; the other entries only have a unit and type.");
+    }
+
+    #[test]
+    fn parse_catalog_reserved_name() {
+        assert!(parse_catalog("kind,name,unit,type,version,description\nvalue,?,?,?,,\n").is_err());
+    }
+}
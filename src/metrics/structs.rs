@@ -2,14 +2,17 @@
 //!
 use chrono::{DateTime, Local};
 use std::collections::{BTreeMap};
+use clap::ValueEnum;
+use crate::restarts::Restarts;
+use crate::placements::Placements;
+use crate::output::Change;
 /// The root struct for deserializing `/metrics`.
 ///
-/// Struct to represent the metric entities found in the YugabyteDB
+/// Struct to represent the metric entities found in the YugabyteDB metrics endpoints of the:
 /// - master
 /// - tablet server
 /// - YSQL
 /// - YCQL
-/// metrics endpoints.
 ///
 /// Mind the '[' at the top: this is a list of MetricEntity's.
 /// Therefore, parsing this endpoint will result in a vector of structs.
@@ -81,7 +84,7 @@ pub struct MetricEntity {
 ///                 "table_id": "c70ffbbe28f14e84b0559c405ae20197"
 ///             }
 /// ```
-/// For now, stream_id is only collected, not printed.
+/// The stream_id is used to report the streams with `--replication-diff`.
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Attributes {
     pub stream_id: Option<String>,
@@ -205,14 +208,16 @@ pub struct AllMetricEntity {
 /// The key fields are: `hostname_port`, `metric_type`, `metric_id`, `metric_name`
 type BTreeMetricDiffValues = BTreeMap<(String, String, String, String), MetricDiffValues>;
 /// The struct that holds the first and second snapshot statistics.
+///
+/// The values are i128, so the values that only fit in an u64 ([Metrics::RejectedU64MetricValue]) are diffed together with the i64 values.
 #[derive(Debug, Default)]
 pub struct MetricDiffValues {
     pub table_name: String,
     pub namespace: String,
     pub first_snapshot_time: DateTime<Local>,
-    pub first_value: i64,
+    pub first_value: i128,
     pub second_snapshot_time: DateTime<Local>,
-    pub second_value: i64,
+    pub second_value: i128,
 }
 /// BTreeMap for storing a metricentity countsum.
 ///
@@ -220,9 +225,13 @@ pub struct MetricDiffValues {
 type BTreeMetricDiffCountSum = BTreeMap<(String, String, String, String), MetricDiffCountSum>;
 /// The struct that holds the first and second snapshot statistics.
 ///
-/// Please mind the fields min, mean, percentile_* and max are not put in the diff struct.
+/// Please mind the fields min, mean, percentile_* and max are not diffed.
 /// There is no mathematical way to make sense of these figures between the snapshots.
 /// Also, these statistics are flushed upon query, making these rather random.
+///
+/// The 95th and 99th percentile and the maximum of the second snapshot are kept to show the tail latency.
+/// Because these are flushed upon query, these describe the period since the previous read of the endpoint.
+/// If the table or tablet statistics are added up, these are the highest of the added up statistics.
 #[derive(Debug, Default)]
 pub struct MetricDiffCountSum {
     pub table_name: String,
@@ -233,6 +242,9 @@ pub struct MetricDiffCountSum {
     pub second_snapshot_time: DateTime<Local>,
     pub second_total_sum: u64,
     pub second_total_count: u64,
+    pub second_percentile_95: u64,
+    pub second_percentile_99: u64,
+    pub second_max: u64,
 }
 /// BTreeMap for storing a metricentity countsum.
 ///
@@ -252,10 +264,147 @@ pub struct MetricDiffCountSumRows {
     pub second_sum: u64,
     pub second_rows: u64,
 }
-/// Wrapper struct for holding the diff btreemaps.
+/// Wrapper struct for holding the diff btreemaps, and the servers that restarted between the snapshots.
 #[derive(Debug, Default)]
 pub struct MetricEntityDiff {
     pub btreemetricdiffvalue: BTreeMetricDiffValues,
     pub btreemetricdiffcountsum: BTreeMetricDiffCountSum,
     pub btreemetricdiffcountsumrows: BTreeMetricDiffCountSumRows,
-}
\ No newline at end of file
+    pub restarts: Restarts,
+    pub placements: Placements,
+    pub states: MetricStatesDiff,
+}
+/// A row of the metrics diff report for a value statistic, used for the table and the machine readable output.
+///
+/// For a counter, `value` is the difference between the snapshots, and `rate` the difference per second.
+/// For a gauge, `value` is the value in the second snapshot, and `difference` the change between the snapshots.
+/// If the counter is reset, because the server restarted or the counter went down, `value` is the value in the second snapshot, and `reset` is set.
+#[derive(Debug, Default, Serialize)]
+pub struct MetricValueDiffRow {
+    pub hostname_port: String,
+    pub metric_type: String,
+    pub metric_id: String,
+    pub table_name: String,
+    pub metric_name: String,
+    pub stat_type: String,
+    pub value: i128,
+    pub difference: i128,
+    pub rate: f64,
+    pub unit: String,
+    pub reset: bool,
+    #[serde(skip)]
+    pub unit_suffix: String,
+}
+/// A row of the metrics diff report for a countsum statistic.
+///
+/// If the statistic is reset, because the server restarted or the count went down, the count and total are the values in the second snapshot, and `reset` is set.
+#[derive(Debug, Default, Serialize)]
+pub struct MetricCountSumDiffRow {
+    pub hostname_port: String,
+    pub metric_type: String,
+    pub metric_id: String,
+    pub table_name: String,
+    pub metric_name: String,
+    pub stat_type: String,
+    pub count: u64,
+    pub rate: f64,
+    pub average: f64,
+    pub total: u64,
+    /// The 95th percentile of the end snapshot.
+    pub percentile_95: u64,
+    /// The 99th percentile of the end snapshot.
+    pub percentile_99: u64,
+    /// The maximum of the end snapshot.
+    pub max: u64,
+    pub unit: String,
+    pub reset: bool,
+    #[serde(skip)]
+    pub unit_suffix: String,
+}
+/// The level at which the metrics diff report adds up the statistics, set with `--aggregation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AggregationLevel {
+    /// All servers of the cluster added up.
+    Cluster,
+    /// Per server, with the tables and tablets added up (default).
+    Host,
+    /// Per table (`namespace.table`), added up over all servers.
+    Table,
+    /// Per table and tablet per server (default with `--details-enable`).
+    Tablet,
+}
+/// A row of the metrics diff report for a derived statistic, which is calculated from other statistics, such as a ratio or an average.
+#[derive(Debug, Default, Serialize)]
+pub struct MetricDerivedDiffRow {
+    pub hostname_port: String,
+    pub metric_type: String,
+    pub metric_id: String,
+    pub table_name: String,
+    pub metric_name: String,
+    pub value: f64,
+    pub unit: String,
+}
+/// A row of the metrics series report: the figure of a statistic of a server in the interval between two consecutive snapshots.
+///
+/// For a counter or countsum statistic, `value` is the rate per second, for a gauge it's the value in the end snapshot of the interval.
+/// The figures of the tables, tablets and other entities of a server are added up.
+#[derive(Debug, Default, Serialize)]
+pub struct MetricSeriesRow {
+    pub hostname_port: String,
+    pub metric_name: String,
+    pub stat_type: String,
+    pub begin_snapshot: i32,
+    pub end_snapshot: i32,
+    pub timestamp: DateTime<Local>,
+    pub value: f64,
+    pub unit: String,
+    pub reset: bool,
+    #[serde(skip)]
+    pub unit_suffix: String,
+}
+/// A row of the metrics diff report for a countsumrows statistic.
+///
+/// If the statistic is reset, because the server restarted or the count went down, the figures are the values in the second snapshot, and `reset` is set.
+#[derive(Debug, Default, Serialize)]
+pub struct MetricCountSumRowsDiffRow {
+    pub hostname_port: String,
+    pub metric_type: String,
+    pub metric_id: String,
+    pub table_name: String,
+    pub metric_name: String,
+    pub count: u64,
+    pub rate: f64,
+    pub average_time_ms: f64,
+    pub total_time_ms: f64,
+    pub average_rows: u64,
+    pub total_rows: u64,
+    pub reset: bool,
+}
+/// BTreeMap for storing a metricentity boolean state.
+///
+/// The key fields are: `hostname_port`, `metric_type`, `metric_id`, `metric_name`
+type BTreeMetricStatesDiff = BTreeMap<(String, String, String, String), MetricStateDiffFields>;
+/// Wrapper struct for holding the boolean statistics ([Metrics::RejectedBooleanMetricValue]), such as `is_load_balancing_enabled`.
+/// These are states instead of performance figures, and are reported with the non-metrics differences.
+#[derive(Debug, Default)]
+pub struct MetricStatesDiff {
+    pub btreemetricstatesdiff: BTreeMetricStatesDiff,
+}
+/// The struct that holds the first and second snapshot state.
+/// The state is None if it is not found in the snapshot.
+#[derive(Debug, Default, Serialize)]
+pub struct MetricStateDiffFields {
+    pub first_value: Option<bool>,
+    pub second_value: Option<bool>,
+}
+/// A row of the metric states diff report, used for the table and the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct MetricStateDiffRow<'a> {
+    pub change: Change,
+    pub hostname_port: &'a str,
+    pub metric_type: &'a str,
+    pub metric_id: &'a str,
+    pub metric_name: &'a str,
+    #[serde(flatten)]
+    pub fields: &'a MetricStateDiffFields,
+}
@@ -1,14 +1,15 @@
 //! The impls and functions
 //! 
-use std::{sync::mpsc::channel, time::Instant};
+use std::{collections::BTreeSet, sync::mpsc::channel, time::Instant};
 use chrono::{DateTime, Local};
 use prometheus_parse::Value;
 use regex::Regex;
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::node_exporter::{NodeExporter, AllNodeExporter, NodeExporterDiff, NameCategoryDiff};
+use crate::node_exporter::{NodeExporter, AllNodeExporter, NodeExporterDiff, NameCategoryDiff, NodeExporterDiffRow, OsSummary, OsCpuRow, OsDiskRow, OsNetworkRow, OsMemoryRow};
+use crate::output::{self, OutputFormat};
 use crate::Opts;
 
 impl AllNodeExporter {
@@ -43,17 +44,21 @@ impl AllNodeExporter {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut nodeexporter = AllNodeExporter::read_http(host, port);
-                        nodeexporter.iter_mut().for_each(|r| r.timestamp = detail_snapshot_time);
-                        nodeexporter.iter_mut().for_each(|r| r.hostname_port = format!("{}:{}", host, port));
-                        tx.send(nodeexporter).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::NodeExporter]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut nodeexporter = match AllNodeExporter::read_http(host, port) {
+                        Ok(nodeexporter) => nodeexporter,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    nodeexporter.iter_mut().for_each(|r| r.timestamp = detail_snapshot_time);
+                    nodeexporter.iter_mut().for_each(|r| r.hostname_port = format!("{}:{}", host, port));
+                    tx.send(nodeexporter).expect("error sending data via tx");
+                });
             }
         });
 
@@ -73,10 +78,10 @@ impl AllNodeExporter {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<NodeExporter>
+    ) -> Result<Vec<NodeExporter>>
     {
-        let data_from_http = utility::http_get(host, port, "metrics");
-        AllNodeExporter::parse_nodeexporter(data_from_http)
+        let data_from_http = utility::http_get(host, port, "metrics").into_body()?;
+        Ok(AllNodeExporter::parse_nodeexporter(data_from_http))
     }
     fn parse_nodeexporter( 
         node_exporter_data: String
@@ -235,46 +240,361 @@ impl NodeExporterDiff {
                 });
         }
     }
+    /// Return the `hostname_port` of the nodes that rebooted between the snapshots,
+    /// which are the nodes for which the boot time (`node_boot_time_seconds`) changed.
+    ///
+    /// The boot time is calculated by the kernel from the current time and the uptime,
+    /// so it's allowed to move a second because of clock adjustments.
+    pub fn rebooted(
+        &self,
+    ) -> BTreeSet<String>
+    {
+        self.btreemapnodeexporterdiff.iter()
+            .filter(|((_, name, _), diff_row)| name == "node_boot_time_seconds"
+                && diff_row.first_value > 0.0
+                && diff_row.second_value > 0.0
+                && (diff_row.second_value - diff_row.first_value).abs() > 1.0)
+            .map(|((hostname_port, _, _), _)| hostname_port.clone())
+            .collect()
+    }
+    /// Create the rows for the node_exporter statistics, filtered and with the counter/gauge logic applied.
+    pub fn rows(
+        &self,
+        hostname_filter: &Regex,
+        stat_name_filter: &Regex,
+        gauges_enable: &bool,
+        details_enable: &bool,
+    ) -> Vec<NodeExporterDiffRow>
+    {
+        let rebooted = self.rebooted();
+        let mut rows = Vec::new();
+        for ((hostname_port, name, labels), diff_row) in &self.btreemapnodeexporterdiff {
+            if !hostname_filter.is_match(hostname_port)
+                || !stat_name_filter.is_match(name)
+            {
+                continue;
+            }
+            if *details_enable && labels == "summary" { continue };
+            if ! *details_enable && diff_row.category == "detail" { continue };
+            let difference = diff_row.second_value - diff_row.first_value;
+            // a counter is reset if the node rebooted, or if it went down, such as when node_exporter restarted for its process counters.
+            // if so, the second value is the difference, and a counter that is still zero after the reset has no difference to report.
+            let reset = rebooted.contains(hostname_port) || difference < 0.0;
+            if diff_row.exporter_type == "counter"
+                && reset
+                && diff_row.second_value != 0.0
+            {
+                rows.push(NodeExporterDiffRow {
+                    hostname_port: hostname_port.to_string(),
+                    exporter_type: diff_row.exporter_type.clone(),
+                    name: name.to_string(),
+                    labels: labels.to_string(),
+                    category: diff_row.category.clone(),
+                    value: diff_row.second_value,
+                    difference: diff_row.second_value,
+                    rate: diff_row.second_value / (diff_row.second_snapshot_time - diff_row.first_snapshot_time).num_seconds() as f64,
+                    reset,
+                });
+            }
+            else if diff_row.exporter_type == "counter"
+                && !reset
+                && difference != 0.0
+            {
+                rows.push(NodeExporterDiffRow {
+                    hostname_port: hostname_port.to_string(),
+                    exporter_type: diff_row.exporter_type.clone(),
+                    name: name.to_string(),
+                    labels: labels.to_string(),
+                    category: diff_row.category.clone(),
+                    value: difference,
+                    difference,
+                    rate: difference / (diff_row.second_snapshot_time - diff_row.first_snapshot_time).num_seconds() as f64,
+                    ..Default::default()
+                });
+            }
+            if diff_row.exporter_type == "gauge"
+                && *gauges_enable
+            {
+                rows.push(NodeExporterDiffRow {
+                    hostname_port: hostname_port.to_string(),
+                    exporter_type: diff_row.exporter_type.clone(),
+                    name: name.to_string(),
+                    labels: labels.to_string(),
+                    category: diff_row.category.clone(),
+                    value: diff_row.second_value,
+                    difference,
+                    ..Default::default()
+                });
+            }
+        }
+        rows
+    }
     pub fn print(
         &self,
         hostname_filter: &Regex,
         stat_name_filter: &Regex,
         gauges_enable: &bool,
         details_enable: &bool,
-    )
+        output: &OutputFormat,
+    ) -> Result<()>
     {
-        for ((hostname_port, name, category), diff_row) in &self.btreemapnodeexporterdiff {
-            if hostname_filter.is_match(hostname_port)
-                && stat_name_filter.is_match(name)
-                && diff_row.second_value - diff_row.first_value != 0.0
-                && diff_row.exporter_type == "counter"
+        let rows = self.rows(hostname_filter, stat_name_filter, gauges_enable, details_enable);
+        if !output.is_table()
+        {
+            return output::print_rows(output, "node_exporter", &rows);
+        }
+        let rebooted = self.rebooted();
+        if !rebooted.is_empty()
+        {
+            println!("rebooted between the snapshots: {}, the statistics of these are since the reboot (reset)", rebooted.into_iter().collect::<Vec<_>>().join(", "));
+        }
+        for row in &rows
+        {
+            if row.exporter_type == "counter"
             {
-                if *details_enable && category == "summary" { continue };
-                if ! *details_enable && diff_row.category == "detail" { continue };
-                println!("{:20} {:8} {:73} {:19.6} {:15.3} /s",
-                         hostname_port,
-                         diff_row.exporter_type,
-                         format!("{}{}", name, category),
-                         diff_row.second_value - diff_row.first_value,
-                         (diff_row.second_value - diff_row.first_value) / (diff_row.second_snapshot_time - diff_row.first_snapshot_time).num_seconds() as f64,
+                println!("{:20} {:8} {:73} {:19.6} {:15.3} /s{}",
+                         row.hostname_port,
+                         row.exporter_type,
+                         format!("{}{}", row.name, row.labels),
+                         row.value,
+                         row.rate,
+                         if row.reset { " reset" } else { "" },
                 );
             }
-            if hostname_filter.is_match(hostname_port)
-                && stat_name_filter.is_match(hostname_port)
-                && diff_row.exporter_type == "gauge"
-                && *gauges_enable
+            else
             {
-                if *details_enable && category == "summary" { continue };
-                if ! *details_enable && diff_row.category == "detail" { continue };
                 println!("{:20} {:8} {:73} {:19.6} {:+15}",
-                         hostname_port,
-                         diff_row.exporter_type,
-                         format!("{}{}", name, category),
-                         diff_row.second_value,
-                         diff_row.second_value - diff_row.first_value
+                         row.hostname_port,
+                         row.exporter_type,
+                         format!("{}{}", row.name, row.labels),
+                         row.value,
+                         row.difference,
                 );
             }
         }
+        Ok(())
+    }
+    /// The difference of a counter between the snapshots, and whether it is reset.
+    /// If the counter is reset, the difference is the value in the second snapshot, as in [NodeExporterDiff::rows].
+    fn counter_difference(
+        &self,
+        rebooted: &BTreeSet<String>,
+        hostname_port: &str,
+        name: &str,
+        labels: &str,
+    ) -> (f64, bool)
+    {
+        match self.btreemapnodeexporterdiff.get(&(hostname_port.to_string(), name.to_string(), labels.to_string())) {
+            Some(diff_row) => {
+                let difference = diff_row.second_value - diff_row.first_value;
+                if rebooted.contains(hostname_port) || difference < 0.0 {
+                    (diff_row.second_value, true)
+                } else {
+                    (difference, false)
+                }
+            },
+            None => (0.0, false),
+        }
+    }
+    /// The value of a gauge in the second snapshot.
+    fn gauge(
+        &self,
+        hostname_port: &str,
+        name: &str,
+    ) -> Option<f64>
+    {
+        self.btreemapnodeexporterdiff.get(&(hostname_port.to_string(), name.to_string(), String::new()))
+            .map(|diff_row| diff_row.second_value)
+    }
+    /// The labels of a statistic of a host, which for the disk and network statistics is the device.
+    /// The 'detail' devices, such as device mapper devices, are only included if details_enable is set.
+    fn labels(
+        &self,
+        hostname_port: &str,
+        name: &str,
+        details_enable: &bool,
+    ) -> Vec<String>
+    {
+        self.btreemapnodeexporterdiff.iter()
+            .filter(|((row_hostname_port, row_name, _), diff_row)| row_hostname_port == hostname_port
+                && row_name == name
+                && (*details_enable || diff_row.category != "detail"))
+            .map(|((_, _, labels), _)| labels.clone())
+            .collect()
+    }
+    /// Create the OS summary from the node_exporter statistics.
+    pub fn os_summary(
+        &self,
+        hostname_filter: &Regex,
+        details_enable: &bool,
+    ) -> OsSummary
+    {
+        let rebooted = self.rebooted();
+        let mut os_summary = OsSummary::default();
+        let mut hostnames: Vec<&String> = self.btreemapnodeexporterdiff.keys()
+            .map(|(hostname_port, _, _)| hostname_port)
+            .filter(|hostname_port| hostname_filter.is_match(hostname_port))
+            .collect();
+        hostnames.dedup();
+        for hostname_port in hostnames
+        {
+            // the statistics of a host are read at the same time, so the times of any of them are the times of the host.
+            let seconds = self.btreemapnodeexporterdiff.iter()
+                .find(|((row_hostname_port, _, _), _)| row_hostname_port == hostname_port)
+                .map(|(_, diff_row)| (diff_row.second_snapshot_time - diff_row.first_snapshot_time).num_milliseconds() as f64 / 1000_f64)
+                .unwrap_or_default();
+            if seconds <= 0.0 { continue };
+            let counter = |name: &str, labels: &str| self.counter_difference(&rebooted, hostname_port, name, labels);
+
+            // cpu: the summary rows of node_cpu_seconds_total hold the seconds per mode for all cpus, the detail rows per cpu.
+            let cpus = self.labels(hostname_port, "node_cpu_seconds_total", &true).iter()
+                .filter(|labels| labels.ends_with("_idle") && labels.as_str() != "_idle")
+                .count();
+            if cpus > 0
+            {
+                let cpu_seconds = seconds * cpus as f64;
+                let modes: Vec<_> = ["_user", "_nice", "_system", "_iowait", "_irq", "_softirq", "_steal", "_idle"].iter()
+                    .map(|mode| counter("node_cpu_seconds_total", mode))
+                    .collect();
+                let percentage = |index: usize| modes[index].0 / cpu_seconds * 100_f64;
+                os_summary.cpu.push(OsCpuRow {
+                    hostname_port: hostname_port.to_string(),
+                    cpus,
+                    user: percentage(0),
+                    nice: percentage(1),
+                    system: percentage(2),
+                    iowait: percentage(3),
+                    irq: percentage(4),
+                    softirq: percentage(5),
+                    steal: percentage(6),
+                    idle: percentage(7),
+                    reset: modes.iter().any(|(_, reset)| *reset),
+                });
+            }
+
+            // disk
+            for device in self.labels(hostname_port, "node_disk_reads_completed_total", details_enable)
+            {
+                let (reads, reads_reset) = counter("node_disk_reads_completed_total", &device);
+                let (writes, writes_reset) = counter("node_disk_writes_completed_total", &device);
+                let (read_bytes, _) = counter("node_disk_read_bytes_total", &device);
+                let (written_bytes, _) = counter("node_disk_written_bytes_total", &device);
+                let (read_time, _) = counter("node_disk_read_time_seconds_total", &device);
+                let (write_time, _) = counter("node_disk_write_time_seconds_total", &device);
+                let (io_time, _) = counter("node_disk_io_time_seconds_total", &device);
+                os_summary.disk.push(OsDiskRow {
+                    hostname_port: hostname_port.to_string(),
+                    device: device.trim_start_matches('_').to_string(),
+                    reads_per_second: reads / seconds,
+                    writes_per_second: writes / seconds,
+                    read_mb_per_second: read_bytes / seconds / 1024_f64 / 1024_f64,
+                    write_mb_per_second: written_bytes / seconds / 1024_f64 / 1024_f64,
+                    read_latency_ms: if reads > 0.0 { read_time / reads * 1000_f64 } else { 0.0 },
+                    write_latency_ms: if writes > 0.0 { write_time / writes * 1000_f64 } else { 0.0 },
+                    utilization: io_time / seconds * 100_f64,
+                    reset: reads_reset || writes_reset,
+                });
+            }
+
+            // network
+            for device in self.labels(hostname_port, "node_network_receive_bytes_total", details_enable)
+            {
+                let (receive_packets, _) = counter("node_network_receive_packets_total", &device);
+                let (transmit_packets, _) = counter("node_network_transmit_packets_total", &device);
+                let (receive_bytes, receive_reset) = counter("node_network_receive_bytes_total", &device);
+                let (transmit_bytes, transmit_reset) = counter("node_network_transmit_bytes_total", &device);
+                os_summary.network.push(OsNetworkRow {
+                    hostname_port: hostname_port.to_string(),
+                    device: device.trim_start_matches('_').to_string(),
+                    receive_packets_per_second: receive_packets / seconds,
+                    transmit_packets_per_second: transmit_packets / seconds,
+                    receive_mb_per_second: receive_bytes / seconds / 1024_f64 / 1024_f64,
+                    transmit_mb_per_second: transmit_bytes / seconds / 1024_f64 / 1024_f64,
+                    reset: receive_reset || transmit_reset,
+                });
+            }
+
+            // memory
+            if let Some(memory_total) = self.gauge(hostname_port, "node_memory_MemTotal_bytes")
+            {
+                let megabytes = |name: &str| self.gauge(hostname_port, name).unwrap_or_default() / 1024_f64 / 1024_f64;
+                let (swap_in, swap_in_reset) = counter("node_vmstat_pswpin", "");
+                let (swap_out, swap_out_reset) = counter("node_vmstat_pswpout", "");
+                os_summary.memory.push(OsMemoryRow {
+                    hostname_port: hostname_port.to_string(),
+                    memory_total_mb: memory_total / 1024_f64 / 1024_f64,
+                    memory_free_mb: megabytes("node_memory_MemFree_bytes"),
+                    memory_available_mb: megabytes("node_memory_MemAvailable_bytes"),
+                    buffers_mb: megabytes("node_memory_Buffers_bytes"),
+                    cached_mb: megabytes("node_memory_Cached_bytes"),
+                    swap_total_mb: megabytes("node_memory_SwapTotal_bytes"),
+                    swap_free_mb: megabytes("node_memory_SwapFree_bytes"),
+                    swap_in_per_second: swap_in / seconds,
+                    swap_out_per_second: swap_out / seconds,
+                    reset: swap_in_reset || swap_out_reset,
+                });
+            }
+        }
+        os_summary
+    }
+    pub fn print_os_summary(
+        &self,
+        hostname_filter: &Regex,
+        details_enable: &bool,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let os_summary = self.os_summary(hostname_filter, details_enable);
+        if !output.is_table()
+        {
+            output::print_rows(output, "os_cpu", &os_summary.cpu)?;
+            output::print_rows(output, "os_disk", &os_summary.disk)?;
+            output::print_rows(output, "os_network", &os_summary.network)?;
+            return output::print_rows(output, "os_memory", &os_summary.memory);
+        }
+        let rebooted = self.rebooted();
+        if !rebooted.is_empty()
+        {
+            println!("rebooted between the snapshots: {}, the statistics of these are since the reboot (reset)", rebooted.into_iter().collect::<Vec<_>>().join(", "));
+        }
+        let reset = |reset: bool| if reset { " reset" } else { "" };
+        if !os_summary.cpu.is_empty()
+        {
+            println!("{:20} {:>5} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}", "cpu", "cpus", "%user", "%nice", "%system", "%iowait", "%irq", "%softirq", "%steal", "%idle");
+            for row in &os_summary.cpu
+            {
+                println!("{:20} {:5} {:8.2} {:8.2} {:8.2} {:8.2} {:8.2} {:8.2} {:8.2} {:8.2}{}",
+                         row.hostname_port, row.cpus, row.user, row.nice, row.system, row.iowait, row.irq, row.softirq, row.steal, row.idle, reset(row.reset));
+            }
+        }
+        if !os_summary.disk.is_empty()
+        {
+            println!("{:20} {:10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>8}", "disk", "device", "r/s", "w/s", "rMB/s", "wMB/s", "r_await", "w_await", "%util");
+            for row in &os_summary.disk
+            {
+                println!("{:20} {:10} {:10.2} {:10.2} {:10.2} {:10.2} {:10.2} {:10.2} {:8.2}{}",
+                         row.hostname_port, row.device, row.reads_per_second, row.writes_per_second, row.read_mb_per_second, row.write_mb_per_second, row.read_latency_ms, row.write_latency_ms, row.utilization, reset(row.reset));
+            }
+        }
+        if !os_summary.network.is_empty()
+        {
+            println!("{:20} {:10} {:>12} {:>12} {:>10} {:>10}", "network", "device", "rxpck/s", "txpck/s", "rxMB/s", "txMB/s");
+            for row in &os_summary.network
+            {
+                println!("{:20} {:10} {:12.2} {:12.2} {:10.2} {:10.2}{}",
+                         row.hostname_port, row.device, row.receive_packets_per_second, row.transmit_packets_per_second, row.receive_mb_per_second, row.transmit_mb_per_second, reset(row.reset));
+            }
+        }
+        if !os_summary.memory.is_empty()
+        {
+            println!("{:20} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}", "memory (MB)", "total", "free", "available", "buffers", "cached", "swap total", "swap free", "pswpin/s", "pswpout/s");
+            for row in &os_summary.memory
+            {
+                println!("{:20} {:10.0} {:10.0} {:10.0} {:10.0} {:10.0} {:10.0} {:10.0} {:10.2} {:10.2}{}",
+                         row.hostname_port, row.memory_total_mb, row.memory_free_mb, row.memory_available_mb, row.buffers_mb, row.cached_mb, row.swap_total_mb, row.swap_free_mb, row.swap_in_per_second, row.swap_out_per_second, reset(row.reset));
+            }
+        }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot (
         &mut self,
@@ -391,6 +711,7 @@ fn linux_softnet_sum(nodeexporter: &mut Vec<NodeExporter>)
 /// - schedstat_waiting (task runnable, but not running on CPU, waiting for runtime)
 /// - schedstat_running (task runnable and running on CPU)
 /// - schedstat_timeslices (the number of timeslices executed)
+///
 /// The original values are kept, but put in category 'detail'.
 /// The summarized values are put in a category 'summary'.
 fn linux_schedstat_sum(nodeexporter: &mut Vec<NodeExporter>)
@@ -453,7 +774,7 @@ fn linux_schedstat_sum(nodeexporter: &mut Vec<NodeExporter>)
 /// - softirq   : amount of time running deferrable functions (non-urgent interruptable kernel functions).
 /// - idle      : amount of time NOT running.
 /// - iowait    : this is a special case. There is no IO wait time in the kernel. Instead for regular, buffered, IO, linux keeps a counter of outstanding IOs, and tries to map idle time to these, based on idle time availability.
-///               More advanced IO interfaces mostly do not increase this counter, such as io_submit/io_getevents.
+///   More advanced IO interfaces mostly do not increase this counter, such as io_submit/io_getevents.
 /// - steal     : this too is a special case: this is the time the hypervisor did not get CPU slices. Higher values for this could indicate CPU oversubscription by the hypervisor.
 ///
 /// The original values are kept, but put in category 'detail'.
@@ -547,7 +868,7 @@ pub async fn node_exporter_diff(
 ) -> Result<()>
 {
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
@@ -557,16 +878,112 @@ pub async fn node_exporter_diff(
     let (begin_snapshot, end_snapshot, begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let nodeexporterdiff = NodeExporterDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp)?;
-    nodeexporterdiff.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable);
+    nodeexporterdiff.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable, &options.output)?;
 
     Ok(())
 }
 
+/// Create the OS summary report using a begin and end snapshot number.
+pub async fn os_summary(
+    options: &Opts,
+) -> Result<()>
+{
+    if options.begin.is_none() || options.end.is_none() {
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
+    }
+    if options.snapshot_list { return Ok(()) };
+
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+
+    let (begin_snapshot, end_snapshot, begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
+
+    let nodeexporterdiff = NodeExporterDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp)?;
+    nodeexporterdiff.print_os_summary(&hostname_filter, &options.details_enable, &options.output)?;
+
+    Ok(())
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn unit_node_exporter_rows_rebooted() {
+        let begin = Local::now();
+        let diff_row = |exporter_type: &str, first_value: f64, second_value: f64| NameCategoryDiff {
+            first_snapshot_time: begin,
+            second_snapshot_time: begin + chrono::Duration::seconds(10),
+            exporter_type: exporter_type.to_string(),
+            category: "all".to_string(),
+            first_value,
+            second_value,
+        };
+        let mut diff = NodeExporterDiff::new();
+        let key = |hostname_port: &str, name: &str| (hostname_port.to_string(), name.to_string(), String::new());
+        diff.btreemapnodeexporterdiff.insert(key("yb-1.local:9300", "node_boot_time_seconds"), diff_row("gauge", 1000.0, 1000.4));
+        diff.btreemapnodeexporterdiff.insert(key("yb-1.local:9300", "node_intr_total"), diff_row("counter", 100.0, 150.0));
+        diff.btreemapnodeexporterdiff.insert(key("yb-2.local:9300", "node_boot_time_seconds"), diff_row("gauge", 1000.0, 5000.0));
+        diff.btreemapnodeexporterdiff.insert(key("yb-2.local:9300", "node_intr_total"), diff_row("counter", 100.0, 150.0));
+        assert_eq!(diff.rebooted().into_iter().collect::<Vec<_>>(), vec!["yb-2.local:9300"]);
+        let all = Regex::new(".*").unwrap();
+        let rows = diff.rows(&all, &all, &false, &false);
+        assert_eq!((rows[0].value, rows[0].reset), (50.0, false));
+        // the rebooted node counted 150 since the reboot.
+        assert_eq!((rows[1].value, rows[1].reset), (150.0, true));
+
+        // a counter that went down to zero is reset, and not a negative difference.
+        diff.btreemapnodeexporterdiff.insert(key("yb-1.local:9300", "node_intr_total"), diff_row("counter", 100.0, 0.0));
+        let rows = diff.rows(&all, &all, &false, &false);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].hostname_port, "yb-2.local:9300");
+        assert!(rows.iter().all(|row| row.value >= 0.0 && row.rate >= 0.0));
+    }
+
+    #[test]
+    fn unit_node_exporter_os_summary() {
+        let begin = Local::now();
+        let mut diff = NodeExporterDiff::new();
+        let mut insert = |name: &str, labels: &str, category: &str, exporter_type: &str, first_value: f64, second_value: f64| {
+            diff.btreemapnodeexporterdiff.insert(("yb-1.local:9300".to_string(), name.to_string(), labels.to_string()), NameCategoryDiff {
+                first_snapshot_time: begin,
+                second_snapshot_time: begin + chrono::Duration::seconds(10),
+                exporter_type: exporter_type.to_string(),
+                category: category.to_string(),
+                first_value,
+                second_value,
+            });
+        };
+        // 2 cpus for 10 seconds is 20 cpu seconds.
+        insert("node_cpu_seconds_total", "_0_idle", "detail", "counter", 100.0, 107.0);
+        insert("node_cpu_seconds_total", "_1_idle", "detail", "counter", 100.0, 109.0);
+        insert("node_cpu_seconds_total", "_idle", "summary", "counter", 200.0, 216.0);
+        insert("node_cpu_seconds_total", "_user", "summary", "counter", 50.0, 53.0);
+        insert("node_cpu_seconds_total", "_system", "summary", "counter", 20.0, 21.0);
+        insert("node_disk_reads_completed_total", "_sda", "all", "counter", 0.0, 1000.0);
+        insert("node_disk_read_time_seconds_total", "_sda", "all", "counter", 0.0, 2.0);
+        insert("node_disk_read_bytes_total", "_sda", "all", "counter", 0.0, 104857600.0);
+        insert("node_disk_io_time_seconds_total", "_sda", "all", "counter", 0.0, 5.0);
+        insert("node_disk_reads_completed_total", "_dm-0", "detail", "counter", 0.0, 1000.0);
+        insert("node_network_receive_bytes_total", "_eth0", "all", "counter", 0.0, 10485760.0);
+        insert("node_network_receive_packets_total", "_eth0", "all", "counter", 0.0, 5000.0);
+        insert("node_memory_MemTotal_bytes", "", "all", "gauge", 8589934592.0, 8589934592.0);
+        insert("node_memory_MemAvailable_bytes", "", "all", "gauge", 0.0, 4294967296.0);
+        insert("node_vmstat_pswpin", "", "all", "counter", 10.0, 30.0);
+
+        let os_summary = diff.os_summary(&Regex::new(".*").unwrap(), &false);
+        let cpu = &os_summary.cpu[0];
+        assert_eq!((cpu.cpus, cpu.user, cpu.system, cpu.idle, cpu.iowait), (2, 15.0, 5.0, 80.0, 0.0));
+        // the device mapper device is a detail.
+        assert_eq!(os_summary.disk.len(), 1);
+        let disk = &os_summary.disk[0];
+        assert_eq!((disk.device.as_str(), disk.reads_per_second, disk.read_mb_per_second, disk.read_latency_ms, disk.utilization), ("sda", 100.0, 10.0, 2.0, 50.0));
+        let network = &os_summary.network[0];
+        assert_eq!((network.device.as_str(), network.receive_packets_per_second, network.receive_mb_per_second), ("eth0", 500.0, 1.0));
+        let memory = &os_summary.memory[0];
+        assert_eq!((memory.memory_total_mb, memory.memory_available_mb, memory.swap_in_per_second), (8192.0, 4096.0, 2.0));
+        assert_eq!(diff.os_summary(&Regex::new(".*").unwrap(), &true).disk.len(), 2);
+    }
+
     #[test]
     fn unit_parse_node_exporter_non_prometheus_data() {
         let fake_http_data = r#"
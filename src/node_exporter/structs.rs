@@ -37,4 +37,86 @@ type BTreeNodeExporterDiff = BTreeMap<(String, String, String), NameCategoryDiff
 #[derive(Default)]
 pub struct NodeExporterDiff {
     pub btreemapnodeexporterdiff: BTreeNodeExporterDiff,
-}
\ No newline at end of file
+}
+/// A row of the node_exporter diff report, used for the table and the machine readable output.
+///
+/// For a counter, `value` is the difference between the snapshots, and `rate` the difference per second.
+/// For a gauge, `value` is the value in the second snapshot, and `difference` the change between the snapshots.
+/// If the counter is reset, because the node rebooted or the counter went down, `value` is the value in the second snapshot, and `reset` is set.
+#[derive(Debug, Default, Serialize)]
+pub struct NodeExporterDiffRow {
+    pub hostname_port: String,
+    pub exporter_type: String,
+    pub name: String,
+    pub labels: String,
+    pub category: String,
+    pub value: f64,
+    pub difference: f64,
+    pub rate: f64,
+    pub reset: bool,
+}
+/// The OS summary report: the CPU, disk, network and memory figures per host,
+/// calculated from the node_exporter statistics between the snapshots, similar to sar and iostat.
+#[derive(Debug, Default)]
+pub struct OsSummary {
+    pub cpu: Vec<OsCpuRow>,
+    pub disk: Vec<OsDiskRow>,
+    pub network: Vec<OsNetworkRow>,
+    pub memory: Vec<OsMemoryRow>,
+}
+/// The CPU time per mode (`node_cpu_seconds_total`) as percentage of the elapsed time times the number of CPUs.
+#[derive(Debug, Default, Serialize)]
+pub struct OsCpuRow {
+    pub hostname_port: String,
+    pub cpus: usize,
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64,
+    pub idle: f64,
+    pub reset: bool,
+}
+/// The IO per block device (`node_disk_*`).
+/// The latency is the average time of a read or write in milliseconds, and the utilization the percentage of the elapsed time the device was busy.
+#[derive(Debug, Default, Serialize)]
+pub struct OsDiskRow {
+    pub hostname_port: String,
+    pub device: String,
+    pub reads_per_second: f64,
+    pub writes_per_second: f64,
+    pub read_mb_per_second: f64,
+    pub write_mb_per_second: f64,
+    pub read_latency_ms: f64,
+    pub write_latency_ms: f64,
+    pub utilization: f64,
+    pub reset: bool,
+}
+/// The network traffic per interface (`node_network_*`).
+#[derive(Debug, Default, Serialize)]
+pub struct OsNetworkRow {
+    pub hostname_port: String,
+    pub device: String,
+    pub receive_packets_per_second: f64,
+    pub transmit_packets_per_second: f64,
+    pub receive_mb_per_second: f64,
+    pub transmit_mb_per_second: f64,
+    pub reset: bool,
+}
+/// The memory and swap (`node_memory_*`) in the end snapshot, and the swapping (`node_vmstat_pswp*`) in pages per second.
+#[derive(Debug, Default, Serialize)]
+pub struct OsMemoryRow {
+    pub hostname_port: String,
+    pub memory_total_mb: f64,
+    pub memory_free_mb: f64,
+    pub memory_available_mb: f64,
+    pub buffers_mb: f64,
+    pub cached_mb: f64,
+    pub swap_total_mb: f64,
+    pub swap_free_mb: f64,
+    pub swap_in_per_second: f64,
+    pub swap_out_per_second: f64,
+    pub reset: bool,
+}
@@ -0,0 +1,188 @@
+//! The impls and functions
+//!
+use std::io::{self, Write};
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use crate::output::{Change, OutputFormat};
+
+impl OutputFormat {
+    /// Is the output the (default) text table format?
+    pub fn is_table(&self) -> bool {
+        *self == OutputFormat::Table
+    }
+}
+
+impl Change {
+    /// Determine the change of a diff entry, based on whether the first and second snapshot entries are empty.
+    pub fn from_empty(
+        first_is_empty: bool,
+        second_is_empty: bool,
+    ) -> Change
+    {
+        if first_is_empty {
+            Change::Added
+        } else if second_is_empty {
+            Change::Removed
+        } else {
+            Change::Changed
+        }
+    }
+}
+
+/// Print a message for the user, such as the snapshot list or the time between the snapshots.
+///
+/// With the table output the message is printed to stdout, with a machine readable output to stderr,
+/// so the message does not mix with the rows.
+pub fn print_message(
+    output: &OutputFormat,
+    message: &str,
+)
+{
+    if output.is_table() {
+        println!("{}", message);
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+/// Print the rows of a report in the requested machine readable format.
+///
+/// The report name is added as the first field of every row, so the output of multiple reports can be separated.
+/// For [OutputFormat::Table] nothing is printed: the table output is done by the report itself.
+pub fn print_rows<T: Serialize>(
+    output: &OutputFormat,
+    report: &str,
+    rows: &[T],
+) -> Result<()>
+{
+    let stdout = io::stdout();
+    match output {
+        OutputFormat::Table => {},
+        OutputFormat::Json => {
+            let mut handle = stdout.lock();
+            for row in rows {
+                writeln!(handle, "{}", serde_json::to_string(&json_row(report, row)?)?)?;
+            }
+        },
+        OutputFormat::Csv => {
+            let flattened_rows = rows.iter()
+                .map(|row| flatten_row(report, row))
+                .collect::<Result<Vec<_>>>()?;
+            // the header is the union of all the columns, in the order they are encountered.
+            let mut header: Vec<String> = Vec::new();
+            for row in &flattened_rows {
+                for column in row.keys() {
+                    if !header.contains(column) {
+                        header.push(column.clone());
+                    }
+                }
+            }
+            let mut writer = csv::Writer::from_writer(stdout.lock());
+            writer.write_record(&header)?;
+            for row in &flattened_rows {
+                writer.write_record(header.iter().map(|column| row.get(column).map(value_to_field).unwrap_or_default()))?;
+            }
+            writer.flush()?;
+        },
+    }
+    Ok(())
+}
+
+/// Create a JSON object for a row, with the report name as first field.
+/// If the row doesn't serialize to an object, it is added as field `value`.
+fn json_row<T: Serialize>(
+    report: &str,
+    row: &T,
+) -> Result<Value>
+{
+    let mut object = Map::new();
+    object.insert("report".to_string(), Value::String(report.to_string()));
+    match serde_json::to_value(row)? {
+        Value::Object(fields) => object.extend(fields),
+        other => { object.insert("value".to_string(), other); },
+    }
+    Ok(Value::Object(object))
+}
+
+/// Create a flat map of a row, with nested objects joined into `field.subfield` names.
+fn flatten_row<T: Serialize>(
+    report: &str,
+    row: &T,
+) -> Result<Map<String, Value>>
+{
+    let mut flattened = Map::new();
+    if let Value::Object(object) = json_row(report, row)? {
+        for (name, value) in object {
+            flatten_value(&name, value, &mut flattened);
+        }
+    }
+    Ok(flattened)
+}
+
+fn flatten_value(
+    name: &str,
+    value: Value,
+    flattened: &mut Map<String, Value>,
+)
+{
+    match value {
+        Value::Object(object) => {
+            for (field, value) in object {
+                flatten_value(&format!("{}.{}", name, field), value, flattened);
+            }
+        },
+        other => { flattened.insert(name.to_string(), other); },
+    }
+}
+
+/// Turn a JSON value into a CSV field.
+/// Strings are not quoted, null is empty, and arrays are kept as JSON text.
+fn value_to_field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Nested {
+        first: i64,
+        second: Option<String>,
+    }
+    #[derive(Serialize)]
+    struct Row {
+        hostname_port: String,
+        nested: Nested,
+        list: Vec<i64>,
+    }
+
+    #[test]
+    fn unit_json_row_adds_report_first() {
+        let row = Row { hostname_port: "host:7000".to_string(), nested: Nested { first: 1, second: None }, list: vec![1, 2] };
+        let result = serde_json::to_string(&json_row("test", &row).unwrap()).unwrap();
+        assert_eq!(result, r#"{"report":"test","hostname_port":"host:7000","nested":{"first":1,"second":null},"list":[1,2]}"#);
+    }
+
+    #[test]
+    fn unit_flatten_row_nested_objects() {
+        let row = Row { hostname_port: "host:7000".to_string(), nested: Nested { first: 1, second: Some("a".to_string()) }, list: vec![1, 2] };
+        let result = flatten_row("test", &row).unwrap();
+        assert_eq!(result.keys().collect::<Vec<_>>(), vec!["report", "hostname_port", "nested.first", "nested.second", "list"]);
+        assert_eq!(value_to_field(&result["nested.first"]), "1");
+        assert_eq!(value_to_field(&result["nested.second"]), "a");
+        assert_eq!(value_to_field(&result["list"]), "[1,2]");
+    }
+
+    #[test]
+    fn unit_flatten_row_null_is_empty() {
+        let row = Row { hostname_port: "host:7000".to_string(), nested: Nested { first: 1, second: None }, list: Vec::new() };
+        let result = flatten_row("test", &row).unwrap();
+        assert_eq!(value_to_field(&result["nested.second"]), "");
+    }
+}
@@ -0,0 +1,15 @@
+//! Module for the machine readable output of the reports (`--output`).
+//!
+//! By default, yb_stats prints its reports as text tables, aimed at a human reader.
+//! With `--output json` or `--output csv`, the print and diff reports produce rows instead:
+//! - json: every row is written as a single JSON object per line (JSON lines), with the report name in the field `report`.
+//! - csv: every report writes a header line, followed by the rows. Nested fields are flattened into `field.subfield` columns.
+//!
+//! The rows are the serializable structs of the individual modules, which allows the output to be processed by tools
+//! such as `jq`, spreadsheets or databases.
+//!
+mod structs;
+mod functions;
+
+pub use structs::*;
+pub use functions::*;
@@ -0,0 +1,38 @@
+//! The structs
+//!
+use clap::ValueEnum;
+
+/// The output format for the print and diff reports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text tables (default).
+    #[default]
+    Table,
+    /// JSON lines: one JSON object per row.
+    Json,
+    /// CSV with a header line per report.
+    Csv,
+}
+
+/// Wrapper struct to add the hostname_port to a row that doesn't contain it.
+///
+/// The source data of most modules is a struct per hostname_port with a vector of rows.
+/// This struct allows such a row to be serialized with the hostname_port added in front of it.
+#[derive(Debug, Serialize)]
+pub struct HostRow<'a, T: serde::Serialize> {
+    pub hostname_port: &'a str,
+    #[serde(flatten)]
+    pub row: &'a T,
+}
+
+/// The change of an entry in a diff report between the first and the second snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    /// The entry is only found in the second snapshot.
+    Added,
+    /// The entry is only found in the first snapshot.
+    Removed,
+    /// The entry is found in both snapshots, but is different.
+    Changed,
+}
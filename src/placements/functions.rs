@@ -0,0 +1,166 @@
+//! The impls and functions
+//!
+use std::collections::BTreeSet;
+use log::*;
+use anyhow::Result;
+use crate::masters::{GetMasterRegistrationRequestPB, Masters};
+use crate::tablet_servers::{AllTabletServers, TabletServer};
+use crate::placements::{GroupBy, Placement, Placements};
+use crate::snapshot;
+
+impl Placements {
+    pub fn new() -> Self { Default::default() }
+    /// Create the placements from the masters and tablet servers.
+    ///
+    /// The placement of the tablet server is used for its host, because the YSQL and YCQL servers are part of the tablet server.
+    pub fn from_servers(
+        masters: &[GetMasterRegistrationRequestPB],
+        tablet_servers: &[TabletServer],
+    ) -> Self
+    {
+        let mut placements = Placements::new();
+        for tablet_server in tablet_servers {
+            if let Some(hostname_port) = &tablet_server.tablet_server_hostname_port {
+                placements.add(hostname_port, Placement {
+                    cloud: tablet_server.cloud.clone(),
+                    region: tablet_server.region.clone(),
+                    zone: tablet_server.zone.clone(),
+                });
+            }
+        }
+        for master in masters {
+            let Some(registration) = &master.registration else { continue };
+            let http_address = registration.http_addresses.as_ref().and_then(|http_addresses| http_addresses.first());
+            if let (Some(http_address), Some(cloud_info)) = (http_address, &registration.cloud_info) {
+                placements.add(&format!("{}:{}", http_address.host, http_address.port), Placement {
+                    cloud: cloud_info.placement_cloud.clone().unwrap_or_default(),
+                    region: cloud_info.placement_region.clone().unwrap_or_default(),
+                    zone: cloud_info.placement_zone.clone().unwrap_or_default(),
+                });
+            }
+        }
+        placements
+    }
+    /// Add the placement of a server, and of its host if the host has no placement yet.
+    fn add(
+        &mut self,
+        hostname_port: &str,
+        placement: Placement,
+    )
+    {
+        if let Some((host, _)) = hostname_port.rsplit_once(':') {
+            self.hosts.entry(host.to_string()).or_insert_with(|| placement.clone());
+        }
+        self.servers.insert(hostname_port.to_string(), placement);
+    }
+    /// Read the placements from the masters and tablet servers data in a snapshot.
+    ///
+    /// A snapshot that does not contain the masters or tablet servers data results in no placements for these.
+    pub fn snapshot(
+        snapshot_number: &String,
+    ) -> Result<Placements>
+    {
+        let masters = snapshot::read_snapshot_json::<GetMasterRegistrationRequestPB>(snapshot_number, "masters")
+            .unwrap_or_else(|error| {
+                info!("No masters data for the placements: {:#}", error);
+                Vec::new()
+            });
+        let tablet_servers = snapshot::read_snapshot_json::<TabletServer>(snapshot_number, "tablet_servers")
+            .unwrap_or_else(|error| {
+                info!("No tablet servers data for the placements: {:#}", error);
+                Vec::new()
+            });
+        Ok(Placements::from_servers(&masters, &tablet_servers))
+    }
+    /// Read the placements from the masters.
+    pub async fn read(
+        hosts: &Vec<&str>,
+        ports: &Vec<&str>,
+        parallel: usize,
+    ) -> Placements
+    {
+        let masters = Masters::read_masters(hosts, ports, parallel).await;
+        let tablet_servers = AllTabletServers::read_tabletservers(hosts, ports, parallel).await;
+        Placements::from_servers(&masters.masters, &tablet_servers.tabletservers)
+    }
+    /// Return the placement of the server at `hostname_port` at the group by level: `cloud.region` or `cloud.region.zone`.
+    ///
+    /// A server that has no placement itself gets the placement of its host, and "unknown" if the host has no placement either.
+    pub fn placement(
+        &self,
+        hostname_port: &str,
+        group_by: &GroupBy,
+    ) -> String
+    {
+        match (self.find(hostname_port), group_by) {
+            (Some(placement), GroupBy::Region) => format!("{}.{}", placement.cloud, placement.region),
+            (Some(placement), GroupBy::Zone) => format!("{}.{}.{}", placement.cloud, placement.region, placement.zone),
+            (None, _) => "unknown".to_string(),
+        }
+    }
+    /// Return the servers that have no placement, which [placement](Placements::placement) returns as "unknown".
+    ///
+    /// The placement is found by the hostname and port as registered with the masters,
+    /// so a server that is read using another name or IP address for the same host has no placement.
+    pub fn unknown_servers<'a>(
+        &self,
+        hostname_ports: impl IntoIterator<Item = &'a str>,
+    ) -> BTreeSet<&'a str>
+    {
+        hostname_ports.into_iter()
+            .filter(|hostname_port| self.find(hostname_port).is_none())
+            .collect()
+    }
+    /// Find the placement of the server, or of its host.
+    fn find(
+        &self,
+        hostname_port: &str,
+    ) -> Option<&Placement>
+    {
+        self.servers.get(hostname_port)
+            .or_else(|| hostname_port.rsplit_once(':').and_then(|(host, _)| self.hosts.get(host)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_placements_from_servers() {
+        let masters: Masters = serde_json::from_str(r#"
+{
+  "masters": [
+    {
+      "instance_id": { "permanent_uuid": "3fc1141619304cffa2f0a345d37a51c2", "instance_seqno": 1657972299220554, "start_time_us": 1657972299220554 },
+      "registration": {
+        "private_rpc_addresses": [ { "host": "yb-3.local", "port": 7100 } ],
+        "http_addresses": [ { "host": "yb-3.local", "port": 7000 } ],
+        "cloud_info": { "placement_cloud": "aws", "placement_region": "eu-west-1", "placement_zone": "eu-west-1c" },
+        "placement_uuid": ""
+      },
+      "role": "LEADER"
+    }
+  ]
+}
+        "#).unwrap();
+        let tablet_servers: Vec<TabletServer> = [("yb-1.local:9000", "eu-west-1a"), ("yb-2.local:9000", "eu-west-1b")].iter()
+            .map(|(hostname_port, zone)| TabletServer {
+                tablet_server_hostname_port: Some(hostname_port.to_string()),
+                cloud: "aws".to_string(),
+                region: "eu-west-1".to_string(),
+                zone: zone.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        let placements = Placements::from_servers(&masters.masters, &tablet_servers);
+        assert_eq!(placements.placement("yb-1.local:9000", &GroupBy::Zone), "aws.eu-west-1.eu-west-1a");
+        assert_eq!(placements.placement("yb-2.local:9000", &GroupBy::Region), "aws.eu-west-1");
+        assert_eq!(placements.placement("yb-3.local:7000", &GroupBy::Zone), "aws.eu-west-1.eu-west-1c");
+        // the YSQL server gets the placement of the tablet server on its host.
+        assert_eq!(placements.placement("yb-2.local:13000", &GroupBy::Zone), "aws.eu-west-1.eu-west-1b");
+        assert_eq!(placements.placement("yb-4.local:9000", &GroupBy::Zone), "unknown");
+        // the same server read by its IP address has no placement.
+        assert_eq!(placements.unknown_servers(["yb-1.local:9000", "192.168.66.80:9000", "yb-2.local:12000", "192.168.66.80:9000"]), BTreeSet::from(["192.168.66.80:9000"]));
+    }
+}
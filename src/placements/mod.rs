@@ -0,0 +1,12 @@
+//! Module for mapping the servers to their placement: cloud, region and zone.
+//!
+//! The placement is read from:
+//! - `/api/v1/masters`: the cloud info of the master registration.
+//! - `/api/v1/tablet-servers`: the cloud, region and zone of the tablet servers.
+//!
+//! The YSQL, YCQL and node_exporter endpoints have no placement of their own, these get the placement of the tablet server or master on the same host.
+//!
+mod structs;
+mod functions;
+
+pub use structs::*;
@@ -0,0 +1,27 @@
+//! The structs
+//!
+use std::collections::BTreeMap;
+use clap::ValueEnum;
+/// The placement of a server.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub cloud: String,
+    pub region: String,
+    pub zone: String,
+}
+/// The placements of the servers of a cluster.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Placements {
+    /// The placement per `hostname_port` of the masters and tablet servers.
+    pub servers: BTreeMap<String, Placement>,
+    /// The placement per hostname, for the other endpoints on the host of a tablet server or master.
+    pub hosts: BTreeMap<String, Placement>,
+}
+/// The placement level to add up the statistics by, set with `--group-by`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    /// Per cloud and region.
+    Region,
+    /// Per cloud, region and zone.
+    Zone,
+}
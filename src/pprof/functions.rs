@@ -1,9 +1,9 @@
 //! The impls and functions.
 //!
-use std::{fs, io::Write, sync::mpsc::channel, time::Instant, env};
+use std::{fs, io::Write, sync::mpsc::channel, time::Instant};
 use log::*;
 use anyhow::{Result, Context};
-use crate::utility;
+use crate::utility::{self, Role};
 
 pub struct Pprof;
 
@@ -38,14 +38,18 @@ impl Pprof {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let pprof_data = Pprof::read_http(host, port);
-                        tx.send((format!("{}:{}", host, port), pprof_data)).expect("error sending data via tx (pprof)");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver, Role::Ycql]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let pprof_data = match Pprof::read_http(host, port) {
+                        Ok(pprof_data) => pprof_data,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tx.send((format!("{}:{}", host, port), pprof_data)).expect("error sending data via tx (pprof)");
+                });
             }
         });
 
@@ -55,13 +59,13 @@ impl Pprof {
             if pprof_data.starts_with("heap profile")
                 && snapshot_number >= 0
             {
-                let current_directory = env::current_dir()?;
-                let current_snapshot_directory = current_directory.join("yb_stats.snapshots").join(snapshot_number.to_string());
+                let current_snapshot_directory = utility::snapshot_directory()?.join(snapshot_number.to_string());
 
                 let pprof_file = &current_snapshot_directory.join(format!("pprof_growth_{}.txt", hostname_port));
                 let mut file = fs::OpenOptions::new()
                     .create(true)
                     .write(true)
+                    .truncate(true)
                     .open(pprof_file)
                     .with_context(|| format!("Cannot create file: {}", pprof_file.display()))?;
 
@@ -74,9 +78,9 @@ impl Pprof {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> String
+    ) -> Result<String>
     {
-        utility::http_get(host, port, "pprof/growth")
+        utility::http_get(host, port, "pprof/growth").into_body()
     }
 }
 
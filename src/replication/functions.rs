@@ -0,0 +1,338 @@
+//! The impls and functions
+//!
+use std::collections::{BTreeMap, HashMap};
+use regex::Regex;
+use log::*;
+use anyhow::Result;
+use crate::metrics::{Attributes, MetricEntity};
+use crate::cluster_config::SysClusterConfigEntryPB;
+use crate::replication::{Replication, ReplicationStreamRow, StreamTopology};
+use crate::output::{self, OutputFormat};
+use crate::{snapshot, utility};
+use crate::Opts;
+
+/// The statistics of the `cdc` and `cdcsdk` metric entities for the lag between the source and the target, in microseconds.
+const SENT_LAG_STATISTICS: [&str; 2] = ["async_replication_sent_lag_micros", "cdcsdk_sent_lag_micros"];
+const COMMITTED_LAG_STATISTICS: [&str; 1] = ["async_replication_committed_lag_micros"];
+/// The statistics of the `cdc` and `cdcsdk` metric entities for the bytes sent to the target.
+const BYTES_SENT_STATISTICS: [&str; 2] = ["rpc_payload_bytes_responded", "cdcsdk_traffic_sent"];
+/// The statistics of the `cdcsdk` metric entities for the change events sent to the target.
+const EVENTS_STATISTICS: [&str; 1] = ["cdcsdk_change_event_count"];
+
+impl Replication {
+    /// Create the replication report between the begin and end snapshot.
+    ///
+    /// The topology is taken from the cluster config in the end snapshot.
+    pub fn snapshot_diff(
+        begin_snapshot: &String,
+        end_snapshot: &String,
+        hostname_filter: &Regex,
+        table_name_filter: &Regex,
+    ) -> Result<Replication>
+    {
+        let begin: Vec<MetricEntity> = snapshot::read_snapshot_json(begin_snapshot, "metrics")?;
+        let end: Vec<MetricEntity> = snapshot::read_snapshot_json(end_snapshot, "metrics")?;
+        let cluster_config: Vec<SysClusterConfigEntryPB> = snapshot::read_snapshot_json(end_snapshot, "cluster-config")?;
+        Ok(Replication::create(&begin, &end, &stream_topology(&cluster_config), hostname_filter, table_name_filter))
+    }
+    /// Add up the figures of the stream tablets per stream and table, and join these with the topology.
+    ///
+    /// A stream in the topology without metrics is reported too, because the xCluster target only has the topology.
+    /// Such a stream has no server and table name, so the filters are applied to empty names: it's left out with a hostname or table name filter.
+    fn create(
+        begin: &[MetricEntity],
+        end: &[MetricEntity],
+        topology: &BTreeMap<String, StreamTopology>,
+        hostname_filter: &Regex,
+        table_name_filter: &Regex,
+    ) -> Replication
+    {
+        let begin_tablets = stream_tablets(begin);
+        let mut streams: BTreeMap<(String, String), ReplicationStreamRow> = BTreeMap::new();
+        for ((hostname_port, id), tablet) in stream_tablets(end)
+        {
+            let Some(stream_id) = tablet.attributes.as_ref().and_then(|attributes| attributes.stream_id.clone()) else {
+                debug!("{} entity without stream_id: {}:{}", tablet.metrics_type, hostname_port, id);
+                continue;
+            };
+            let table_name = table_info(tablet.attributes.as_ref());
+            if !hostname_filter.is_match(&hostname_port) || !table_name_filter.is_match(&table_name) { continue };
+
+            let begin_tablet = begin_tablets.get(&(hostname_port.clone(), id.clone()));
+            // a statistic that went down is reset, because the server restarted, and then the end figure is the difference.
+            let difference = |names: &[&str]| {
+                let end_value = statistic(tablet, names);
+                let begin_value = begin_tablet.map(|begin_tablet| statistic(begin_tablet, names)).unwrap_or_default();
+                if end_value >= begin_value { end_value - begin_value } else { end_value }
+            };
+            let seconds = begin_tablet
+                .and_then(|begin_tablet| Some((tablet.timestamp? - begin_tablet.timestamp?).num_milliseconds() as f64 / 1000_f64))
+                .unwrap_or_default();
+
+            let row = streams.entry((stream_id.clone(), table_name.clone()))
+                .or_insert_with(|| ReplicationStreamRow {
+                    stream_id: stream_id.clone(),
+                    stream_type: tablet.metrics_type.clone(),
+                    table_name,
+                    topology: topology.get(&stream_id).cloned().unwrap_or_default(),
+                    ..Default::default()
+                });
+            let bytes_sent = difference(&BYTES_SENT_STATISTICS);
+            row.tablets += 1;
+            row.sent_lag_ms = row.sent_lag_ms.max(statistic(tablet, &SENT_LAG_STATISTICS) as f64 / 1000_f64);
+            row.committed_lag_ms = row.committed_lag_ms.max(statistic(tablet, &COMMITTED_LAG_STATISTICS) as f64 / 1000_f64);
+            row.bytes_sent += bytes_sent.max(0) as u64;
+            if seconds > 0_f64 {
+                row.bytes_per_second += bytes_sent as f64 / seconds;
+            }
+            row.events += difference(&EVENTS_STATISTICS).max(0) as u64;
+        }
+        let include_topology_only = hostname_filter.is_match("") && table_name_filter.is_match("");
+        for (stream_id, stream_topology) in topology.iter().filter(|_| include_topology_only)
+        {
+            if !streams.keys().any(|(key, _)| key == stream_id) {
+                streams.insert((stream_id.clone(), String::new()), ReplicationStreamRow {
+                    stream_id: stream_id.clone(),
+                    topology: stream_topology.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+        Replication { streams: streams.into_values().collect() }
+    }
+    pub fn print(
+        &self,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        if !output.is_table()
+        {
+            return output::print_rows(output, "replication", &self.streams);
+        }
+        println!("{:32} {:6} {:30} {:>7} {:32} {:>13} {:>13} {:>14} {:>12} {:>10}", "stream_id", "type", "table", "tablets", "replication_group", "sent lag ms", "commit lag ms", "bytes sent", "bytes/s", "events");
+        for row in &self.streams
+        {
+            println!("{:32} {:6} {:30} {:>7} {:32} {:>13.3} {:>13.3} {:>14} {:>12.3} {:>10}{}",
+                     row.stream_id,
+                     row.stream_type,
+                     row.table_name,
+                     row.tablets,
+                     row.topology.replication_group,
+                     row.sent_lag_ms,
+                     row.committed_lag_ms,
+                     row.bytes_sent,
+                     row.bytes_per_second,
+                     row.events,
+                     if row.topology.disabled { " disabled" } else { "" },
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Collect the `cdc` and `cdcsdk` metric entities by hostname_port and entity id.
+/// The entity id of these is the stream id and the tablet id, so an entity is a stream tablet on a server.
+fn stream_tablets(
+    metricentities: &[MetricEntity],
+) -> HashMap<(String, String), &MetricEntity>
+{
+    metricentities.iter()
+        .filter(|metricentity| metricentity.metrics_type == "cdc" || metricentity.metrics_type == "cdcsdk")
+        .map(|metricentity| ((metricentity.hostname_port.clone().unwrap_or_default(), metricentity.id.clone()), metricentity))
+        .collect()
+}
+
+/// Add up the value statistics with the given names of a metric entity, including the values that only fit in an u64.
+/// A `cdc` and a `cdcsdk` entity each have one of the alternative names.
+fn statistic(
+    metricentity: &MetricEntity,
+    names: &[&str],
+) -> i128
+{
+    metricentity.metrics.iter()
+        .filter_map(|metric| metric.wide_value())
+        .filter(|(name, _)| names.contains(&name.as_str()))
+        .map(|(_, value)| value)
+        .sum()
+}
+
+/// Create the table info as `namespace.table_name`, or the table name only if there is no namespace.
+fn table_info(
+    attributes: Option<&Attributes>,
+) -> String
+{
+    let namespace = attributes.and_then(|attributes| attributes.namespace_name.as_deref()).unwrap_or_default();
+    let table_name = attributes.and_then(|attributes| attributes.table_name.as_deref()).unwrap_or_default();
+    if namespace.is_empty() {
+        table_name.to_string()
+    } else {
+        format!("{}.{}", namespace, table_name)
+    }
+}
+
+/// Take the xCluster topology of the streams from the consumer registry in the cluster config.
+/// The cluster config is read from every master, so a stream is found once per master.
+fn stream_topology(
+    cluster_config: &[SysClusterConfigEntryPB],
+) -> BTreeMap<String, StreamTopology>
+{
+    let mut topology = BTreeMap::new();
+    for consumer_registry in cluster_config.iter().filter_map(|entry| entry.consumer_registry.as_ref())
+    {
+        for producer in &consumer_registry.producer_map
+        {
+            for stream in &producer.value.stream_map
+            {
+                topology.entry(stream.key.clone())
+                    .or_insert_with(|| StreamTopology {
+                        replication_group: producer.key.clone(),
+                        producer_table_id: stream.value.producer_table_id.clone(),
+                        consumer_table_id: stream.value.consumer_table_id.clone(),
+                        disabled: producer.value.disable_stream.unwrap_or_default(),
+                    });
+            }
+        }
+    }
+    topology
+}
+
+pub async fn replication_diff(
+    options: &Opts,
+) -> Result<()>
+{
+    if options.begin.is_none() || options.end.is_none() {
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
+    }
+    if options.snapshot_list { return Ok(()) };
+
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+    let table_name_filter = utility::set_regex(&options.table_name_match);
+
+    let (begin_snapshot, end_snapshot, _) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
+
+    let replication = Replication::snapshot_diff(&begin_snapshot, &end_snapshot, &hostname_filter, &table_name_filter)?;
+    replication.print(&options.output)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Local};
+
+    /// Create the cluster config of an xCluster target with a stream in the consumer registry.
+    fn cluster_config(stream_id: &str) -> SysClusterConfigEntryPB {
+        let json = format!(r#"
+{{
+    "version":54,
+    "cluster_uuid":"63edb5bd-8855-41b8-bb64-67d611235f1e",
+    "consumer_registry":
+    {{
+        "producer_map":
+        [
+            {{
+                "key":"db8329ec-b249-490c-96d0-cbe6bfa6f0b6_setup1",
+                "value":
+                {{
+                    "stream_map":
+                    [
+                        {{
+                            "key":"{}",
+                            "value":
+                            {{
+                                "consumer_producer_tablet_map": [],
+                                "consumer_table_id":"000033e6000030008000000000004005",
+                                "producer_table_id":"000033e6000030008000000000004006",
+                                "local_tserver_optimized":true
+                            }}
+                        }}
+                    ],
+                    "disable_stream":true
+                }}
+            }}
+        ]
+    }}
+}}"#, stream_id);
+        serde_json::from_str(&json).unwrap()
+    }
+
+    /// Create a cdc metric entity for a stream tablet on a server.
+    fn stream_tablet(hostname_port: &str, tablet_id: &str, sent_lag: i64, bytes_sent: u64, timestamp: DateTime<Local>) -> MetricEntity {
+        let json = format!(r#"
+{{
+    "type": "cdc",
+    "id": ":face4edb05934e77b564857878cf5015:{}",
+    "attributes": {{ "stream_id": "face4edb05934e77b564857878cf5015", "table_name": "table0", "namespace_name": "test", "table_id": "c70ffbbe28f14e84b0559c405ae20197" }},
+    "metrics": [
+        {{ "name": "async_replication_sent_lag_micros", "value": {} }},
+        {{ "name": "async_replication_committed_lag_micros", "value": {} }},
+        {{ "name": "rpc_payload_bytes_responded", "value": {} }}
+    ]
+}}"#, tablet_id, sent_lag, sent_lag / 2, bytes_sent);
+        let mut metricentity: MetricEntity = serde_json::from_str(&json).unwrap();
+        metricentity.hostname_port = Some(hostname_port.to_string());
+        metricentity.timestamp = Some(timestamp);
+        metricentity
+    }
+
+    #[test]
+    fn unit_stream_topology_from_consumer_registry() {
+        let topology = stream_topology(&[cluster_config("3a395c2133004d90bb0e572c727174bd"), cluster_config("3a395c2133004d90bb0e572c727174bd")]);
+        assert_eq!(topology.len(), 1);
+        let stream = &topology["3a395c2133004d90bb0e572c727174bd"];
+        assert_eq!(stream.replication_group, "db8329ec-b249-490c-96d0-cbe6bfa6f0b6_setup1");
+        assert_eq!((stream.producer_table_id.as_str(), stream.consumer_table_id.as_str()), ("000033e6000030008000000000004006", "000033e6000030008000000000004005"));
+        assert!(stream.disabled);
+    }
+
+    #[test]
+    fn unit_replication_per_stream_and_table() {
+        let begin_time = Local::now();
+        let end_time = begin_time + chrono::Duration::seconds(10);
+        let begin = vec![
+            stream_tablet("yb-1.local:9000", "tablet-1", 1000, 100, begin_time),
+            stream_tablet("yb-2.local:9000", "tablet-2", 1000, 500, begin_time),
+        ];
+        // the second server restarted, so its bytes sent went down.
+        let end = vec![
+            stream_tablet("yb-1.local:9000", "tablet-1", 4000, 300, end_time),
+            stream_tablet("yb-2.local:9000", "tablet-2", 2000, 50, end_time),
+        ];
+        let topology = stream_topology(&[cluster_config("3a395c2133004d90bb0e572c727174bd")]);
+        let all = Regex::new(".*").unwrap();
+        let replication = Replication::create(&begin, &end, &topology, &all, &all);
+
+        assert_eq!(replication.streams.len(), 2);
+        // the xCluster target stream is only found in the consumer registry.
+        assert_eq!((replication.streams[0].stream_id.as_str(), replication.streams[0].stream_type.as_str(), replication.streams[0].tablets), ("3a395c2133004d90bb0e572c727174bd", "", 0));
+        let stream = &replication.streams[1];
+        assert_eq!((stream.stream_id.as_str(), stream.stream_type.as_str(), stream.table_name.as_str()), ("face4edb05934e77b564857878cf5015", "cdc", "test.table0"));
+        assert_eq!(stream.tablets, 2);
+        assert_eq!((stream.sent_lag_ms, stream.committed_lag_ms), (4.0, 2.0));
+        assert_eq!(stream.bytes_sent, 250);
+        assert_eq!(stream.bytes_per_second, 25.0);
+        assert_eq!(stream.topology, StreamTopology::default());
+
+        // the stream that is only found in the consumer registry has no table name, so it's left out with a table name filter.
+        let replication = Replication::create(&begin, &end, &topology, &all, &Regex::new("table0").unwrap());
+        assert_eq!(replication.streams.len(), 1);
+        assert_eq!(replication.streams[0].stream_id, "face4edb05934e77b564857878cf5015");
+        let replication = Replication::create(&begin, &end, &topology, &Regex::new("yb-1").unwrap(), &all);
+        assert_eq!(replication.streams.len(), 1);
+        assert_eq!(replication.streams[0].tablets, 1);
+    }
+
+    #[test]
+    fn unit_replication_u64_value() {
+        let begin_time = Local::now();
+        let end_time = begin_time + chrono::Duration::seconds(10);
+        // a value that does not fit in an i64 is parsed as RejectedU64MetricValue.
+        let begin = vec![stream_tablet("yb-1.local:9000", "tablet-1", 1000, 18446744073709551500, begin_time)];
+        let end = vec![stream_tablet("yb-1.local:9000", "tablet-1", 1000, 18446744073709551600, end_time)];
+        let all = Regex::new(".*").unwrap();
+        let replication = Replication::create(&begin, &end, &BTreeMap::new(), &all, &all);
+        assert_eq!(replication.streams.len(), 1);
+        assert_eq!(replication.streams[0].bytes_sent, 100);
+    }
+}
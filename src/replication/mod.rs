@@ -0,0 +1,11 @@
+//! Module for the replication report: the CDC and xCluster streams between two snapshots, per stream and table.
+//!
+//! The figures are taken from the `cdc` (xCluster) and `cdcsdk` (CDC) metric entities of the tablet servers, which carry the stream_id attribute.
+//! The streams are joined with the consumer registry in `/api/v1/cluster-config` of the masters,
+//! which holds the xCluster replication group and the producer and consumer tables of the streams that the cluster is the xCluster target of.
+//!
+mod structs;
+mod functions;
+
+pub use structs::*;
+pub use functions::*;
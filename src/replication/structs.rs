@@ -0,0 +1,38 @@
+//! The structs
+//!
+/// The replication report: the streams per stream and table.
+#[derive(Debug, Default)]
+pub struct Replication {
+    pub streams: Vec<ReplicationStreamRow>,
+}
+/// The xCluster topology of a stream, from the consumer registry of the cluster config.
+/// The consumer registry is only set on the xCluster target.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct StreamTopology {
+    /// The xCluster replication group: the key of the producer map, which is the producer universe uuid, optionally with a name.
+    pub replication_group: String,
+    pub producer_table_id: String,
+    pub consumer_table_id: String,
+    pub disabled: bool,
+}
+/// A row of the replication report for a stream and table.
+///
+/// The lags are the highest lag of the tablets of the stream in the end snapshot.
+/// The bytes sent and events are the difference between the snapshots, added up for the tablets of the stream.
+/// If a statistic went down, because the server restarted, the figure in the end snapshot is taken.
+#[derive(Debug, Default, Serialize)]
+pub struct ReplicationStreamRow {
+    pub stream_id: String,
+    /// cdc for an xCluster stream, cdcsdk for a CDC stream, and empty if the stream is only found in the consumer registry.
+    pub stream_type: String,
+    pub table_name: String,
+    /// The number of tablets with metrics for the stream.
+    pub tablets: usize,
+    #[serde(flatten)]
+    pub topology: StreamTopology,
+    pub sent_lag_ms: f64,
+    pub committed_lag_ms: f64,
+    pub bytes_sent: u64,
+    pub bytes_per_second: f64,
+    pub events: u64,
+}
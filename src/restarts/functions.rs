@@ -0,0 +1,231 @@
+//! The impls and functions
+//!
+use std::collections::BTreeMap;
+use log::*;
+use anyhow::Result;
+use crate::masters::{GetMasterRegistrationRequestPB, Masters};
+use crate::tablet_servers::{AllTabletServers, TabletServer};
+use crate::restarts::{Restarts, RestartsSnapshot};
+use crate::snapshot;
+
+impl RestartsSnapshot {
+    /// Read the masters and tablet servers from the cluster, for the restart detection of the ad-hoc diffs.
+    pub async fn read(
+        hosts: &Vec<&str>,
+        ports: &Vec<&str>,
+        parallel: usize,
+    ) -> RestartsSnapshot
+    {
+        RestartsSnapshot {
+            masters: Masters::read_masters(hosts, ports, parallel).await.masters,
+            tablet_servers: AllTabletServers::read_tabletservers(hosts, ports, parallel).await.tabletservers,
+        }
+    }
+}
+
+impl Restarts {
+    pub fn new() -> Self { Default::default() }
+    /// Detect the restarts between the begin and end snapshot from the stored masters and tablet servers data.
+    ///
+    /// Snapshots that do not contain the masters or tablet servers data result in no restarts detected for these.
+    pub fn snapshot_diff(
+        begin_snapshot: &String,
+        end_snapshot: &String,
+    ) -> Result<Restarts>
+    {
+        let read_masters = |snapshot_number: &String| snapshot::read_snapshot_json::<GetMasterRegistrationRequestPB>(snapshot_number, "masters")
+            .unwrap_or_else(|error| {
+                info!("No masters data for restart detection: {:#}", error);
+                Vec::new()
+            });
+        let read_tablet_servers = |snapshot_number: &String| snapshot::read_snapshot_json::<TabletServer>(snapshot_number, "tablet_servers")
+            .unwrap_or_else(|error| {
+                info!("No tablet servers data for restart detection: {:#}", error);
+                Vec::new()
+            });
+
+        Ok(Restarts::adhoc_diff(
+            &RestartsSnapshot { masters: read_masters(begin_snapshot), tablet_servers: read_tablet_servers(begin_snapshot) },
+            &RestartsSnapshot { masters: read_masters(end_snapshot), tablet_servers: read_tablet_servers(end_snapshot) },
+        ))
+    }
+    /// Detect the restarts between the first and second ad-hoc (in memory) snapshot.
+    pub fn adhoc_diff(
+        first_snapshot: &RestartsSnapshot,
+        second_snapshot: &RestartsSnapshot,
+    ) -> Restarts
+    {
+        let mut restarts = Restarts::new();
+        restarts.masters(&first_snapshot.masters, &second_snapshot.masters);
+        restarts.tablet_servers(&first_snapshot.tablet_servers, &second_snapshot.tablet_servers);
+        restarts
+    }
+    /// Find the restarted masters: a master that restarted has another instance sequence number or start time.
+    ///
+    /// The masters are identified by their http address, which is the `hostname_port` used to read their endpoints.
+    fn masters(
+        &mut self,
+        begin_masters: &[GetMasterRegistrationRequestPB],
+        end_masters: &[GetMasterRegistrationRequestPB],
+    )
+    {
+        let instances = |masters: &[GetMasterRegistrationRequestPB]| {
+            let mut instances = BTreeMap::new();
+            for master in masters {
+                let http_address = master.registration.as_ref()
+                    .and_then(|registration| registration.http_addresses.as_ref())
+                    .and_then(|http_addresses| http_addresses.first());
+                if let Some(http_address) = http_address {
+                    // the masters are read from every master, the first one of each is enough.
+                    instances.entry(format!("{}:{}", http_address.host, http_address.port))
+                        .or_insert((master.instance_id.instance_seqno, master.instance_id.start_time_us));
+                }
+            }
+            instances
+        };
+        let begin_instances = instances(begin_masters);
+        for (hostname_port, end_instance) in instances(end_masters) {
+            if let Some(begin_instance) = begin_instances.get(&hostname_port) {
+                if *begin_instance != end_instance {
+                    info!("master restarted: {}", hostname_port);
+                    self.servers.insert(hostname_port.clone());
+                }
+            }
+            self.masters.insert(hostname_port);
+        }
+    }
+    /// Find the restarted tablet servers: a tablet server that restarted has an uptime shorter than the time between the snapshots.
+    ///
+    /// The uptime is only used if it's reported, an uptime of zero in the begin snapshot means it's not reported.
+    fn tablet_servers(
+        &mut self,
+        begin_tablet_servers: &[TabletServer],
+        end_tablet_servers: &[TabletServer],
+    )
+    {
+        for end in end_tablet_servers {
+            let Some(hostname_port) = &end.tablet_server_hostname_port else { continue };
+            let begin = begin_tablet_servers.iter()
+                .find(|begin| begin.tablet_server_hostname_port.as_ref() == Some(hostname_port));
+            let (Some(begin), Some(begin_timestamp), Some(end_timestamp)) = (begin, begin.and_then(|begin| begin.timestamp), end.timestamp) else { continue };
+            if begin.uptime_seconds > 0
+                && (end.uptime_seconds as i64) < (end_timestamp - begin_timestamp).num_seconds()
+            {
+                info!("tablet server restarted: {}, uptime: {}", hostname_port, end.uptime_seconds);
+                self.servers.insert(hostname_port.clone());
+                if let Some((host, _)) = hostname_port.rsplit_once(':') {
+                    self.tablet_server_hosts.insert(host.to_string());
+                }
+            }
+        }
+    }
+    /// Return if the server at `hostname_port` restarted.
+    ///
+    /// Besides the restarted masters and tablet servers, any server other than a master on the host of a restarted tablet server
+    /// is taken as restarted, because the YSQL and YCQL servers are part of the tablet server.
+    pub fn is_restarted(
+        &self,
+        hostname_port: &str,
+    ) -> bool
+    {
+        if self.servers.contains(hostname_port) {
+            return true;
+        }
+        match hostname_port.rsplit_once(':') {
+            Some((host, _)) => self.tablet_server_hosts.contains(host) && !self.masters.contains(hostname_port),
+            None => false,
+        }
+    }
+    /// Return the restarted servers as a comma separated list, for reporting.
+    pub fn list(
+        &self,
+    ) -> String
+    {
+        self.servers.iter().cloned().collect::<Vec<_>>().join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+    use crate::masters::Masters;
+
+    fn masters(instance_seqno: i64) -> Vec<GetMasterRegistrationRequestPB> {
+        let json = format!(r#"
+{{
+  "masters": [
+    {{
+      "instance_id": {{ "permanent_uuid": "3fc1141619304cffa2f0a345d37a51c2", "instance_seqno": {}, "start_time_us": {} }},
+      "registration": {{
+        "private_rpc_addresses": [ {{ "host": "yb-1.local", "port": 7100 }} ],
+        "http_addresses": [ {{ "host": "yb-1.local", "port": 7000 }} ],
+        "cloud_info": {{ "placement_cloud": "local", "placement_region": "local", "placement_zone": "local" }},
+        "placement_uuid": ""
+      }},
+      "role": "LEADER"
+    }}
+  ]
+}}
+        "#, instance_seqno, instance_seqno);
+        serde_json::from_str::<Masters>(&json).unwrap().masters
+    }
+
+    fn tablet_server(hostname_port: &str, uptime_seconds: u64, timestamp: chrono::DateTime<Local>) -> TabletServer {
+        TabletServer {
+            hostname_port: Some("yb-1.local:7000".to_string()),
+            timestamp: Some(timestamp),
+            tablet_server_hostname_port: Some(hostname_port.to_string()),
+            uptime_seconds,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unit_masters_restarted() {
+        let mut restarts = Restarts::new();
+        restarts.masters(&masters(1657972299220554), &masters(1657972299220554));
+        assert!(restarts.servers.is_empty());
+        restarts.masters(&masters(1657972299220554), &masters(1657972325360336));
+        assert!(restarts.is_restarted("yb-1.local:7000"));
+    }
+
+    #[test]
+    fn unit_tablet_servers_restarted() {
+        let begin = Local::now();
+        let end = begin + Duration::seconds(600);
+        let mut restarts = Restarts::new();
+        restarts.masters(&masters(1), &masters(1));
+        restarts.tablet_servers(
+            &[tablet_server("yb-1.local:9000", 1000, begin), tablet_server("yb-2.local:9000", 1000, begin)],
+            &[tablet_server("yb-1.local:9000", 1600, end), tablet_server("yb-2.local:9000", 300, end)],
+        );
+        assert!(!restarts.is_restarted("yb-1.local:9000"));
+        assert!(restarts.is_restarted("yb-2.local:9000"));
+        // the YSQL server is part of the tablet server.
+        assert!(restarts.is_restarted("yb-2.local:13000"));
+        assert_eq!(restarts.list(), "yb-2.local:9000");
+    }
+
+    #[test]
+    fn unit_tablet_servers_restart_does_not_restart_master() {
+        let begin = Local::now();
+        let end = begin + Duration::seconds(600);
+        let mut restarts = Restarts::new();
+        restarts.masters(&masters(1), &masters(1));
+        restarts.tablet_servers(&[tablet_server("yb-1.local:9000", 1000, begin)], &[tablet_server("yb-1.local:9000", 10, end)]);
+        assert!(restarts.is_restarted("yb-1.local:12000"));
+        assert!(!restarts.is_restarted("yb-1.local:7000"));
+    }
+
+    #[test]
+    fn unit_adhoc_diff() {
+        let begin = Local::now();
+        let end = begin + Duration::seconds(60);
+        let first_snapshot = RestartsSnapshot { masters: masters(1), tablet_servers: vec![tablet_server("yb-1.local:9000", 1000, begin)] };
+        let second_snapshot = RestartsSnapshot { masters: masters(2), tablet_servers: vec![tablet_server("yb-1.local:9000", 10, end)] };
+        let restarts = Restarts::adhoc_diff(&first_snapshot, &second_snapshot);
+        assert_eq!(restarts.list(), "yb-1.local:7000, yb-1.local:9000");
+        assert!(Restarts::adhoc_diff(&first_snapshot, &first_snapshot).servers.is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+//! Module for detecting the servers that restarted between two snapshots.
+//!
+//! A restart resets the counters of a server, which makes the difference between the snapshots meaningless.
+//! The restarts are detected using:
+//! - `/api/v1/masters`: the instance sequence number and start time of the masters.
+//! - `/api/v1/tablet-servers`: the uptime of the tablet servers.
+//!
+//! The YSQL and YCQL servers run as part of the tablet server, so a tablet server restart restarts these too.
+//!
+//! The diffs of stored snapshots read these from the snapshots, the ad-hoc diffs and `--serve` read these with the first and second
+//! in-memory snapshot into a [RestartsSnapshot].
+//!
+mod structs;
+mod functions;
+
+pub use structs::*;
@@ -0,0 +1,21 @@
+//! The structs
+//!
+use std::collections::BTreeSet;
+use crate::masters::GetMasterRegistrationRequestPB;
+use crate::tablet_servers::TabletServer;
+/// The servers that restarted between two snapshots.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Restarts {
+    /// The `hostname_port` of the restarted masters and tablet servers.
+    pub servers: BTreeSet<String>,
+    /// The hostname of the restarted tablet servers, for the YSQL and YCQL servers on the same host.
+    pub tablet_server_hosts: BTreeSet<String>,
+    /// The `hostname_port` of all masters, which are not restarted by a tablet server restart on the same host.
+    pub masters: BTreeSet<String>,
+}
+/// The masters and tablet servers data of an ad-hoc (in memory) snapshot, for detecting the restarts.
+#[derive(Debug, Default)]
+pub struct RestartsSnapshot {
+    pub masters: Vec<GetMasterRegistrationRequestPB>,
+    pub tablet_servers: Vec<TabletServer>,
+}
@@ -5,11 +5,12 @@ use std::{fmt, sync::mpsc::channel, collections::BTreeMap, time::Instant};
 use log::*;
 use regex::Regex;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::rpcs::{Rpcs, AllRpcs, CQLCallDetailsPB, RpcConnectionDetailsPB, RpcCallState, RequestHeader, StateType, CqlConnectionDetails, RemoteMethodPB};
 use crate::rpcs::Rpcs::{Ysql, Rpc};
 use crate::Opts;
+use crate::output::{self, HostRow, OutputFormat};
 
 impl fmt::Display for RpcCallState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -52,27 +53,31 @@ impl AllRpcs {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut rpcs = AllRpcs::read_http(host, port);
-                        match rpcs
-                        {
-                            Ysql { ref mut hostname_port, ref mut timestamp, .. } => {
-                                *hostname_port = Some(format!("{}:{}", host, port));
-                                *timestamp = Some(detail_snapshot_time);
-                            }
-                            Rpc { ref mut hostname_port, ref mut timestamp, .. } => {
-                                *hostname_port = Some(format!("{}:{}", host, port));
-                                *timestamp = Some(detail_snapshot_time);
-                            }
-                            _ => {}
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver, Role::Ysql, Role::Ycql]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut rpcs = match AllRpcs::read_http(host, port) {
+                        Ok(rpcs) => rpcs,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    match rpcs
+                    {
+                        Ysql { ref mut hostname_port, ref mut timestamp, .. } => {
+                            *hostname_port = Some(format!("{}:{}", host, port));
+                            *timestamp = Some(detail_snapshot_time);
                         }
-                        tx.send(rpcs).expect("error sending data via tx");
-                    });
-                }
+                        Rpc { ref mut hostname_port, ref mut timestamp, .. } => {
+                            *hostname_port = Some(format!("{}:{}", host, port));
+                            *timestamp = Some(detail_snapshot_time);
+                        }
+                        _ => {}
+                    }
+                    tx.send(rpcs).expect("error sending data via tx");
+                });
             }
         });
 
@@ -90,10 +95,10 @@ impl AllRpcs {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Rpcs
+    ) -> Result<Rpcs>
     {
-        let data_from_http = utility::http_get(host, port, "rpcz");
-        AllRpcs::parse_rpcs(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "rpcz").into_body()?;
+        Ok(AllRpcs::parse_rpcs(data_from_http, host, port))
     }
     fn parse_rpcs(
         http_data: String,
@@ -109,8 +114,13 @@ impl AllRpcs {
         &self,
         details_enable: &bool,
         hostname_filter: &Regex,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            return self.print_rows(hostname_filter, output);
+        }
         let mut endpoint_count: BTreeMap<String, (usize, usize)> = BTreeMap::new();
         for rpcs in &self.rpcs {
             match rpcs
@@ -243,6 +253,34 @@ impl AllRpcs {
 
         Ok(())
     }
+    /// Print all the connections as rows, separated into ysql, inbound and outbound connections.
+    fn print_rows(
+        &self,
+        hostname_filter: &Regex,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let mut ysql = Vec::new();
+        let mut inbound = Vec::new();
+        let mut outbound = Vec::new();
+        for rpcs in &self.rpcs
+        {
+            match rpcs
+            {
+                Ysql { connections, hostname_port: Some(hostname_port), .. } if hostname_filter.is_match(hostname_port) =>
+                    ysql.extend(connections.iter().map(|row| HostRow { hostname_port, row })),
+                Rpc { inbound_connections, outbound_connections, hostname_port: Some(hostname_port), .. } if hostname_filter.is_match(hostname_port) =>
+                {
+                    inbound.extend(inbound_connections.iter().flatten().map(|row| HostRow { hostname_port, row }));
+                    outbound.extend(outbound_connections.iter().flatten().map(|row| HostRow { hostname_port, row }));
+                }
+                _ => {}
+            }
+        }
+        output::print_rows(output, "rpcs_ysql", &ysql)?;
+        output::print_rows(output, "rpcs_inbound", &inbound)?;
+        output::print_rows(output, "rpcs_outbound", &outbound)
+    }
     fn print_details(
         &self,
         hostname: String,
@@ -255,15 +293,13 @@ impl AllRpcs {
         {
             match rpcs
             {
-                Ysql { connections, hostname_port, .. } =>
-                    {
-                        if hostname_port
+                Ysql { connections, hostname_port, .. } if hostname_port
                             .clone()
                             .expect("hostname:port should be set")
                             .split(':')
                             .next()
                             .unwrap() == hostname
-                            && hostname_filter.is_match(&hostname_port.clone().expect("hostname:port should be set"))
+                            && hostname_filter.is_match(&hostname_port.clone().expect("hostname:port should be set")) =>
                         {
                             for connection in connections
                             {
@@ -336,16 +372,13 @@ impl AllRpcs {
                                 }
                             }
                         }
-                    }
-                Rpc { inbound_connections, outbound_connections, hostname_port, .. } =>
-                    {
-                        if hostname_port
+                Rpc { inbound_connections, outbound_connections, hostname_port, .. } if hostname_port
                             .clone()
                             .expect("hostname:port should be set")
                             .split(':')
                             .next()
                             .unwrap() == hostname
-                            && hostname_filter.is_match(&hostname_port.clone().expect("hostname:port should be set"))
+                            && hostname_filter.is_match(&hostname_port.clone().expect("hostname:port should be set")) =>
                         {
                             for inbound in inbound_connections
                                 .as_ref()
@@ -581,7 +614,6 @@ impl AllRpcs {
                                 }
                             }
                         }
-                    }
                 _ => {}
             }
         }
@@ -611,12 +643,12 @@ pub async fn print_rpcs(
         {
             let mut allrpcs = AllRpcs::new();
             allrpcs.rpcs = snapshot::read_snapshot_json(snapshot_number, "rpcs")?;
-            allrpcs.print(&options.details_enable, &hostname_filter)?;
+            allrpcs.print(&options.details_enable, &hostname_filter, &options.output)?;
         }
         None =>
         {
             let allrpcs = AllRpcs::read_rpcs(&hosts, &ports, parallel).await;
-            allrpcs.print(&options.details_enable, &hostname_filter)?;
+            allrpcs.print(&options.details_enable, &hostname_filter, &options.output)?;
         }
     }
     Ok(())
@@ -943,7 +975,7 @@ mod tests {
                                    .calls_in_flight.as_ref().unwrap()[0]
                                    .cql_details.as_ref().unwrap()
                                    .call_details[19]
-                                   .params.as_ref().unwrap(), "[2008-06, Louisiana, \0\0\0\0, n/a, \0\0\u{19}\u{00cd}, \0\0\u{14}L, n/a, \0\0\0\u{00da}, \0\0\0\0, \0\0\0\u{5}, \0\0\0\u{3}, n/a, \0\0\u{2}\u{0192}, \0\0\u{3}@, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, \0\04\u{00be}]");
+                                   .params.as_ref().unwrap(), "[2008-06, Louisiana, \0\0\0\0, n/a, \0\0\u{19}\u{00cd}, \0\0\u{14}L, n/a, \0\0\0\u{00da}, \0\0\0\0, \0\0\0\u{5}, \0\0\0\u{3}, n/a, \0\0\u{2}\u{0192}, \0\0\u{3}@, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, n/a, \0\0\x34\u{00be}]");
                 }
     }
 
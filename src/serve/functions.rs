@@ -0,0 +1,393 @@
+//! The impls and functions
+//!
+use std::{collections::BTreeMap, fmt::Write as _, sync::Arc, time::Instant};
+use chrono::Local;
+use log::*;
+use anyhow::{bail, Context, Result};
+use substring::Substring;
+use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::{TcpListener, TcpStream}, sync::RwLock};
+use crate::metrics::{MetricCountSumDiffRow, MetricCountSumRowsDiffRow, MetricEntityDiff, MetricValueDiffRow};
+use crate::restarts::{Restarts, RestartsSnapshot};
+use crate::statements::{StatementsDiff, StatementsDiffRow};
+use crate::serve::{MetricFamily, Sample, StatementTotal};
+use crate::utility;
+use crate::Opts;
+
+impl MetricFamily {
+    fn new(
+        name: &'static str,
+        help: &'static str,
+    ) -> Self
+    {
+        MetricFamily { name, help, ..Default::default() }
+    }
+    fn add(
+        &mut self,
+        labels: Vec<(&'static str, String)>,
+        value: f64,
+    )
+    {
+        self.samples.push(Sample { labels, value });
+    }
+    /// Add the family in the text exposition format to the output.
+    /// A family without samples is not added.
+    fn render(
+        &self,
+        output: &mut String,
+    )
+    {
+        if self.samples.is_empty() {
+            return;
+        }
+        writeln!(output, "# HELP {} {}", self.name, self.help).unwrap();
+        writeln!(output, "# TYPE {} gauge", self.name).unwrap();
+        for sample in &self.samples {
+            let labels = sample.labels.iter()
+                .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(output, "{}{{{}}} {}", self.name, labels, sample.value).unwrap();
+        }
+    }
+}
+
+/// Escape a label value according to the text exposition format: backslash, double-quote and line feed.
+fn escape_label_value(
+    value: &str,
+) -> String
+{
+    value.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn metric_labels(
+    hostname_port: &str,
+    metric_type: &str,
+    metric_id: &str,
+    table_name: &str,
+    metric_name: &str,
+) -> Vec<(&'static str, String)>
+{
+    vec![
+        ("hostname_port", hostname_port.to_string()),
+        ("metric_type", metric_type.to_string()),
+        ("metric_id", metric_id.to_string()),
+        ("table_name", table_name.to_string()),
+        ("metric_name", metric_name.to_string()),
+    ]
+}
+
+/// Create the text exposition for the rows of the metrics and statements diff reports.
+///
+/// Value counters are exposed as rate per second, value gauges as the value of the second snapshot.
+/// Countsum and countsumrows statistics are exposed as rate per second and average,
+/// statements as calls per second, average time and average rows, and an info series with the query text.
+/// The rows are expected to include the zero differences, so a series does not disappear when it is idle.
+fn exposition(
+    value_rows: &[MetricValueDiffRow],
+    countsum_rows: &[MetricCountSumDiffRow],
+    countsumrows_rows: &[MetricCountSumRowsDiffRow],
+    statements_rows: &[StatementsDiffRow],
+    sql_length: usize,
+) -> String
+{
+    let mut value_rate = MetricFamily::new("yb_stats_value_per_second", "Value counter statistic difference per second");
+    let mut value_gauge = MetricFamily::new("yb_stats_value", "Value gauge statistic value");
+    for row in value_rows {
+        let mut labels = metric_labels(&row.hostname_port, &row.metric_type, &row.metric_id, &row.table_name, &row.metric_name);
+        labels.push(("unit", row.unit.clone()));
+        // the choice of != gauge is deliberate here: if it's unknown, it's a counter, like the diff report.
+        if row.stat_type != "gauge" {
+            value_rate.add(labels, row.rate);
+        } else {
+            value_gauge.add(labels, row.value as f64);
+        }
+    }
+
+    let mut countsum_rate = MetricFamily::new("yb_stats_countsum_per_second", "Countsum statistic count per second");
+    let mut countsum_average = MetricFamily::new("yb_stats_countsum_average", "Countsum statistic average sum per count");
+    for row in countsum_rows {
+        let mut labels = metric_labels(&row.hostname_port, &row.metric_type, &row.metric_id, &row.table_name, &row.metric_name);
+        labels.push(("unit", row.unit.clone()));
+        countsum_rate.add(labels.clone(), row.rate);
+        countsum_average.add(labels, row.average);
+    }
+
+    let mut countsumrows_rate = MetricFamily::new("yb_stats_countsumrows_per_second", "YSQL countsumrows statistic count per second");
+    let mut countsumrows_average_time = MetricFamily::new("yb_stats_countsumrows_average_time_ms", "YSQL countsumrows statistic average time per count in milliseconds");
+    let mut countsumrows_average_rows = MetricFamily::new("yb_stats_countsumrows_average_rows", "YSQL countsumrows statistic average rows per count");
+    for row in countsumrows_rows {
+        let labels = metric_labels(&row.hostname_port, &row.metric_type, &row.metric_id, &row.table_name, &row.metric_name);
+        countsumrows_rate.add(labels.clone(), row.rate);
+        countsumrows_average_time.add(labels.clone(), row.average_time_ms);
+        countsumrows_average_rows.add(labels, row.average_rows as f64);
+    }
+
+    // statements are labelled with the query_id, or the fingerprint if the version does not expose the query_id,
+    // so the number of series is bounded, and the (truncated) query text is a label of the info series only.
+    // different texts can have the same query_id or fingerprint, so these are added together.
+    let mut statements: BTreeMap<(String, &'static str, String), StatementTotal> = BTreeMap::new();
+    for row in statements_rows {
+        let (id_name, id) = match row.query_id {
+            Some(query_id) => ("query_id", query_id.to_string()),
+            None => ("fingerprint", row.fingerprint.clone()),
+        };
+        let total = statements.entry((row.hostname_port.clone(), id_name, id))
+            .or_insert_with(|| StatementTotal { query: row.query.substring(0, sql_length).to_string(), ..Default::default() });
+        total.calls += row.calls;
+        total.calls_per_second += row.calls_per_second;
+        total.total_time_ms += row.total_time_ms;
+        total.rows += row.rows;
+    }
+    let mut statement_info = MetricFamily::new("yb_stats_statement_info", "YSQL statement query text, truncated to sql-length");
+    let mut statement_calls = MetricFamily::new("yb_stats_statement_calls_per_second", "YSQL statement calls per second");
+    let mut statement_average_time = MetricFamily::new("yb_stats_statement_average_time_ms", "YSQL statement average time per call in milliseconds");
+    let mut statement_average_rows = MetricFamily::new("yb_stats_statement_average_rows", "YSQL statement average rows per call");
+    for ((hostname_port, id_name, id), total) in statements {
+        let labels = vec![
+            ("hostname_port", hostname_port),
+            (id_name, id),
+        ];
+        let mut info_labels = labels.clone();
+        info_labels.push(("query", total.query));
+        statement_info.add(info_labels, 1_f64);
+        statement_calls.add(labels.clone(), total.calls_per_second);
+        if total.calls != 0 {
+            statement_average_time.add(labels.clone(), total.total_time_ms / total.calls as f64);
+            statement_average_rows.add(labels, total.rows as f64 / total.calls as f64);
+        } else {
+            statement_average_time.add(labels.clone(), 0_f64);
+            statement_average_rows.add(labels, 0_f64);
+        }
+    }
+
+    let mut output = String::new();
+    for family in [
+        value_rate,
+        value_gauge,
+        countsum_rate,
+        countsum_average,
+        countsumrows_rate,
+        countsumrows_average_time,
+        countsumrows_average_rows,
+        statement_info,
+        statement_calls,
+        statement_average_time,
+        statement_average_rows,
+    ] {
+        family.render(&mut output);
+    }
+    output
+}
+
+/// Take a first and second adhoc snapshot of the metrics and statements, and create the text exposition of the difference.
+async fn collect(
+    hosts: &Vec<&str>,
+    ports: &Vec<&str>,
+    parallel: usize,
+    interval: std::time::Duration,
+    options: &Opts,
+) -> String
+{
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+    let stat_name_filter = utility::set_regex(&options.stat_name_match);
+    let table_name_filter = utility::set_regex(&options.table_name_match);
+
+    let mut metrics = MetricEntityDiff::new();
+    let mut statements = StatementsDiff::new();
+
+    let first_snapshot_time = Local::now();
+    metrics.adhoc_read_first_snapshot(hosts, ports, parallel, options.details_enable).await;
+    statements.adhoc_read_first_snapshot(hosts, ports, parallel).await;
+    let first_restarts = RestartsSnapshot::read(hosts, ports, parallel).await;
+
+    tokio::time::sleep(interval).await;
+
+    let timer = Instant::now();
+    metrics.adhoc_read_second_snapshot(hosts, ports, parallel, &first_snapshot_time, options.details_enable).await;
+    statements.adhoc_read_second_snapshot(hosts, ports, parallel, &first_snapshot_time).await;
+    let second_restarts = RestartsSnapshot::read(hosts, ports, parallel).await;
+    info!("serve second snapshot: {:?}", timer.elapsed());
+    metrics.restarts = Restarts::adhoc_diff(&first_restarts, &second_restarts);
+    statements.restarts = Restarts::adhoc_diff(&first_restarts, &second_restarts);
+
+    exposition(
+        &metrics.value_rows(&hostname_filter, &stat_name_filter, &table_name_filter, &options.gauges_enable, &true),
+        &metrics.countsum_rows(&hostname_filter, &stat_name_filter, &table_name_filter, &true),
+        &metrics.countsumrows_rows(&hostname_filter, &stat_name_filter, &true),
+        &statements.rows(&hostname_filter, &true),
+        options.sql_length,
+    )
+}
+
+/// Answer a single http request: `/metrics` returns the latest exposition, anything else is not found.
+async fn handle_connection(
+    mut stream: TcpStream,
+    exposition: Arc<RwLock<String>>,
+) -> Result<()>
+{
+    let mut buffer = [0; 4096];
+    let length = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..length]);
+    let path = request.lines()
+        .next()
+        .and_then(|request_line| request_line.split_whitespace().nth(1))
+        .unwrap_or_default();
+    let response = if path == "/metrics" || path.starts_with("/metrics?") {
+        let body = exposition.read().await;
+        format!("HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body)
+    } else {
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+    };
+    stream.write_all(response.as_bytes()).await?;
+    Ok(())
+}
+
+/// Serve the derived statistics as a Prometheus endpoint on `--serve <address:port>`.
+///
+/// The http requests are answered by a background task.
+/// Meanwhile, this function continuously takes a first snapshot, waits `--interval`, takes a second snapshot,
+/// and replaces the exposition with the difference.
+/// The endpoint returns the exposition of the latest completed interval, which is empty until the first interval has completed.
+pub async fn serve(
+    hosts: Vec<&'static str>,
+    ports: Vec<&'static str>,
+    parallel: usize,
+    options: &Opts,
+) -> Result<()>
+{
+    let address = options.serve.as_ref().expect("--serve should be set");
+    let interval = utility::parse_duration(&options.interval)?;
+    if interval.is_zero() {
+        bail!("Invalid interval: {}, the interval must be at least 1 second", options.interval);
+    }
+    let listener = TcpListener::bind(address).await
+        .with_context(|| format!("Cannot listen on address: {}", address))?;
+    if !options.silent {
+        println!("serving statistics on http://{}/metrics", address);
+    }
+
+    let exposition = Arc::new(RwLock::new(String::new()));
+
+    let listener_exposition = exposition.clone();
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer)) => {
+                    debug!("serve request from: {}", peer);
+                    let exposition = listener_exposition.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) = handle_connection(stream, exposition).await {
+                            debug!("serve request error: {}", error);
+                        }
+                    });
+                },
+                Err(error) => warn!("serve accept error: {}", error),
+            }
+        }
+    });
+
+    loop {
+        let new_exposition = collect(&hosts, &ports, parallel, interval, options).await;
+        *exposition.write().await = new_exposition;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Change;
+
+    #[test]
+    fn unit_escape_label_value() {
+        let result = escape_label_value("select \"a\" from t\nwhere b = '\\'");
+        assert_eq!(result, r#"select \"a\" from t\nwhere b = '\\'"#);
+    }
+
+    #[test]
+    fn unit_exposition_counter_and_gauge() {
+        let value_rows = vec![
+            MetricValueDiffRow {
+                hostname_port: "192.168.66.80:9000".to_string(),
+                metric_type: "server".to_string(),
+                metric_id: "yb.tabletserver".to_string(),
+                metric_name: "tcp_bytes_sent".to_string(),
+                stat_type: "counter".to_string(),
+                value: 100,
+                rate: 10.5,
+                unit: "bytes".to_string(),
+                ..Default::default()
+            },
+            MetricValueDiffRow {
+                hostname_port: "192.168.66.80:9000".to_string(),
+                metric_type: "server".to_string(),
+                metric_id: "yb.tabletserver".to_string(),
+                metric_name: "threads_running".to_string(),
+                stat_type: "gauge".to_string(),
+                value: 42,
+                unit: "threads".to_string(),
+                ..Default::default()
+            },
+        ];
+        let result = exposition(&value_rows, &[], &[], &[], 80);
+        assert_eq!(result, r#"# HELP yb_stats_value_per_second Value counter statistic difference per second
+# TYPE yb_stats_value_per_second gauge
+yb_stats_value_per_second{hostname_port="192.168.66.80:9000",metric_type="server",metric_id="yb.tabletserver",table_name="",metric_name="tcp_bytes_sent",unit="bytes"} 10.5
+# HELP yb_stats_value Value gauge statistic value
+# TYPE yb_stats_value gauge
+yb_stats_value{hostname_port="192.168.66.80:9000",metric_type="server",metric_id="yb.tabletserver",table_name="",metric_name="threads_running",unit="threads"} 42
+"#);
+    }
+
+    #[test]
+    fn unit_exposition_statements_query_is_truncated() {
+        let statements_rows = vec![
+            StatementsDiffRow {
+                change: Change::Changed,
+                hostname_port: "192.168.66.80:13000".to_string(),
+                query_id: None,
+                calls: 10,
+                calls_per_second: 1.0,
+                average_time_ms: 0.25,
+                total_time_ms: 2.5,
+                average_rows: 1,
+                rows: 10,
+                fingerprint: "0123456789abcdef".to_string(),
+                query: "select * from t where id = $1".to_string(),
+                reset: false,
+            },
+        ];
+        let result = exposition(&[], &[], &[], &statements_rows, 8);
+        assert!(result.contains(r#"yb_stats_statement_info{hostname_port="192.168.66.80:13000",fingerprint="0123456789abcdef",query="select *"} 1"#));
+        assert!(result.contains(r#"yb_stats_statement_calls_per_second{hostname_port="192.168.66.80:13000",fingerprint="0123456789abcdef"} 1"#));
+        assert!(result.contains(r#"yb_stats_statement_average_time_ms{hostname_port="192.168.66.80:13000",fingerprint="0123456789abcdef"} 0.25"#));
+        assert!(!result.contains("yb_stats_value"));
+    }
+
+    #[test]
+    fn unit_exposition_statements_same_query_id_and_zero_calls() {
+        let statement = |query: &str, calls: i64, total_time_ms: f64| StatementsDiffRow {
+            change: Change::Changed,
+            hostname_port: "192.168.66.80:13000".to_string(),
+            query_id: Some(1234),
+            calls,
+            calls_per_second: calls as f64 / 10_f64,
+            average_time_ms: 0_f64,
+            total_time_ms,
+            average_rows: 0,
+            rows: calls,
+            fingerprint: String::new(),
+            query: query.to_string(),
+            reset: false,
+        };
+        // two texts with the same query_id must give a single sample, and a statement without calls a zero.
+        let result = exposition(&[], &[], &[], &[statement("select 1", 10, 5.0), statement("SELECT 2", 30, 15.0)], 80);
+        assert!(result.contains(r#"yb_stats_statement_calls_per_second{hostname_port="192.168.66.80:13000",query_id="1234"} 4"#));
+        assert!(result.contains(r#"yb_stats_statement_average_time_ms{hostname_port="192.168.66.80:13000",query_id="1234"} 0.5"#));
+        assert_eq!(result.matches("yb_stats_statement_average_rows{").count(), 1);
+        let result = exposition(&[], &[], &[], &[statement("select 1", 0, 0.0)], 80);
+        assert!(result.contains(r#"yb_stats_statement_calls_per_second{hostname_port="192.168.66.80:13000",query_id="1234"} 0"#));
+        assert!(result.contains(r#"yb_stats_statement_average_time_ms{hostname_port="192.168.66.80:13000",query_id="1234"} 0"#));
+    }
+}
@@ -0,0 +1,16 @@
+//! Module for serving the yb_stats derived statistics as a Prometheus endpoint (`--serve`).
+//!
+//! In serve mode, yb_stats continuously takes adhoc (in memory) first and second snapshots of:
+//! - metrics (value, coarse_histogram/countsum, ysql/countsumrows)
+//! - statements (ysql)
+//!
+//! The difference between the snapshots is converted into the Prometheus text exposition format,
+//! which is served on `/metrics` of the given address.
+//! This means the per second rates, countsum averages and statement deltas are exposed as gauges,
+//! using the same counter and gauge classification as the diff reports.
+//!
+mod structs;
+mod functions;
+
+pub use structs::*;
+pub use functions::*;
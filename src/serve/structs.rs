@@ -0,0 +1,28 @@
+//! The structs
+//!
+/// A metric family in the Prometheus text exposition format.
+///
+/// All the families that yb_stats exposes are gauges, because these are derived figures,
+/// such as rates and averages, over the interval between the first and second snapshot.
+#[derive(Debug, Default)]
+pub struct MetricFamily {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub samples: Vec<Sample>,
+}
+/// A single sample of a [MetricFamily], with its labels.
+#[derive(Debug, Default)]
+pub struct Sample {
+    pub labels: Vec<(&'static str, String)>,
+    pub value: f64,
+}
+/// The total of the statements rows with the same query_id or fingerprint on a server,
+/// with the (truncated) query text of the first row for the info series.
+#[derive(Debug, Default)]
+pub struct StatementTotal {
+    pub calls: i64,
+    pub calls_per_second: f64,
+    pub total_time_ms: f64,
+    pub rows: i64,
+    pub query: String,
+}
@@ -1,21 +1,26 @@
 //! Snapshot functions
 //!
 use log::*;
-use std::{env, fs, io::{stdin, stdout, Write}, path::Path, sync::Arc, time::Instant};
-use chrono::Local;
-use anyhow::{Context, Result};
+use std::{collections::BTreeMap, fs, io::{stderr, stdin, BufReader, BufWriter, ErrorKind, Read, Write}, path::{Component, Path}, str::FromStr, sync::Arc, time::Instant};
+use chrono::{DateTime, Local};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use regex::Regex;
 use tokio::sync::Mutex;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use tar::EntryType;
 use crate::{Opts, tablet_server_operations};
-use crate::{clocks, entities, gflags, isleader, loglines, masters, mems, memtrackers, metrics, node_exporter, pprof, rpcs, statements, threads, tablet_servers, utility, vars, versions, cluster_config, health_check, table_detail, tablet_detail, tasks, tablet_replication, drives};
-use crate::snapshot::Snapshot;
+use crate::{clocks, entities, gflags, isleader, loglines, masters, mems, memtrackers, metrics, node_exporter, pprof, rpcs, statements, threads, tablet_servers, utility, vars, versions, cluster_config, health_check, table_detail, tablet_detail, tasks, tablet_replication, drives, placements, restarts};
+use crate::snapshot::{Retention, Snapshot, SnapshotProblem, SnapshotRange};
+use crate::output;
+use crate::utility::{FetchErrorKind, FetchResult};
 
 impl Snapshot {
     /// This is a public function to:
     /// - create the yb_stats.snapshots directory (if it exists, it does nothing).
     /// - open the yb_stats.snapshots/snapshot.index if it exists, and read it into a vec of Snapshot.
     /// - if it exists, get the highest snapshot number, otherwise snapshot_number remains 0.
-    /// - save a new Snapshot into the vec of Snapshot.
+    /// - save a new Snapshot with the cluster name into the vec of Snapshot.
     /// - write the vec of Snapshot to yb_stats.snapshots/snapshot.index.
     /// - create the snapshot directory for the data as yb_stats.snapshots/<snapshot_number>.
     /// - return snapshot_number.
@@ -28,9 +33,7 @@ impl Snapshot {
         let mut snapshot_number: i32 = 0;
 
         // setup yb_stats.snapshots if necessary
-        let current_directory = env::current_dir()
-            .with_context(|| "Cannot evaluate current working directory" )?;
-        let yb_stats_directory = current_directory.join("yb_stats.snapshots");
+        let yb_stats_directory = utility::snapshot_directory()?;
         // If the &yb_stats_directory does not exist, create it.
         // If it does exist already, nothing happens and continue.
         fs::create_dir_all(&yb_stats_directory)
@@ -41,11 +44,13 @@ impl Snapshot {
         let snapshot_index = &yb_stats_directory.join("snapshot.index");
         if Path::new(&snapshot_index).exists() {
             snapshots = Snapshot::read_snapshot_index()?;
-            let record_with_highest_snapshot_number = snapshots.iter().max_by_key(|k| k.number).unwrap();
-            snapshot_number = record_with_highest_snapshot_number.number + 1;
+            // the index can be empty if all snapshots have been pruned.
+            if let Some(record_with_highest_snapshot_number) = snapshots.iter().max_by_key(|k| k.number) {
+                snapshot_number = record_with_highest_snapshot_number.number + 1;
+            }
         }
         // create a new snapshot vector and assign it the new_snapshot, and add it to the snapshots vector.
-        let new_snapshot: Snapshot = Snapshot { number: snapshot_number, timestamp: Local::now(), comment: snapshot_comment.clone().unwrap_or_default() };
+        let new_snapshot: Snapshot = Snapshot { number: snapshot_number, timestamp: Local::now(), comment: snapshot_comment.clone().unwrap_or_default(), cluster: utility::snapshot_cluster() };
         snapshots.push(new_snapshot);
         Snapshot::write_snapshot_index(snapshots)?;
         // Create the snapshot number directory in the &yb_stats_directory
@@ -59,9 +64,7 @@ impl Snapshot {
     ) -> Result<Vec<Snapshot>>
     {
         let mut snapshots: Vec<Snapshot> = Vec::new();
-        let current_directory = env::current_dir()
-            .with_context(|| "Cannot evaluate current working directory" )?;
-        let yb_stats_directory = current_directory.join("yb_stats.snapshots");
+        let yb_stats_directory = utility::snapshot_directory()?;
         let snapshot_index = &yb_stats_directory.join("snapshot.index");
 
         let file = fs::File::open(snapshot_index)
@@ -80,10 +83,7 @@ impl Snapshot {
         snapshots: Vec<Snapshot>
     ) -> Result<()>
     {
-        let current_directory = env::current_dir()
-            .with_context(|| "Cannot evaluate current working directory" )?;
-
-        let yb_stats_directory = current_directory.join("yb_stats.snapshots");
+        let yb_stats_directory = utility::snapshot_directory()?;
         let snapshot_index = &yb_stats_directory.join("snapshot.index");
 
         // Open the snapshot.index file, but truncate it and write the new snapshots vector to it.
@@ -102,17 +102,346 @@ impl Snapshot {
             .with_context(|| "Error flushing buffer")?;
         Ok(())
     }
+    /// This is a public function that reads the snapshots file into a vector, and returns the snapshots of which the cluster name matches the filter.
+    pub fn list(
+        cluster_filter: &Regex,
+    ) -> Result<Vec<Snapshot>>
+    {
+        Ok(Snapshot::read_snapshot_index()?
+            .into_iter()
+            .filter(|row| cluster_filter.is_match(&row.cluster))
+            .collect())
+    }
     /// This is a public function that reads the snapshots file into a vector and print the contents of it.
     /// The main use is to display the current snapshots to the user.
+    ///
+    /// Only the snapshots of which the cluster name matches the filter are printed.
+    /// The cluster name is only printed if any of the snapshots has one.
     pub fn print(
+        cluster_filter: &Regex,
+        output: &output::OutputFormat,
     ) -> Result<()>
+    {
+        let snapshots = Snapshot::list(cluster_filter)?;
+        let print_cluster = snapshots.iter().any(|row| !row.cluster.is_empty());
+        for row in &snapshots {
+            if print_cluster {
+                output::print_message(output, &format!("{:>3} {:30} {:20} {:50}", row.number, row.timestamp, row.cluster, row.comment));
+            } else {
+                output::print_message(output, &format!("{:>3} {:30} {:50}", row.number, row.timestamp, row.comment));
+            }
+        }
+        Ok(())
+    }
+    /// This is a public function that removes the snapshots that are expired according to the retention.
+    ///
+    /// Only the snapshots of the current cluster (set with `--cluster`) are considered, so the retention is per cluster.
+    /// The snapshot directories are removed first, after which the snapshot.index is rewritten with the remaining snapshots.
+    /// It returns the snapshot numbers that are removed.
+    pub fn prune(
+        retention: &Retention,
+    ) -> Result<Vec<i32>>
+    {
+        let snapshots = Snapshot::read_snapshot_index()?;
+        let cluster = utility::snapshot_cluster();
+        let cluster_snapshots: Vec<Snapshot> = snapshots.iter().filter(|row| row.cluster == cluster).cloned().collect();
+        let expired = Snapshot::expired(&cluster_snapshots, retention, Local::now());
+        Snapshot::remove(snapshots, &expired)?;
+        Ok(expired)
+    }
+    /// This is a public function that removes the snapshots in the range.
+    ///
+    /// The snapshot directories are removed first, after which the snapshot.index is rewritten with the remaining snapshots.
+    /// It returns the snapshot numbers that are removed.
+    pub fn delete(
+        range: &SnapshotRange,
+    ) -> Result<Vec<i32>>
+    {
+        let snapshots = Snapshot::read_snapshot_index()?;
+        let deleted: Vec<i32> = snapshots.iter()
+            .filter(|row| range.contains(row.number))
+            .map(|row| row.number)
+            .collect();
+        if deleted.is_empty() {
+            bail!("No snapshots found in the range");
+        }
+        Snapshot::remove(snapshots, &deleted)?;
+        Ok(deleted)
+    }
+    /// This is a private function that removes the directories of the snapshot numbers,
+    /// and writes the snapshot.index with the snapshots that are not removed.
+    fn remove(
+        snapshots: Vec<Snapshot>,
+        snapshot_numbers: &[i32],
+    ) -> Result<()>
+    {
+        if snapshot_numbers.is_empty() {
+            return Ok(());
+        }
+
+        let yb_stats_directory = utility::snapshot_directory()?;
+        for snapshot_number in snapshot_numbers {
+            let snapshot_directory = yb_stats_directory.join(snapshot_number.to_string());
+            match fs::remove_dir_all(&snapshot_directory) {
+                Ok(_) => info!("removed snapshot directory: {}", snapshot_directory.display()),
+                // a snapshot directory that is already gone is fine.
+                Err(error) if error.kind() == ErrorKind::NotFound => {},
+                Err(error) => return Err(error).with_context(|| format!("Cannot remove directory: {}", snapshot_directory.display())),
+            }
+        }
+        Snapshot::write_snapshot_index(snapshots.into_iter().filter(|row| !snapshot_numbers.contains(&row.number)).collect())?;
+        Ok(())
+    }
+    /// This is a public function that verifies the snapshots in the snapshot.index, and returns the problems found:
+    /// - the snapshot directory is missing.
+    /// - a snapshot JSON file is missing, or cannot be read and deserialized with [read_snapshot_json].
+    /// - a snapshot directory exists that is not in the snapshot.index.
+    pub fn verify(
+    ) -> Result<Vec<SnapshotProblem>>
     {
         let snapshots = Snapshot::read_snapshot_index()?;
+        let yb_stats_directory = utility::snapshot_directory()?;
+        let mut problems = Vec::new();
+
         for row in &snapshots {
-            println!("{:>3} {:30} {:50}", row.number, row.timestamp, row.comment);
+            let snapshot_number = row.number.to_string();
+            let snapshot_directory = yb_stats_directory.join(&snapshot_number);
+            if !snapshot_directory.is_dir() {
+                problems.push(SnapshotProblem::new(row.number, "", "snapshot directory is missing"));
+                continue;
+            }
+            for (filename, optional, read) in SNAPSHOT_FILES {
+                if !snapshot_directory.join(format!("{}.json", filename)).exists() {
+                    if !optional {
+                        problems.push(SnapshotProblem::new(row.number, filename, "file is missing"));
+                    }
+                    continue;
+                }
+                if let Err(error) = read(&snapshot_number, filename) {
+                    problems.push(SnapshotProblem::new(row.number, filename, &format!("{:#}", error)));
+                }
+            }
+        }
+
+        let mut orphans: Vec<i32> = fs::read_dir(&yb_stats_directory)
+            .with_context(|| format!("Error reading directory: {}", yb_stats_directory.display()))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().to_str().and_then(|name| name.parse::<i32>().ok()))
+            .filter(|number| !snapshots.iter().any(|row| row.number == *number))
+            .collect();
+        orphans.sort_unstable();
+        problems.extend(orphans.into_iter().map(|number| SnapshotProblem::new(number, "", "snapshot directory is not in snapshot.index")));
+
+        Ok(problems)
+    }
+    /// This is a private function that writes the snapshots and their directories to the bundle as a gzip compressed tar archive.
+    ///
+    /// The snapshot.index with the rows of the snapshots is written first, so it can be read first upon import.
+    fn write_bundle<W: Write>(
+        writer: W,
+        snapshots: &[Snapshot],
+        yb_stats_directory: &Path,
+    ) -> Result<()>
+    {
+        let mut index = csv::Writer::from_writer(Vec::new());
+        for row in snapshots {
+            index.serialize(row)
+                .with_context(|| format!("Unable to serialize: {} {} {}", row.number, row.timestamp, row.comment))?;
+        }
+        let index = index.into_inner()
+            .with_context(|| "Error flushing buffer")?;
+
+        let mut archive = tar::Builder::new(GzEncoder::new(writer, Compression::default()));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(index.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(Local::now().timestamp() as u64);
+        archive.append_data(&mut header, "snapshot.index", index.as_slice())
+            .with_context(|| "Error adding snapshot.index to bundle")?;
+        for row in snapshots {
+            let snapshot_directory = yb_stats_directory.join(row.number.to_string());
+            archive.append_dir_all(row.number.to_string(), &snapshot_directory)
+                .with_context(|| format!("Error adding directory to bundle: {}", snapshot_directory.display()))?;
         }
+        archive.into_inner()
+            .with_context(|| "Error finishing bundle")?
+            .finish()
+            .with_context(|| "Error compressing bundle")?;
         Ok(())
     }
+    /// This is a private function that returns the new snapshot numbers for imported snapshots,
+    /// which are numbered after the highest existing snapshot number in the order of the imported snapshot numbers.
+    fn renumber(
+        existing: &[Snapshot],
+        imported: &[Snapshot],
+    ) -> BTreeMap<i32, i32>
+    {
+        let first_number = existing.iter().map(|row| row.number + 1).max().unwrap_or_default();
+        let mut numbers: Vec<i32> = imported.iter().map(|row| row.number).collect();
+        numbers.sort_unstable();
+        numbers.into_iter()
+            .zip(first_number..)
+            .collect()
+    }
+    /// This is a private function that reads a bundle created by [Snapshot::write_bundle], and extracts the snapshots
+    /// into the yb_stats directory with new numbers following the existing snapshots.
+    ///
+    /// It returns the imported snapshots with their new numbers, and the new numbers per imported snapshot number.
+    /// The snapshot index is not changed.
+    fn read_bundle<R: Read>(
+        reader: R,
+        existing: &[Snapshot],
+        yb_stats_directory: &Path,
+    ) -> Result<(Vec<Snapshot>, BTreeMap<i32, i32>)>
+    {
+        let mut archive = tar::Archive::new(GzDecoder::new(reader));
+        let mut imported: Vec<Snapshot> = Vec::new();
+        let mut numbers: Option<BTreeMap<i32, i32>> = None;
+
+        for entry in archive.entries().with_context(|| "Error reading bundle")? {
+            let mut entry = entry.with_context(|| "Error reading bundle entry")?;
+            let path = entry.path().with_context(|| "Invalid path in bundle")?.into_owned();
+
+            if path == Path::new("snapshot.index") {
+                let mut reader = csv::Reader::from_reader(&mut entry);
+                for row in reader.deserialize() {
+                    let data: Snapshot = row
+                        .with_context(|| "Error deserialize row")?;
+                    imported.push(data);
+                }
+                numbers = Some(Snapshot::renumber(existing, &imported));
+                continue;
+            }
+
+            let numbers = numbers.as_ref()
+                .with_context(|| format!("Invalid bundle: {} found before snapshot.index", path.display()))?;
+            // the path must be a snapshot number followed by the (relative) path inside the snapshot directory.
+            let mut components = path.components();
+            let new_number = components.next()
+                .and_then(|component| component.as_os_str().to_str())
+                .and_then(|number| number.parse::<i32>().ok())
+                .and_then(|number| numbers.get(&number))
+                .with_context(|| format!("Invalid bundle: {} is not part of a snapshot in snapshot.index", path.display()))?;
+            let relative_path = components.as_path();
+            if !relative_path.components().all(|component| matches!(component, Component::Normal(_))) {
+                bail!("Invalid bundle: {} is not a path inside a snapshot directory", path.display());
+            }
+
+            // only files and directories are extracted: a link could point outside the snapshot directory,
+            // and a later entry would be written through it.
+            let entry_type = entry.header().entry_type();
+            if entry_type != EntryType::Regular && entry_type != EntryType::Directory {
+                bail!("Invalid bundle: {} is not a file or directory", path.display());
+            }
+
+            let destination = yb_stats_directory.join(new_number.to_string()).join(relative_path);
+            if entry_type.is_dir() {
+                fs::create_dir_all(&destination)
+                    .with_context(|| format!("Cannot create directory: {}", destination.display()))?;
+            } else {
+                if let Some(parent) = destination.parent() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("Cannot create directory: {}", parent.display()))?;
+                }
+                entry.unpack(&destination)
+                    .with_context(|| format!("Error extracting: {}", destination.display()))?;
+            }
+        }
+
+        let numbers = numbers.with_context(|| "Invalid bundle: snapshot.index not found")?;
+        for row in imported.iter_mut() {
+            row.number = numbers[&row.number];
+        }
+        Ok((imported, numbers))
+    }
+    /// This is a public function that exports the snapshots in the range together with their snapshot.index rows
+    /// into a single compressed bundle file.
+    ///
+    /// It returns the snapshot numbers that are exported.
+    pub fn export(
+        range: &SnapshotRange,
+        file: &Path,
+    ) -> Result<Vec<i32>>
+    {
+        let snapshots: Vec<Snapshot> = Snapshot::read_snapshot_index()?
+            .into_iter()
+            .filter(|row| range.contains(row.number))
+            .collect();
+        if snapshots.is_empty() {
+            bail!("No snapshots found in the range");
+        }
+
+        let yb_stats_directory = utility::snapshot_directory()?;
+        let bundle = fs::File::create(file)
+            .with_context(|| format!("Cannot create file: {}", file.display()))?;
+        Snapshot::write_bundle(BufWriter::new(bundle), &snapshots, &yb_stats_directory)?;
+        Ok(snapshots.iter().map(|row| row.number).collect())
+    }
+    /// This is a public function that imports the snapshots from a bundle created with [Snapshot::export].
+    ///
+    /// The imported snapshots are renumbered after the existing snapshots, so they do not collide with these.
+    /// The snapshot directories are extracted into a temporary directory, which is removed if the import fails.
+    /// After the snapshot.index is written, the snapshot directories are moved into the yb_stats directory.
+    /// It returns the new snapshot numbers per imported snapshot number.
+    pub fn import(
+        file: &Path,
+    ) -> Result<BTreeMap<i32, i32>>
+    {
+        let yb_stats_directory = utility::snapshot_directory()?;
+        fs::create_dir_all(&yb_stats_directory)
+            .with_context(|| format!("Cannot create directory: {}", yb_stats_directory.display()))?;
+        let mut snapshots = if yb_stats_directory.join("snapshot.index").exists() {
+            Snapshot::read_snapshot_index()?
+        } else {
+            Vec::new()
+        };
+
+        let bundle = fs::File::open(file)
+            .with_context(|| format!("Error opening file: {}", file.display()))?;
+        let import_directory = yb_stats_directory.join(format!(".import.{}", std::process::id()));
+        let (imported, numbers) = match Snapshot::read_bundle(BufReader::new(bundle), &snapshots, &import_directory) {
+            Ok(result) => result,
+            Err(error) => {
+                if import_directory.exists() {
+                    fs::remove_dir_all(&import_directory)
+                        .with_context(|| format!("Cannot remove directory: {}", import_directory.display()))?;
+                }
+                return Err(error);
+            },
+        };
+        snapshots.extend(imported);
+        Snapshot::write_snapshot_index(snapshots)?;
+        for new_number in numbers.values() {
+            let (source, destination) = (import_directory.join(new_number.to_string()), yb_stats_directory.join(new_number.to_string()));
+            if source.exists() {
+                fs::rename(&source, &destination)
+                    .with_context(|| format!("Cannot move {} to {}", source.display(), destination.display()))?;
+            }
+        }
+        fs::remove_dir_all(&import_directory)
+            .with_context(|| format!("Cannot remove directory: {}", import_directory.display()))?;
+        Ok(numbers)
+    }
+    /// This is a private function that returns the snapshot numbers that fall outside the retention.
+    fn expired(
+        snapshots: &[Snapshot],
+        retention: &Retention,
+        now: DateTime<Local>,
+    ) -> Vec<i32>
+    {
+        match retention {
+            Retention::Age(age) => snapshots.iter()
+                .filter(|row| row.timestamp < now - *age)
+                .map(|row| row.number)
+                .collect(),
+            Retention::Count(count) => {
+                let mut numbers: Vec<i32> = snapshots.iter().map(|row| row.number).collect();
+                numbers.sort_unstable_by(|a, b| b.cmp(a));
+                numbers.into_iter().skip(*count).collect()
+            },
+        }
+    }
     /// This is a public function that validates begin and end provided values,
     /// and if these are not specified are requested interactively,
     /// after which the begin and end snapshot numbers and the struct with the begin snapshot are returned as record.
@@ -120,7 +449,7 @@ impl Snapshot {
     /// If the begin or end value is provided (using the switches `-b`/`--begin` and `-e`/`--end`),
     /// it will take that value and not ask for it.
     ///
-    /// Both begin and end snapshots are validated for their existence in the [Snapshot] vector.
+    /// Both begin and end snapshots are validated for their existence in the [Snapshot] vector, and for being of the same cluster.
     /// Besides the begin and end snapshot values, the struct with the begin [Snapshot] is returned.
     /// The begin [Snapshot] struct is needed for the timestamp.
     pub fn read_begin_end_snapshot_from_user(
@@ -132,9 +461,9 @@ impl Snapshot {
         let begin_snapshot= match option_begin {
             Some(nr) => nr,
             None => {
-                print!("Enter begin snapshot: ");
+                eprint!("Enter begin snapshot: ");
                 let mut snap= String::new();
-                stdout().flush()?;
+                stderr().flush()?;
                 stdin().read_line(&mut snap).expect("Failed to read input.");
                 let snap: i32 = snap.trim().parse().expect("Invalid input");
                 snap
@@ -150,21 +479,167 @@ impl Snapshot {
         let end_snapshot = match option_end {
             Some(nr) => nr,
             None => {
-                print!("Enter end snapshot: ");
+                eprint!("Enter end snapshot: ");
                 let mut snap = String::new();
-                stdout().flush()?;
+                stderr().flush()?;
                 stdin().read_line(&mut snap).expect("Failed to read input.");
                 let snap: i32 = snap.trim().parse().expect("Invalid input");
                 snap
             }
         };
         // end_snapshot has to exists as row.number, otherwise error out.
-        snapshots.iter()
+        let end_snapshot_row = snapshots.iter()
             .find(|&row| row.number == end_snapshot)
             .with_context(|| format!("Unable to find end snapshot number: {}", end_snapshot))?;
+        // a difference between snapshots of different clusters is meaningless.
+        if begin_snapshot_row.cluster != end_snapshot_row.cluster {
+            bail!("Begin snapshot {} (cluster: {}) and end snapshot {} (cluster: {}) are of different clusters", begin_snapshot, begin_snapshot_row.cluster, end_snapshot, end_snapshot_row.cluster);
+        }
         // all information is found and collected, return it to the caller.
         Ok((begin_snapshot.to_string(), end_snapshot.to_string(), begin_snapshot_row.clone()))
     }
+    /// This is a public function that returns the snapshots from the begin up to and including the end snapshot, in snapshot number order,
+    /// to walk through the intervals between them.
+    ///
+    /// Only the snapshots of the cluster of the begin snapshot are returned, and there must be at least two of them.
+    pub fn series(
+        begin_snapshot: i32,
+        end_snapshot: i32,
+    ) -> Result<Vec<Snapshot>>
+    {
+        let snapshots = Snapshot::read_snapshot_index()?;
+        let cluster = snapshots.iter()
+            .find(|row| row.number == begin_snapshot)
+            .map(|row| row.cluster.clone())
+            .with_context(|| format!("Unable to find begin snapshot number: {}", begin_snapshot))?;
+        let mut series: Vec<Snapshot> = snapshots.into_iter()
+            .filter(|row| (begin_snapshot..=end_snapshot).contains(&row.number) && row.cluster == cluster)
+            .collect();
+        series.sort_by_key(|row| row.number);
+        if series.len() < 2 {
+            bail!("At least two snapshots are needed between begin snapshot {} and end snapshot {}", begin_snapshot, end_snapshot);
+        }
+        Ok(series)
+    }
+}
+impl FromStr for Retention {
+    type Err = anyhow::Error;
+
+    fn from_str(retain: &str) -> Result<Self>
+    {
+        if let Ok(count) = retain.trim().parse::<usize>() {
+            if count == 0 {
+                bail!("Invalid retention: {}, at least one snapshot must be kept", retain);
+            }
+            return Ok(Retention::Count(count));
+        }
+        Retention::from_age(retain)
+    }
+}
+impl Retention {
+    /// Create the retention by age from a duration such as `7d`, which is used by `--retain` and `--older-than`.
+    /// A zero age is rejected, because it would remove every snapshot.
+    pub fn from_age(
+        age: &str,
+    ) -> Result<Self>
+    {
+        let duration = utility::parse_duration(age)?;
+        if duration.is_zero() {
+            bail!("Invalid retention: {}, the age must be at least 1 second", age);
+        }
+        Ok(Retention::Age(chrono::Duration::from_std(duration)?))
+    }
+}
+impl SnapshotProblem {
+    fn new(
+        number: i32,
+        file: &str,
+        problem: &str,
+    ) -> Self
+    {
+        SnapshotProblem { number, file: file.to_string(), problem: problem.to_string() }
+    }
+}
+/// Read a snapshot JSON file into a vector of the type, and discard the result.
+/// This is used by [Snapshot::verify] to validate the files.
+fn read_snapshot_json_as<T: for<'de> Deserialize<'de>>(
+    snapshot_number: &String,
+    filename: &str,
+) -> Result<()>
+{
+    read_snapshot_json::<T>(snapshot_number, filename).map(|_| ())
+}
+/// The function type to read a snapshot JSON file with its type.
+type ReadSnapshotJson = fn(&String, &str) -> Result<()>;
+/// The JSON files in a snapshot, if these are optional, and the function to read these with their type.
+/// The threads file is not saved with `--disable-threads`, and the collection report is not present in snapshots made with older versions.
+const SNAPSHOT_FILES: [(&str, bool, ReadSnapshotJson); 24] = [
+    ("metrics", false, read_snapshot_json_as::<metrics::MetricEntity>),
+    ("statements", false, read_snapshot_json_as::<statements::Statements>),
+    ("nodeexporter", false, read_snapshot_json_as::<node_exporter::NodeExporter>),
+    ("isleader", false, read_snapshot_json_as::<isleader::IsLeader>),
+    ("entities", false, read_snapshot_json_as::<entities::Entities>),
+    ("masters", false, read_snapshot_json_as::<masters::GetMasterRegistrationRequestPB>),
+    ("tablet_servers", false, read_snapshot_json_as::<tablet_servers::TabletServer>),
+    ("vars", false, read_snapshot_json_as::<vars::Vars>),
+    ("versions", false, read_snapshot_json_as::<versions::Version>),
+    ("gflags", false, read_snapshot_json_as::<gflags::GFlag>),
+    ("threads", true, read_snapshot_json_as::<threads::Threads>),
+    ("memtrackers", false, read_snapshot_json_as::<memtrackers::MemTrackers>),
+    ("loglines", false, read_snapshot_json_as::<loglines::LogLine>),
+    ("rpcs", false, read_snapshot_json_as::<rpcs::Rpcs>),
+    ("clocks", false, read_snapshot_json_as::<clocks::Clocks>),
+    ("cluster-config", false, read_snapshot_json_as::<cluster_config::SysClusterConfigEntryPB>),
+    ("health-check", false, read_snapshot_json_as::<health_check::Health_Check>),
+    ("tables", false, read_snapshot_json_as::<table_detail::Table>),
+    ("tablets", false, read_snapshot_json_as::<tablet_detail::Tablet>),
+    ("tasks", false, read_snapshot_json_as::<tasks::Tasks>),
+    ("tablet_replication", false, read_snapshot_json_as::<tablet_replication::TabletReplication>),
+    ("tablet_server_operations", false, read_snapshot_json_as::<tablet_server_operations::Operations>),
+    ("drives", false, read_snapshot_json_as::<drives::Drives>),
+    ("collection_report", true, read_snapshot_json_as::<FetchResult>),
+];
+impl FromStr for SnapshotRange {
+    type Err = anyhow::Error;
+
+    fn from_str(range: &str) -> Result<Self>
+    {
+        if range.trim() == "all" {
+            return Ok(SnapshotRange::All);
+        }
+        let parse = |number: &str| number.trim().parse::<i32>()
+            .with_context(|| format!("Invalid snapshot number: {} in range: {}, use for example 1,3,5-9 or all", number, range));
+        let mut numbers = Vec::new();
+        for part in range.split(',') {
+            match part.split_once('-') {
+                Some((begin, end)) => {
+                    let (begin, end) = (parse(begin)?, parse(end)?);
+                    if begin > end {
+                        bail!("Invalid snapshot range: {}, the begin is higher than the end", part);
+                    }
+                    numbers.push(begin..=end);
+                },
+                None => {
+                    let number = parse(part)?;
+                    numbers.push(number..=number);
+                },
+            }
+        }
+        Ok(SnapshotRange::Numbers(numbers))
+    }
+}
+impl SnapshotRange {
+    /// This is a public function that returns if the snapshot number is part of the range.
+    pub fn contains(
+        &self,
+        snapshot_number: i32,
+    ) -> bool
+    {
+        match self {
+            SnapshotRange::All => true,
+            SnapshotRange::Numbers(numbers) => numbers.iter().any(|range| range.contains(&snapshot_number)),
+        }
+    }
 }
 /// This is the general yb_stat wide save_snapshot_json function.
 pub fn save_snapshot_json<T: Serialize>(
@@ -173,8 +648,7 @@ pub fn save_snapshot_json<T: Serialize>(
     vector: Vec<T>,
 ) -> Result<()>
 {
-    let current_directory = env::current_dir()?;
-    let current_snapshot_directory = current_directory.join("yb_stats.snapshots").join(snapshot_number.to_string());
+    let current_snapshot_directory = utility::snapshot_directory()?.join(snapshot_number.to_string());
 
     let filepath = &current_snapshot_directory.join(format!("{}.{}",filename, "json"));
     fs::write(filepath, serde_json::to_string(&vector)
@@ -188,8 +662,7 @@ pub fn read_snapshot_json<T: for<'de> Deserialize<'de>>(
     filename: &str,
 ) -> Result<Vec<T>>
 {
-    let current_directory = env::current_dir()?;
-    let current_snapshot_directory = current_directory.join("yb_stats.snapshots").join(snapshot_number);
+    let current_snapshot_directory = utility::snapshot_directory()?.join(snapshot_number);
     let filepath = &current_snapshot_directory.join(format!("{}.{}", filename, "json"));
 
     let vector = {
@@ -212,6 +685,8 @@ pub async fn perform_snapshot(
     let snapshot_number = Snapshot::insert_new_snapshot_number(&options.snapshot_comment)?;
     info!("using snapshot number: {}", snapshot_number);
 
+    utility::start_fetch_results();
+
     let arc_hosts = Arc::new(hosts);
     let arc_ports = Arc::new(ports);
     let arc_extra_data = Arc::new(options.extra_data);
@@ -397,18 +872,118 @@ pub async fn perform_snapshot(
     });
     handles.push(handle);
 
+    // a failed task is returned as error instead of a panic, so the daemon can continue.
     for handle in handles {
-        handle.await.unwrap();
+        handle.await.with_context(|| format!("snapshot number {} is incomplete", snapshot_number))?;
     }
 
+    let fetch_results = utility::take_fetch_results();
+    let (totals, failures) = collection_summary(&fetch_results);
+    save_snapshot_json(snapshot_number, "collection_report", fetch_results)?;
+
     if !options.silent {
         println!("snapshot number {}", snapshot_number);
+        println!("{}", totals);
+    }
+    // failures are printed even when silent: these mean data is missing from the snapshot.
+    for failure in failures {
+        println!("{}", failure);
     }
 
     info!("end snapshot: {:?}", timer.elapsed());
     Ok(())
 }
 
+/// Print the failures of the fetch results recorded since [utility::start_fetch_results] to stderr.
+///
+/// The modes that read the hosts directly, such as the ad-hoc and print modes, do not create a collection report,
+/// so these failures show that output is missing because a host could not be read, and not because it has no data.
+pub fn print_fetch_failures()
+{
+    let (_, failures) = collection_summary(&utility::take_fetch_results());
+    for failure in failures {
+        eprintln!("{}", failure);
+    }
+}
+
+/// Create the summary of the collection report of a snapshot:
+/// - a line with the totals of the requests.
+/// - a line per hostname:port that had requests that got no response, with the error kind and the first error.
+///
+/// A non-success http status is not considered a failure: most endpoints only exist on some server types,
+/// and therefore return 404 on the others.
+fn collection_summary(
+    fetch_results: &[FetchResult],
+) -> (String, Vec<String>)
+{
+    let success = fetch_results.iter().filter(|result| result.error_kind.is_none()).count();
+    let non_success_status = fetch_results.iter().filter(|result| result.error_kind == Some(FetchErrorKind::Status)).count();
+    let failed: Vec<&FetchResult> = fetch_results.iter()
+        .filter(|result| result.error_kind.is_some() && result.error_kind != Some(FetchErrorKind::Status))
+        .collect();
+    let totals = format!("collection: {} requests, {} success, {} non-success status, {} failed", fetch_results.len(), success, non_success_status, failed.len());
+
+    // group the failures per hostname:port, and keep the first failure for the error.
+    let mut failures_per_hostname_port: BTreeMap<&str, (usize, &FetchResult)> = BTreeMap::new();
+    for result in failed {
+        failures_per_hostname_port.entry(&result.hostname_port)
+            .and_modify(|(count, _)| *count += 1)
+            .or_insert((1, result));
+    }
+    let failures = failures_per_hostname_port.into_iter()
+        .map(|(hostname_port, (count, result))| format!("failed: {} {} requests, {:?}: {}", hostname_port, count, result.error_kind.unwrap(), result.error.as_deref().unwrap_or_default()))
+        .collect();
+    (totals, failures)
+}
+
+/// The function to perform snapshots continuously, until interrupted with ctrl-c.
+///
+/// Every `--interval` a snapshot is performed using [perform_snapshot].
+/// If `--retain` is set, the snapshots outside of the retention are removed after each snapshot.
+/// If a snapshot takes longer than the interval, the next snapshot is started directly after it.
+/// If a snapshot fails, the error is logged and the daemon continues with the next interval.
+pub async fn perform_snapshot_daemon(
+    hosts: Vec<&'static str>,
+    ports: Vec<&'static str>,
+    parallel: usize,
+    options: &Opts,
+) -> Result<()> {
+    info!("begin daemon");
+    let interval = utility::parse_duration(&options.interval)?;
+    if interval.is_zero() {
+        bail!("Invalid interval: {}, the interval must be at least 1 second", options.interval);
+    }
+    let retention = options.retain.as_deref().map(Retention::from_str).transpose()?;
+
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // a single ctrl-c future, so an interrupt during a snapshot is handled after the snapshot has finished.
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {},
+            _ = &mut ctrl_c => break,
+        }
+        // a failed snapshot or prune, such as by a full disk, must not stop the daemon: it's logged, and retried the next interval.
+        if let Err(error) = perform_snapshot(hosts.clone(), ports.clone(), parallel, options).await {
+            error!("snapshot failed: {:#}", error);
+            continue;
+        }
+        if let Some(retention) = &retention {
+            match Snapshot::prune(retention) {
+                Ok(pruned) if !options.silent && !pruned.is_empty() => println!("pruned snapshot numbers {:?}", pruned),
+                Ok(_) => {},
+                Err(error) => error!("pruning snapshots failed: {:#}", error),
+            }
+        }
+    }
+
+    info!("end daemon");
+    Ok(())
+}
+
 /// This function shows the difference report for the snapshot data that allows to show a difference:
 /// - metrics (value, coarse_histogram/countsum, ysql/countsumrows)
 /// - statements (ysql)
@@ -417,6 +992,7 @@ pub async fn perform_snapshot(
 /// - masters (read via master leader)
 /// - tablet servers (read via master leader)
 /// - vars
+/// - metric states (boolean metrics)
 /// - versions
 /// - health check (read via master leader)
 pub async fn snapshot_diff(
@@ -424,8 +1000,11 @@ pub async fn snapshot_diff(
 ) -> Result<()>
 {
     info!("snapshot diff");
+    if options.snapshot_list && !options.output.is_table() {
+        return output::print_rows(&options.output, "snapshots", &Snapshot::list(&utility::set_regex(&options.cluster_match))?);
+    }
     if options.begin.is_none() || options.end.is_none() {
-        Snapshot::print()?;
+        Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
@@ -435,32 +1014,35 @@ pub async fn snapshot_diff(
 
     let (begin_snapshot, end_snapshot, begin_snapshot_row) = Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
-    let metrics_diff = metrics::MetricEntityDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp, &options.details_enable)?;
-    metrics_diff.print(&hostname_filter, &stat_name_filter, &table_name_filter, &options.details_enable, &options.gauges_enable).await;
+    let aggregation = metrics::AggregationLevel::from_options(&options.aggregation, &options.details_enable, &options.group_by)?;
+    let metrics_diff = metrics::MetricEntityDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp, &aggregation.needs_details())?;
+    metrics_diff.print(&hostname_filter, &stat_name_filter, &table_name_filter, &aggregation, &options.group_by, &options.gauges_enable, &options.tail_latency_sort, &options.output).await?;
 
     let statements_diff = statements::StatementsDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp)?;
-    statements_diff.print(&hostname_filter, options.sql_length).await;
+    statements_diff.print(&hostname_filter, options.sql_length, &options.output).await?;
 
     let nodeexporter_diff = node_exporter::NodeExporterDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp)?;
-    nodeexporter_diff.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable);
+    nodeexporter_diff.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable, &options.output)?;
 
     let entities_diff = entities::EntitiesDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    entities_diff.print();
+    entities_diff.print(&options.output)?;
 
     let masters_diff = masters::MastersDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    masters_diff.print();
+    masters_diff.print(&options.output)?;
 
     let tabletservers_diff = tablet_servers::TabletServersDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    tabletservers_diff.print();
+    tabletservers_diff.print(&options.output)?;
 
     let vars_diff = vars::VarsDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    vars_diff.print();
+    vars_diff.print(&options.output)?;
+
+    metrics_diff.states.print(&hostname_filter, &options.output)?;
 
     let versions_diff = versions::VersionsDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    versions_diff.print(&hostname_filter);
+    versions_diff.print(&hostname_filter, &options.output)?;
 
     let healthcheck_diff = health_check::HealthCheckDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    healthcheck_diff.print();
+    healthcheck_diff.print(&options.output)?;
 
     Ok(())
 }
@@ -471,6 +1053,7 @@ pub async fn snapshot_diff(
 /// - masters (read via master leader)
 /// - tablet servers (read via master leader)
 /// - vars
+/// - metric states (boolean metrics)
 /// - versions
 /// - health check (read via master leader)
 ///
@@ -481,8 +1064,11 @@ pub async fn snapshot_nonmetrics_diff(
 ) -> Result<()>
 {
     info!("snapshot diff");
+    if options.snapshot_list && !options.output.is_table() {
+        return output::print_rows(&options.output, "snapshots", &Snapshot::list(&utility::set_regex(&options.cluster_match))?);
+    }
     if options.begin.is_none() || options.end.is_none() {
-        Snapshot::print()?;
+        Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
@@ -491,25 +1077,106 @@ pub async fn snapshot_nonmetrics_diff(
     let (begin_snapshot, end_snapshot, _) = Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let entities_diff = entities::EntitiesDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    entities_diff.print();
+    entities_diff.print(&options.output)?;
 
     let masters_diff = masters::MastersDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    masters_diff.print();
+    masters_diff.print(&options.output)?;
 
     let tabletservers_diff = tablet_servers::TabletServersDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    tabletservers_diff.print();
+    tabletservers_diff.print(&options.output)?;
 
     let vars_diff = vars::VarsDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    vars_diff.print();
+    vars_diff.print(&options.output)?;
+
+    let metric_states_diff = metrics::MetricStatesDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
+    metric_states_diff.print(&hostname_filter, &options.output)?;
 
     let versions_diff = versions::VersionsDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    versions_diff.print(&hostname_filter);
+    versions_diff.print(&hostname_filter, &options.output)?;
 
     let healthcheck_diff = health_check::HealthCheckDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    healthcheck_diff.print();
+    healthcheck_diff.print(&options.output)?;
 
     Ok(())
 }
+/// The function to export the snapshots in a range into a single compressed bundle file (`--export-snapshots <range> <file>`).
+pub async fn export_snapshots(
+    options: &Opts,
+) -> Result<()>
+{
+    info!("export snapshots");
+    let (range, file) = match options.export_snapshots.as_deref() {
+        Some([range, file]) => (range, file),
+        _ => bail!("--export-snapshots requires a range and a file"),
+    };
+    let exported = Snapshot::export(&range.parse()?, Path::new(file))?;
+    println!("exported {} snapshots ({}) to: {}", exported.len(), exported.iter().map(|number| number.to_string()).collect::<Vec<_>>().join(","), file);
+    Ok(())
+}
+/// The function to import the snapshots from a bundle file created with `--export-snapshots` (`--import-snapshots <file>`).
+pub async fn import_snapshots(
+    options: &Opts,
+) -> Result<()>
+{
+    info!("import snapshots");
+    let file = options.import_snapshots.as_ref().expect("--import-snapshots should be set");
+    let numbers = Snapshot::import(Path::new(file))?;
+    for (imported_number, new_number) in &numbers {
+        println!("imported snapshot {} as snapshot {}", imported_number, new_number);
+    }
+    Ok(())
+}
+/// The function to delete the snapshots in a range (`--snapshot-delete <range>`).
+pub async fn delete_snapshots(
+    options: &Opts,
+) -> Result<()>
+{
+    info!("delete snapshots");
+    let range: SnapshotRange = options.snapshot_delete.as_ref().expect("--snapshot-delete should be set").parse()?;
+    let deleted = Snapshot::delete(&range)?;
+    println!("deleted {} snapshots ({})", deleted.len(), deleted.iter().map(|number| number.to_string()).collect::<Vec<_>>().join(","));
+    Ok(())
+}
+/// The function to prune the snapshots of the current cluster (`--snapshot-prune`),
+/// keeping a number of snapshots (`--keep <n>`) or removing the snapshots older than a duration (`--older-than <duration>`).
+pub async fn prune_snapshots(
+    options: &Opts,
+) -> Result<()>
+{
+    info!("prune snapshots");
+    let retention = match (options.keep, &options.older_than) {
+        (Some(0), None) => bail!("Invalid --keep: 0, at least one snapshot must be kept"),
+        (Some(keep), None) => Retention::Count(keep),
+        (None, Some(older_than)) => Retention::from_age(older_than)?,
+        _ => bail!("--snapshot-prune requires either --keep <n> or --older-than <duration>"),
+    };
+    let pruned = Snapshot::prune(&retention)?;
+    println!("pruned {} snapshots ({})", pruned.len(), pruned.iter().map(|number| number.to_string()).collect::<Vec<_>>().join(","));
+    Ok(())
+}
+/// The function to verify the snapshots (`--snapshot-verify`), and print the problems found.
+/// If problems are found, an error is returned, so the exit code shows the result.
+pub async fn verify_snapshots(
+    options: &Opts,
+) -> Result<()>
+{
+    info!("verify snapshots");
+    let problems = Snapshot::verify()?;
+    if options.output.is_table() {
+        for problem in &problems {
+            println!("{:>3} {:30} {}", problem.number, problem.file, problem.problem);
+        }
+    } else {
+        output::print_rows(&options.output, "snapshot_verify", &problems)?;
+    }
+    if !problems.is_empty() {
+        bail!("Found {} problems in the snapshots", problems.len());
+    }
+    if options.output.is_table() {
+        println!("no problems found");
+    }
+    Ok(())
+}
 
 /// Take "adhoc" (in memory) snapshots of metrics only:
 /// - metrics (value, coarse_histogram/countsum, ysql/countsumrows)
@@ -532,6 +1199,7 @@ pub async fn adhoc_metrics_diff(
 
     let first_snapshot_time = Local::now();
 
+    let aggregation = metrics::AggregationLevel::from_options(&options.aggregation, &options.details_enable, &options.group_by)?;
     let metrics = Arc::new(Mutex::new(metrics::MetricEntityDiff::new()));
 
     let hosts = Arc::new(hosts);
@@ -542,19 +1210,21 @@ pub async fn adhoc_metrics_diff(
     let clone_metrics = metrics.clone();
     let clone_hosts = hosts.clone();
     let clone_ports = ports.clone();
-    let details_enable = options.details_enable;
+    let details_enable = aggregation.needs_details();
 
     let handle = tokio::spawn(async move {
         clone_metrics.lock().await.adhoc_read_first_snapshot(&clone_hosts, &clone_ports, parallel, details_enable).await;
     });
     handles.push(handle);
 
+    let first_restarts = restarts::RestartsSnapshot::read(&hosts, &ports, parallel).await;
+
     for handle in handles {
         handle.await.unwrap();
     }
     info!("ad-hoc metrics diff first snapshot end: {:?}", timer.elapsed());
 
-    println!("Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
+    output::print_message(&options.output, "Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
     let mut input = String::new();
     stdin().read_line(&mut input).expect("failed");
 
@@ -568,21 +1238,27 @@ pub async fn adhoc_metrics_diff(
     let clone_metrics = metrics.clone();
     let clone_hosts = hosts.clone();
     let clone_ports = ports.clone();
-    let details_enable = options.details_enable;
+    let details_enable = aggregation.needs_details();
 
     let handle = tokio::spawn(async move {
         clone_metrics.lock().await.adhoc_read_second_snapshot(&clone_hosts, &clone_ports, parallel, &first_snapshot_time, details_enable).await;
     });
     handles.push(handle);
 
+    let second_restarts = restarts::RestartsSnapshot::read(&hosts, &ports, parallel).await;
+
     for handle in handles {
         handle.await.unwrap();
     }
 
     info!("ad-hoc metrics diff second snapshot end: {:?}", timer.elapsed());
+    metrics.lock().await.restarts = restarts::Restarts::adhoc_diff(&first_restarts, &second_restarts);
 
-    println!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64);
-    metrics.lock().await.print(&hostname_filter, &stat_name_filter, &table_name_filter, &options.details_enable, &options.gauges_enable).await;
+    output::print_message(&options.output, &format!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64));
+    if options.group_by.is_some() {
+        metrics.lock().await.placements = placements::Placements::read(&hosts, &ports, parallel).await;
+    }
+    metrics.lock().await.print(&hostname_filter, &stat_name_filter, &table_name_filter, &aggregation, &options.group_by, &options.gauges_enable, &options.tail_latency_sort, &options.output).await?;
 
     Ok(())
 }
@@ -626,7 +1302,7 @@ pub async fn adhoc_node_exporter_diff(
     }
     info!("ad-hoc node_exporter diff first snapshot end: {:?}", timer.elapsed());
 
-    println!("Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
+    output::print_message(&options.output, "Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
     let mut input = String::new();
     stdin().read_line(&mut input).expect("failed");
 
@@ -651,8 +1327,8 @@ pub async fn adhoc_node_exporter_diff(
 
     info!("ad-hoc node_exporter diff second snapshot end: {:?}", timer.elapsed());
 
-    println!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64);
-    node_exporter.lock().await.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable);
+    output::print_message(&options.output, &format!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64));
+    node_exporter.lock().await.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable, &options.output)?;
 
     Ok(())
 }
@@ -677,6 +1353,7 @@ pub async fn adhoc_nonmetrics_diff(
     let tablet_servers = Arc::new(Mutex::new(tablet_servers::TabletServersDiff::new()));
     let versions = Arc::new(Mutex::new(versions::VersionsDiff::new()));
     let vars = Arc::new(Mutex::new(vars::VarsDiff::new()));
+    let metric_states = Arc::new(Mutex::new(metrics::MetricStatesDiff::new()));
     let health_check = Arc::new(Mutex::new(health_check::HealthCheckDiff::new()));
 
     let hosts = Arc::new(hosts);
@@ -717,6 +1394,14 @@ pub async fn adhoc_nonmetrics_diff(
     });
     handles.push(handle);
 
+    let clone_metric_states = metric_states.clone();
+    let clone_hosts = hosts.clone();
+    let clone_ports = ports.clone();
+    let handle = tokio::spawn(async move {
+        clone_metric_states.lock().await.adhoc_read_first_snapshot(&clone_hosts, &clone_ports, parallel).await;
+    });
+    handles.push(handle);
+
     let clone_versions = versions.clone();
     let clone_hosts = hosts.clone();
     let clone_ports = ports.clone();
@@ -739,7 +1424,7 @@ pub async fn adhoc_nonmetrics_diff(
     }
     info!("ad-hoc metrics diff first snapshot end: {:?}", timer.elapsed());
 
-    println!("Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
+    output::print_message(&options.output, "Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
     let mut input = String::new();
     stdin().read_line(&mut input).expect("failed");
 
@@ -780,6 +1465,14 @@ pub async fn adhoc_nonmetrics_diff(
     });
     handles.push(handle);
 
+    let clone_metric_states = metric_states.clone();
+    let clone_hosts = hosts.clone();
+    let clone_ports = ports.clone();
+    let handle = tokio::spawn(async move {
+        clone_metric_states.lock().await.adhoc_read_second_snapshot(&clone_hosts, &clone_ports, parallel).await;
+    });
+    handles.push(handle);
+
     let clone_versions = versions.clone();
     let clone_hosts = hosts.clone();
     let clone_ports = ports.clone();
@@ -801,13 +1494,14 @@ pub async fn adhoc_nonmetrics_diff(
     }
     info!("ad-hoc metrics diff second snapshot end: {:?}", timer.elapsed());
 
-    println!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64);
-    entities.lock().await.print();
-    masters.lock().await.print();
-    tablet_servers.lock().await.print();
-    vars.lock().await.print();
-    versions.lock().await.print(&hostname_filter);
-    health_check.lock().await.print();
+    output::print_message(&options.output, &format!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64));
+    entities.lock().await.print(&options.output)?;
+    masters.lock().await.print(&options.output)?;
+    tablet_servers.lock().await.print(&options.output)?;
+    vars.lock().await.print(&options.output)?;
+    metric_states.lock().await.print(&hostname_filter, &options.output)?;
+    versions.lock().await.print(&hostname_filter, &options.output)?;
+    health_check.lock().await.print(&options.output)?;
 
     Ok(())
 }
@@ -819,6 +1513,7 @@ pub async fn adhoc_nonmetrics_diff(
 /// - masters (read via master leader)
 /// - tablet servers (read via master leader)
 /// - vars
+/// - metric states (boolean metrics)
 /// - versions
 pub async fn adhoc_diff(
     hosts: Vec<&'static str>,
@@ -836,6 +1531,7 @@ pub async fn adhoc_diff(
 
     let first_snapshot_time = Local::now();
 
+    let aggregation = metrics::AggregationLevel::from_options(&options.aggregation, &options.details_enable, &options.group_by)?;
     let metrics = Arc::new(Mutex::new(metrics::MetricEntityDiff::new()));
     let statements = Arc::new(Mutex::new(statements::StatementsDiff::new()));
     let node_exporter = Arc::new(Mutex::new(node_exporter::NodeExporterDiff::new()));
@@ -848,7 +1544,7 @@ pub async fn adhoc_diff(
 
     let hosts = Arc::new(hosts);
     let ports = Arc::new(ports);
-    let details_enable = options.details_enable;
+    let details_enable = aggregation.needs_details();
 
     let mut handles = vec![];
 
@@ -924,12 +1620,14 @@ pub async fn adhoc_diff(
     });
     handles.push(handle);
 
+    let first_restarts = restarts::RestartsSnapshot::read(&hosts, &ports, parallel).await;
+
     for handle in handles {
         handle.await.unwrap();
     }
     info!("ad-hoc metrics diff first snapshot end: {:?}", timer.elapsed());
 
-    println!("Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
+    output::print_message(&options.output, "Begin ad-hoc in-memory snapshot created, press enter to create end snapshot for difference calculation.");
     let mut input = String::new();
     stdin().read_line(&mut input).expect("failed");
 
@@ -942,7 +1640,7 @@ pub async fn adhoc_diff(
     let clone_metrics = metrics.clone();
     let clone_hosts = hosts.clone();
     let clone_ports = ports.clone();
-    let details_enable = options.details_enable;
+    let details_enable = aggregation.needs_details();
 
     let handle = tokio::spawn(async move {
         clone_metrics.lock().await.adhoc_read_second_snapshot(&clone_hosts, &clone_ports, parallel, &first_snapshot_time, details_enable).await;
@@ -1013,21 +1711,172 @@ pub async fn adhoc_diff(
     });
     handles.push(handle);
 
+    let second_restarts = restarts::RestartsSnapshot::read(&hosts, &ports, parallel).await;
+
     for handle in handles {
         handle.await.unwrap();
     }
     info!("ad-hoc metrics diff second snapshot end: {:?}", timer.elapsed());
+    metrics.lock().await.restarts = restarts::Restarts::adhoc_diff(&first_restarts, &second_restarts);
+    statements.lock().await.restarts = restarts::Restarts::adhoc_diff(&first_restarts, &second_restarts);
 
-    println!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64);
-    metrics.lock().await.print(&hostname_filter, &stat_name_filter, &table_name_filter, &options.details_enable, &options.gauges_enable).await;
-    statements.lock().await.print(&hostname_filter, options.sql_length).await;
-    node_exporter.lock().await.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable);
-    entities.lock().await.print();
-    masters.lock().await.print();
-    tablet_servers.lock().await.print();
-    vars.lock().await.print();
-    versions.lock().await.print(&hostname_filter);
-    health_check.lock().await.print();
+    output::print_message(&options.output, &format!("Time between snapshots: {:8.3} seconds", (second_snapshot_time - first_snapshot_time).num_milliseconds() as f64 / 1000_f64));
+    if options.group_by.is_some() {
+        metrics.lock().await.placements = placements::Placements::read(&hosts, &ports, parallel).await;
+    }
+    metrics.lock().await.print(&hostname_filter, &stat_name_filter, &table_name_filter, &aggregation, &options.group_by, &options.gauges_enable, &options.tail_latency_sort, &options.output).await?;
+    statements.lock().await.print(&hostname_filter, options.sql_length, &options.output).await?;
+    node_exporter.lock().await.print(&hostname_filter, &stat_name_filter, &options.gauges_enable, &options.details_enable, &options.output)?;
+    entities.lock().await.print(&options.output)?;
+    masters.lock().await.print(&options.output)?;
+    tablet_servers.lock().await.print(&options.output)?;
+    vars.lock().await.print(&options.output)?;
+    metrics.lock().await.states.print(&hostname_filter, &options.output)?;
+    versions.lock().await.print(&hostname_filter, &options.output)?;
+    health_check.lock().await.print(&options.output)?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn snapshots(now: DateTime<Local>) -> Vec<Snapshot> {
+        (0..5).map(|number| Snapshot { number, timestamp: now - Duration::days(4 - number as i64), comment: String::new(), cluster: String::new() }).collect()
+    }
+
+    fn fetch_result(hostname_port: &str, endpoint: &str, status: Option<u16>, error_kind: Option<FetchErrorKind>) -> FetchResult {
+        FetchResult {
+            hostname_port: hostname_port.to_string(),
+            endpoint: endpoint.to_string(),
+            status,
+            error_kind,
+            error: error_kind.map(|_| "error".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unit_collection_summary() {
+        let fetch_results = vec![
+            fetch_result("192.168.66.80:9000", "metrics", Some(200), None),
+            fetch_result("192.168.66.80:9000", "statements", Some(404), Some(FetchErrorKind::Status)),
+            fetch_result("192.168.66.81:9000", "metrics", None, Some(FetchErrorKind::Connect)),
+            fetch_result("192.168.66.81:9000", "statements", None, Some(FetchErrorKind::Connect)),
+        ];
+        let (totals, failures) = collection_summary(&fetch_results);
+        assert_eq!(totals, "collection: 4 requests, 1 success, 1 non-success status, 2 failed");
+        assert_eq!(failures, vec!["failed: 192.168.66.81:9000 2 requests, Connect: error"]);
+    }
+
+    #[test]
+    fn unit_retention_from_str() {
+        assert_eq!(Retention::from_str("100").unwrap(), Retention::Count(100));
+        assert_eq!(Retention::from_str("7d").unwrap(), Retention::Age(Duration::days(7)));
+        assert_eq!(Retention::from_str("12h").unwrap(), Retention::Age(Duration::hours(12)));
+        assert!(Retention::from_str("0").is_err());
+        assert!(Retention::from_str("0s").is_err());
+        assert!(Retention::from_str("0d").is_err());
+        assert!(Retention::from_age("0").is_err());
+        assert_eq!(Retention::from_age("12h").unwrap(), Retention::Age(Duration::hours(12)));
+        assert!(Retention::from_str("7w").is_err());
+    }
+
+    #[test]
+    fn unit_expired_by_count() {
+        let now = Local.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+        let result = Snapshot::expired(&snapshots(now), &Retention::Count(2), now);
+        assert_eq!(result, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn unit_expired_by_age() {
+        let now = Local.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+        let result = Snapshot::expired(&snapshots(now), &Retention::Age(Duration::hours(36)), now);
+        assert_eq!(result, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn unit_snapshot_range_from_str() {
+        let range: SnapshotRange = "1,3,5-9".parse().unwrap();
+        assert_eq!(range, SnapshotRange::Numbers(vec![1..=1, 3..=3, 5..=9]));
+        assert!(range.contains(7));
+        assert!(!range.contains(4));
+        assert!(SnapshotRange::from_str("all").unwrap().contains(100));
+        assert!(SnapshotRange::from_str("9-5").is_err());
+        assert!(SnapshotRange::from_str("a").is_err());
+    }
+
+    #[test]
+    fn unit_renumber_after_existing() {
+        let now = Local.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+        let imported: Vec<Snapshot> = snapshots(now).into_iter().filter(|row| row.number != 1).collect();
+        let result = Snapshot::renumber(&snapshots(now), &imported);
+        assert_eq!(result, BTreeMap::from([(0, 5), (2, 6), (3, 7), (4, 8)]));
+        let result = Snapshot::renumber(&[], &imported);
+        assert_eq!(result, BTreeMap::from([(0, 0), (2, 1), (3, 2), (4, 3)]));
+    }
+
+    #[test]
+    fn unit_bundle_write_and_read() {
+        let now = Local.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+        let directory = std::env::temp_dir().join(format!("yb_stats_unit_bundle_{}", std::process::id()));
+        let (export_directory, import_directory) = (directory.join("export"), directory.join("import"));
+        for number in 0..2 {
+            fs::create_dir_all(export_directory.join(number.to_string())).unwrap();
+            fs::write(export_directory.join(number.to_string()).join("metrics.json"), format!("[{}]", number)).unwrap();
+        }
+
+        let mut bundle = Vec::new();
+        Snapshot::write_bundle(&mut bundle, &snapshots(now)[..2], &export_directory).unwrap();
+        let (imported, numbers) = Snapshot::read_bundle(bundle.as_slice(), &snapshots(now), &import_directory).unwrap();
+
+        assert_eq!(numbers, BTreeMap::from([(0, 5), (1, 6)]));
+        assert_eq!(imported.iter().map(|row| row.number).collect::<Vec<_>>(), vec![5, 6]);
+        assert_eq!(imported[1].timestamp, snapshots(now)[1].timestamp);
+        assert_eq!(fs::read_to_string(import_directory.join("6").join("metrics.json")).unwrap(), "[1]");
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn unit_bundle_read_rejects_links() {
+        let now = Local.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+        let directory = std::env::temp_dir().join(format!("yb_stats_unit_bundle_links_{}", std::process::id()));
+
+        let mut bundle = Vec::new();
+        {
+            let mut builder = tar::Builder::new(GzEncoder::new(&mut bundle, Compression::default()));
+            let mut index = Vec::new();
+            {
+                let mut writer = csv::Writer::from_writer(&mut index);
+                writer.serialize(&snapshots(now)[0]).unwrap();
+                writer.flush().unwrap();
+            }
+            let mut header = tar::Header::new_gnu();
+            header.set_size(index.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, "snapshot.index", index.as_slice()).unwrap();
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(EntryType::Symlink);
+            header.set_size(0);
+            builder.append_link(&mut header, "0/link", "/etc").unwrap();
+            builder.into_inner().unwrap().finish().unwrap();
+        }
+
+        let result = Snapshot::read_bundle(bundle.as_slice(), &[], &directory);
+        assert!(result.unwrap_err().to_string().contains("is not a file or directory"));
+        assert!(!directory.join("0").join("link").exists());
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn unit_snapshot_index_without_cluster() {
+        let index = "number,timestamp,comment\n0,2023-03-01T12:00:00+01:00,first\n";
+        let mut reader = csv::Reader::from_reader(index.as_bytes());
+        let snapshots: Vec<Snapshot> = reader.deserialize().collect::<Result<_, _>>().unwrap();
+        assert_eq!(snapshots[0].comment, "first");
+        assert_eq!(snapshots[0].cluster, "");
+    }
+}
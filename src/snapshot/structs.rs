@@ -1,9 +1,38 @@
+use std::ops::RangeInclusive;
 use chrono::{DateTime, Local};
 /// Struct to represent the snapshots metadata in yb_stats in a vector as well as on disk as CSV using serde.
 /// The comment can be empty, unless a snapshot is made with the `--snapshot-comment` flag and a comment.
+/// The cluster is the cluster name set with `--cluster`, and is empty for snapshot indexes from before it was added.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Snapshot {
     pub number: i32,
     pub timestamp: DateTime<Local>,
     pub comment: String,
+    #[serde(default)]
+    pub cluster: String,
+}
+/// The retention of snapshots in daemon mode, set with `--retain`.
+///
+/// A duration with a unit (such as `7d`) keeps the snapshots younger than the duration,
+/// a plain number (such as `100`) keeps that number of most recent snapshots.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Retention {
+    Age(chrono::Duration),
+    Count(usize),
+}
+/// A problem with a snapshot found by `--snapshot-verify`.
+/// The file is empty if the problem is with the snapshot directory.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct SnapshotProblem {
+    pub number: i32,
+    pub file: String,
+    pub problem: String,
+}
+/// A selection of snapshot numbers, such as used by `--export-snapshots`.
+///
+/// The selection is `all`, or a comma separated list of snapshot numbers and inclusive ranges, such as `1,3,5-9`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SnapshotRange {
+    All,
+    Numbers(Vec<RangeInclusive<i32>>),
 }
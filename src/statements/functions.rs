@@ -1,16 +1,30 @@
 //! The the impls and functions.
 //!
 use chrono::{DateTime, Local};
-use std::{sync::mpsc::channel, time::Instant};
+use std::{collections::BTreeMap, sync::mpsc::channel, time::Instant};
 use regex::Regex;
 use substring::Substring;
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::statements::{Statements, AllStatements, StatementsDiff, GroupedStatements};
+use crate::restarts::Restarts;
+use crate::statements::{Statement, Statements, StatementRow, AllStatements, StatementsDiff, GroupedStatements, StatementsDiffRow, SqlSort, TopSqlRow, normalize_query, fingerprint};
+use crate::output::{self, Change, OutputFormat};
 use crate::Opts;
 
+impl Statement {
+    /// The query text to group the statement by between the snapshots.
+    /// If the query_id is not exposed, the text can hold literals, so the normalized text is used.
+    fn grouping_query(&self) -> String
+    {
+        match self.query_id {
+            Some(_) => self.query.clone(),
+            None => normalize_query(&self.query),
+        }
+    }
+}
+
 impl AllStatements {
     pub fn new() -> Self { Default::default() }
     pub async fn perform_snapshot(
@@ -41,17 +55,21 @@ impl AllStatements {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut statements = AllStatements::read_http(host, port);
-                        statements.timestamp = Some(detail_snapshot_time);
-                        statements.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(statements).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Ysql]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut statements = match AllStatements::read_http(host, port) {
+                        Ok(statements) => statements,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    statements.timestamp = Some(detail_snapshot_time);
+                    statements.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(statements).expect("error sending data via tx");
+                });
             }
         });
 
@@ -72,10 +90,10 @@ impl AllStatements {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Statements
+    ) -> Result<Statements>
     {
-        let data_from_http = utility::http_get(host, port, "statements");
-        AllStatements::parse_statements(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "statements").into_body()?;
+        Ok(AllStatements::parse_statements(data_from_http, host, port))
     }
     fn parse_statements(
         http_data: String,
@@ -88,7 +106,103 @@ impl AllStatements {
                 Statements::default()
             })
     }
-    // there is no print function
+    /// Create the rows of the statements print, filtered by hostname, the SQL text and the query_id.
+    pub fn rows(
+        &self,
+        hostname_filter: &Regex,
+        sql_filter: &Regex,
+        query_id: Option<i64>,
+    ) -> Vec<StatementRow>
+    {
+        let mut rows = Vec::new();
+        for statements in &self.statements
+        {
+            let hostname_port = statements.hostname_port.clone().unwrap_or_default();
+            if !hostname_filter.is_match(&hostname_port) { continue };
+            for statement in statements.statements.iter()
+                .filter(|statement| sql_filter.is_match(&statement.query))
+                .filter(|statement| query_id.is_none() || statement.query_id == query_id)
+            {
+                rows.push(StatementRow {
+                    hostname_port: hostname_port.clone(),
+                    timestamp: statements.timestamp,
+                    query_id: statement.query_id,
+                    calls: statement.calls,
+                    total_time: statement.total_time,
+                    min_time: statement.min_time,
+                    max_time: statement.max_time,
+                    mean_time: statement.mean_time,
+                    stddev_time: statement.stddev_time,
+                    rows: statement.rows,
+                    query: statement.query.clone(),
+                });
+            }
+        }
+        rows
+    }
+    /// Print the statements.
+    /// If a query_id is given, the full query text is printed below the statistics, otherwise the query text is cut at `sql_length`.
+    pub fn print(
+        &self,
+        hostname_filter: &Regex,
+        sql_filter: &Regex,
+        query_id: Option<i64>,
+        sql_length: usize,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let rows = self.rows(hostname_filter, sql_filter, query_id);
+        if !output.is_table()
+        {
+            return output::print_rows(output, "statements", &rows);
+        }
+        let mut previous_hostname_port = String::from("");
+        for row in &rows
+        {
+            if row.hostname_port != previous_hostname_port
+            {
+                println!("--------------------------------------------------------------------------------------------------------------------------------------");
+                println!("Host: {}, Snapshot time: {}", row.hostname_port, row.timestamp.map(|timestamp| timestamp.to_string()).unwrap_or_default());
+                println!("--------------------------------------------------------------------------------------------------------------------------------------");
+                println!("{:20} {:>10} {:>15} {:>12} {:>12} {:>12} {:>12} {:>10} query",
+                         "query_id",
+                         "calls",
+                         "total_time",
+                         "min_time",
+                         "max_time",
+                         "mean_time",
+                         "stddev_time",
+                         "rows");
+                println!("--------------------------------------------------------------------------------------------------------------------------------------");
+                previous_hostname_port = row.hostname_port.to_string();
+            };
+            let query = if query_id.is_some()
+            {
+                String::new()
+            }
+            else
+            {
+                let adaptive_length = if row.query.len() < sql_length { row.query.len() } else { sql_length };
+                row.query.substring(0, adaptive_length).escape_default().to_string()
+            };
+            println!("{:20} {:>10} {:>15.3} {:>12.3} {:>12.3} {:>12.3} {:>12.3} {:>10} {}",
+                     row.query_id.map(|query_id| query_id.to_string()).unwrap_or_default(),
+                     row.calls,
+                     row.total_time,
+                     row.min_time,
+                     row.max_time,
+                     row.mean_time,
+                     row.stddev_time,
+                     row.rows,
+                     query,
+            );
+            if query_id.is_some()
+            {
+                println!("{}", row.query);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl StatementsDiff {
@@ -109,6 +223,8 @@ impl StatementsDiff {
         allstatements.statements = snapshot::read_snapshot_json(end_snapshot, "statements")?;
         statementsdiff.second_snapshot(allstatements, begin_snapshot_time);
 
+        statementsdiff.restarts = Restarts::snapshot_diff(begin_snapshot, end_snapshot)?;
+
         Ok(statementsdiff)
     }
     fn first_snapshot(
@@ -121,13 +237,15 @@ impl StatementsDiff {
             for statement in statements.statements
             {
                 self.btreestatementsdiff
-                    .entry((statements.hostname_port.clone().expect("hostname:port should be set"), statement.query))
+                    .entry((statements.hostname_port.clone().expect("hostname:port should be set"), statement.grouping_query()))
                     .and_modify(|statementdiff| {
+                        statementdiff.query_id = statementdiff.query_id.or(statement.query_id);
                         statementdiff.first_calls += statement.calls;
                         statementdiff.first_total_time += statement.total_time;
                         statementdiff.first_rows += statement.rows;
                     })
                     .or_insert( GroupedStatements {
+                        query_id: statement.query_id,
                         first_snapshot_time: statements.timestamp.expect("timestamp should be set"),
                         first_calls: statement.calls,
                         first_total_time: statement.total_time,
@@ -149,13 +267,15 @@ impl StatementsDiff {
             for statement in statements.statements
             {
                 self.btreestatementsdiff
-                    .entry((statements.hostname_port.clone().expect("hostname:port should be set"), statement.query))
+                    .entry((statements.hostname_port.clone().expect("hostname:port should be set"), statement.grouping_query()))
                     .and_modify(|statementdiff| {
+                        statementdiff.query_id = statementdiff.query_id.or(statement.query_id);
                         statementdiff.second_calls += statement.calls;
                         statementdiff.second_total_time += statement.total_time;
                         statementdiff.second_rows += statement.rows;
                     })
                     .or_insert( GroupedStatements {
+                        query_id: statement.query_id,
                         first_snapshot_time: *begin_snapshot_time,
                         second_snapshot_time: statements.timestamp.expect("timestamp should be set"),
                         second_calls: statement.calls,
@@ -166,32 +286,199 @@ impl StatementsDiff {
             }
         }
     }
-    pub async fn print(
+    /// Create the rows for the statements that have been executed between the snapshots.
+    ///
+    /// This includes the statements that appeared between the snapshots, which are [Change::Added].
+    /// If zero_enable is set, the statements without calls between the snapshots are included too, with zero values.
+    pub fn rows(
         &self,
         hostname_filter: &Regex,
-        sql_length: usize,
-    )
+        zero_enable: &bool,
+    ) -> Vec<StatementsDiffRow>
     {
+        let mut rows = Vec::new();
         for ((hostname, query), statements_row) in &self.btreestatementsdiff
         {
+            // if the server restarted, or the calls went down, such as by pg_stat_statements_reset(), the statement is reset,
+            // and the second values are the difference.
+            let reset = self.restarts.is_restarted(hostname) || statements_row.second_calls < statements_row.first_calls;
+            let (calls, total_time, statement_rows) = if reset {
+                (statements_row.second_calls, statements_row.second_total_time, statements_row.second_rows)
+            } else {
+                (statements_row.second_calls - statements_row.first_calls, statements_row.second_total_time - statements_row.first_total_time, statements_row.second_rows - statements_row.first_rows)
+            };
             if hostname_filter.is_match(hostname)
-                && statements_row.second_calls - statements_row.first_calls != 0
+                && ((statements_row.second_calls != 0 && calls != 0) || *zero_enable)
             {
-                let adaptive_length = if query.len() < sql_length { query.len() } else { sql_length };
-                trace!("PRINT {}: second_calls: {}, first_calls: {}, query: {}", hostname, statements_row.second_calls, statements_row.first_calls, query.substring(0,adaptive_length).escape_default());
-                println!("{:20} {:10} avg: {:15.3} tot: {:15.3} ms avg: {:10} tot: {:10} rows: {:0adaptive_length$}",
-                         hostname,
-                         statements_row.second_calls - statements_row.first_calls,
-                         (statements_row.second_total_time - statements_row.first_total_time) / (statements_row.second_calls as f64 - statements_row.first_calls as f64),
-                         statements_row.second_total_time - statements_row.first_total_time,
-                         (statements_row.second_rows - statements_row.first_rows) / (statements_row.second_calls - statements_row.first_calls),
-                         statements_row.second_rows - statements_row.first_rows,
-                         query.substring(0, adaptive_length).escape_default()
-                );
+                trace!("ROW {}: second_calls: {}, first_calls: {}, query: {}", hostname, statements_row.second_calls, statements_row.first_calls, query.escape_default());
+                rows.push(StatementsDiffRow {
+                    change: Change::from_empty(statements_row.first_calls == 0, false),
+                    hostname_port: hostname.to_string(),
+                    query_id: statements_row.query_id,
+                    calls,
+                    calls_per_second: if calls != 0 { calls as f64 / (statements_row.second_snapshot_time - statements_row.first_snapshot_time).num_milliseconds() as f64 * 1000_f64 } else { 0_f64 },
+                    average_time_ms: if calls != 0 { total_time / calls as f64 } else { 0_f64 },
+                    total_time_ms: total_time,
+                    average_rows: statement_rows.checked_div(calls).unwrap_or_default(),
+                    rows: statement_rows,
+                    fingerprint: fingerprint(query),
+                    query: query.to_string(),
+                    reset,
+                });
             } else {
                 trace!("SKIP {}: second_calls: {}, first_calls: {}, query: {}", hostname, statements_row.second_calls, statements_row.first_calls, query.escape_default());
             }
         }
+        rows
+    }
+    /// Create the rows for the statements that disappeared between the snapshots, such as by pg_stat_statements_reset() or eviction,
+    /// with the values in the first snapshot.
+    pub fn disappeared_rows(
+        &self,
+        hostname_filter: &Regex,
+    ) -> Vec<StatementsDiffRow>
+    {
+        self.btreestatementsdiff.iter()
+            // pg_stat_statements can hold an entry without calls, which is not a statement that was executed.
+            .filter(|((hostname, _), statements_row)| hostname_filter.is_match(hostname)
+                && statements_row.first_calls != 0
+                && statements_row.second_calls == 0)
+            .map(|((hostname, query), statements_row)| StatementsDiffRow {
+                change: Change::Removed,
+                hostname_port: hostname.to_string(),
+                query_id: statements_row.query_id,
+                calls: statements_row.first_calls,
+                calls_per_second: 0_f64,
+                average_time_ms: statements_row.first_total_time / statements_row.first_calls as f64,
+                total_time_ms: statements_row.first_total_time,
+                average_rows: statements_row.first_rows / statements_row.first_calls,
+                rows: statements_row.first_rows,
+                fingerprint: fingerprint(query),
+                query: query.to_string(),
+                reset: self.restarts.is_restarted(hostname),
+            })
+            .collect()
+    }
+    pub async fn print(
+        &self,
+        hostname_filter: &Regex,
+        sql_length: usize,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let mut rows = self.rows(hostname_filter, &false);
+        rows.extend(self.disappeared_rows(hostname_filter));
+        if !output.is_table()
+        {
+            return output::print_rows(output, "statements", &rows);
+        }
+        if !self.restarts.servers.is_empty()
+        {
+            println!("restarted between the snapshots: {}, the statements of these are since the restart (reset)", self.restarts.list());
+        }
+        for (change, title) in [
+            (Change::Changed, ""),
+            (Change::Added, "appeared between the snapshots:"),
+            (Change::Removed, "disappeared between the snapshots, with the values in the first snapshot:"),
+        ]
+        {
+            let section: Vec<_> = rows.iter().filter(|row| row.change == change).collect();
+            if section.is_empty() { continue };
+            if !title.is_empty() { println!("{}", title) };
+            print_statements_diff_rows(&section, sql_length);
+        }
+        Ok(())
+    }
+    /// Create the rows of the top SQL report: the statements added up over all YSQL servers, sorted and limited to the top number.
+    ///
+    /// The statements are grouped by query_id, so the same statement executed via several YSQL servers is a single row.
+    /// If the query_id is not exposed, the statements are grouped by the normalized query text.
+    pub fn top_sql_rows(
+        &self,
+        hostname_filter: &Regex,
+        sort: &SqlSort,
+        top: Option<usize>,
+    ) -> Vec<TopSqlRow>
+    {
+        let mut grouped: BTreeMap<(Option<i64>, String), TopSqlRow> = BTreeMap::new();
+        for row in self.rows(hostname_filter, &false)
+        {
+            let key = match row.query_id {
+                Some(query_id) => (Some(query_id), String::new()),
+                None => (None, normalize_query(&row.query)),
+            };
+            let top_sql_row = grouped.entry(key)
+                .or_insert_with(|| TopSqlRow {
+                    query_id: row.query_id,
+                    fingerprint: row.fingerprint.clone(),
+                    query: row.query.clone(),
+                    ..Default::default()
+                });
+            top_sql_row.calls += row.calls;
+            top_sql_row.calls_per_second += row.calls_per_second;
+            top_sql_row.total_time_ms += row.total_time_ms;
+            top_sql_row.rows += row.rows;
+            top_sql_row.reset |= row.reset;
+            *top_sql_row.servers.entry(row.hostname_port).or_default() += row.calls;
+        }
+
+        let mut rows: Vec<TopSqlRow> = grouped.into_values()
+            .map(|row| TopSqlRow { mean_time_ms: row.total_time_ms / row.calls as f64, ..row })
+            .collect();
+        let figure = |row: &TopSqlRow| match sort {
+            SqlSort::TotalTime => row.total_time_ms,
+            SqlSort::Calls => row.calls as f64,
+            SqlSort::Rows => row.rows as f64,
+            SqlSort::MeanTime => row.mean_time_ms,
+        };
+        rows.sort_by(|a, b| figure(b).total_cmp(&figure(a)));
+        rows.truncate(top.unwrap_or(rows.len()));
+        for (rank, row) in rows.iter_mut().enumerate() {
+            row.rank = rank + 1;
+        }
+        rows
+    }
+    pub fn print_top_sql(
+        &self,
+        hostname_filter: &Regex,
+        sort: &SqlSort,
+        top: Option<usize>,
+        sql_length: usize,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let rows = self.top_sql_rows(hostname_filter, sort, top);
+        if !output.is_table()
+        {
+            return output::print_rows(output, "top_sql", &rows);
+        }
+        if !self.restarts.servers.is_empty()
+        {
+            println!("restarted between the snapshots: {}, the statements of these are since the restart (reset)", self.restarts.list());
+        }
+        println!("{:>4} {:>10} {:>10} {:>15} {:>12} {:>10} {:20} {:16} query", "rank", "calls", "calls/s", "total ms", "mean ms", "rows", "query_id", "fingerprint");
+        for row in &rows
+        {
+            let adaptive_length = if row.query.len() < sql_length { row.query.len() } else { sql_length };
+            println!("{:>4} {:>10} {:>10.3} {:>15.3} {:>12.3} {:>10} {:20} {:16} {}{}",
+                     row.rank,
+                     row.calls,
+                     row.calls_per_second,
+                     row.total_time_ms,
+                     row.mean_time_ms,
+                     row.rows,
+                     row.query_id.map(|query_id| query_id.to_string()).unwrap_or_default(),
+                     row.fingerprint,
+                     row.query.substring(0, adaptive_length).escape_default(),
+                     if row.reset { " reset" } else { "" },
+            );
+            let distribution = row.servers.iter()
+                .map(|(hostname_port, calls)| format!("{} {:.1}%", hostname_port, *calls as f64 / row.calls as f64 * 100_f64))
+                .collect::<Vec<_>>()
+                .join(", ");
+            println!("{:>4} {}", "", distribution);
+        }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
@@ -221,7 +508,7 @@ pub async fn statements_diff(
 ) -> Result<()>
 {
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
@@ -231,7 +518,74 @@ pub async fn statements_diff(
     let (begin_snapshot, end_snapshot, begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let statementsdiff = StatementsDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp)?;
-    statementsdiff.print(&hostname_filter, sql_length).await;
+    statementsdiff.print(&hostname_filter, sql_length, &options.output).await?;
+
+    Ok(())
+}
+
+/// Print the rows of a section of the statements diff.
+///
+/// The fingerprint is printed after the query text, labelled with `fp:`, and is left out if it's empty.
+fn print_statements_diff_rows(
+    rows: &[&StatementsDiffRow],
+    sql_length: usize,
+)
+{
+    for row in rows
+    {
+        let adaptive_length = if row.query.len() < sql_length { row.query.len() } else { sql_length };
+        println!("{:20} {:10} avg: {:15.3} tot: {:15.3} ms avg: {:10} tot: {:10} rows: {:0adaptive_length$}{}{}",
+                 row.hostname_port,
+                 row.calls,
+                 row.average_time_ms,
+                 row.total_time_ms,
+                 row.average_rows,
+                 row.rows,
+                 row.query.substring(0, adaptive_length).escape_default(),
+                 if row.fingerprint.is_empty() { String::new() } else { format!(" fp: {}", row.fingerprint) },
+                 if row.reset { " reset" } else { "" },
+        );
+    }
+}
+
+/// Print the statements for the given snapshot number, or get current.
+pub async fn print_statements(
+    hosts: Vec<&str>,
+    ports: Vec<&str>,
+    parallel: usize,
+    options: &Opts,
+) -> Result<()>
+{
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+    let sql_filter = utility::set_regex(&options.sql_match);
+    let allstatements = match options.print_statements.as_ref().unwrap() {
+        Some(snapshot_number) => {
+            let mut allstatements = AllStatements::new();
+            allstatements.statements = snapshot::read_snapshot_json(snapshot_number, "statements")?;
+            allstatements
+        },
+        None => AllStatements::read_statements(&hosts, &ports, parallel).await,
+    };
+    allstatements.print(&hostname_filter, &sql_filter, options.query_id, options.sql_length, &options.output)?;
+    Ok(())
+}
+
+/// Create the top SQL report between the begin and end snapshot, ordered by `--sql-sort`, for the top `--top-sql-limit` statements.
+pub async fn print_top_sql(
+    options: &Opts,
+) -> Result<()>
+{
+    if options.begin.is_none() || options.end.is_none() {
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
+    }
+    if options.snapshot_list { return Ok(()) };
+
+    let hostname_filter = utility::set_regex(&options.hostname_match);
+
+    let (begin_snapshot, end_snapshot, begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
+
+    let statementsdiff = StatementsDiff::snapshot_diff(&begin_snapshot, &end_snapshot, &begin_snapshot_row.timestamp)?;
+    statementsdiff.print_top_sql(&hostname_filter, &options.sql_sort, options.top_sql_limit, options.sql_length, &options.output)?;
 
     Ok(())
 }
@@ -338,6 +692,175 @@ mod tests {
         assert_eq!(result.statements[0].min_time, 13.76067);
     }
 
+    #[test]
+    fn unit_statements_rows_filtered() {
+        let json = r#"
+{
+  "statements": [
+    {
+      "query_id": -5860932178841992000,
+      "query": "select current_timestamp",
+      "calls": 4,
+      "total_time": 0.4,
+      "min_time": 0.05,
+      "max_time": 0.2,
+      "mean_time": 0.1,
+      "stddev_time": 0.06,
+      "rows": 4
+    },
+    {
+      "query_id": 1234,
+      "query": "insert into t values ($1)",
+      "calls": 1,
+      "total_time": 2.5,
+      "min_time": 2.5,
+      "max_time": 2.5,
+      "mean_time": 2.5,
+      "stddev_time": 0,
+      "rows": 1
+    }
+  ]
+}
+        "#.to_string();
+        let mut statements = AllStatements::parse_statements(json, "", "");
+        statements.hostname_port = Some("yb-1.local:13000".to_string());
+        let mut allstatements = AllStatements::new();
+        allstatements.statements.push(statements);
+        let all = Regex::new(".*").unwrap();
+
+        let rows = allstatements.rows(&all, &Regex::new("^select").unwrap(), None);
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].min_time, rows[0].max_time, rows[0].stddev_time), (0.05, 0.2, 0.06));
+        let rows = allstatements.rows(&all, &all, Some(1234));
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].query, "insert into t values ($1)");
+        assert!(allstatements.rows(&Regex::new("yb-2").unwrap(), &all, None).is_empty());
+    }
+
+    #[test]
+    fn unit_statements_rows_reset() {
+        let begin = Local::now();
+        let statement = |first_calls: i64, second_calls: i64| GroupedStatements {
+            query_id: None,
+            first_snapshot_time: begin,
+            first_calls,
+            first_total_time: first_calls as f64,
+            first_rows: first_calls,
+            second_snapshot_time: begin + chrono::Duration::seconds(10),
+            second_calls,
+            second_total_time: second_calls as f64,
+            second_rows: second_calls,
+        };
+        let mut diff = StatementsDiff::new();
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 1".to_string()), statement(10, 15));
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 2".to_string()), statement(10, 4));
+        let rows = diff.rows(&Regex::new(".*").unwrap(), &false);
+        assert_eq!((rows[0].calls, rows[0].reset), (5, false));
+        // the calls went down, such as by pg_stat_statements_reset(), so the second values are the difference.
+        assert_eq!((rows[1].calls, rows[1].rows, rows[1].reset), (4, 4, true));
+    }
+
+    #[test]
+    fn unit_statements_diff_grouped_by_normalized_query() {
+        // without query_id, the variants of a statement with different literals are a single statement.
+        let statements = |calls: i64, timestamp: DateTime<Local>| {
+            let json = format!(r#"{{"statements": [
+                {{"query": "select * from t where id = 1", "calls": {calls}, "total_time": 1.0, "min_time": 0.1, "max_time": 0.1, "mean_time": 0.1, "stddev_time": 0, "rows": 1}},
+                {{"query": "SELECT * FROM t WHERE id = 2", "calls": {calls}, "total_time": 1.0, "min_time": 0.1, "max_time": 0.1, "mean_time": 0.1, "stddev_time": 0, "rows": 1}}
+            ]}}"#);
+            let mut statements = AllStatements::parse_statements(json, "", "");
+            statements.hostname_port = Some("yb-1.local:13000".to_string());
+            statements.timestamp = Some(timestamp);
+            let mut allstatements = AllStatements::new();
+            allstatements.statements.push(statements);
+            allstatements
+        };
+        let begin = Local::now();
+        let mut diff = StatementsDiff::new();
+        diff.first_snapshot(statements(1, begin));
+        diff.second_snapshot(statements(3, begin + chrono::Duration::seconds(10)), &begin);
+        let rows = diff.rows(&Regex::new(".*").unwrap(), &false);
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].calls, rows[0].query.as_str()), (4, "select * from t where id = ?"));
+        assert_eq!(rows[0].fingerprint, fingerprint("select * from t where id = 3"));
+    }
+
+    #[test]
+    fn unit_statements_rows_appeared_and_disappeared() {
+        let begin = Local::now();
+        let statement = |first_calls: i64, second_calls: i64| GroupedStatements {
+            query_id: None,
+            first_snapshot_time: begin,
+            first_calls,
+            first_total_time: first_calls as f64,
+            first_rows: first_calls,
+            second_snapshot_time: begin + chrono::Duration::seconds(10),
+            second_calls,
+            second_total_time: second_calls as f64,
+            second_rows: second_calls,
+        };
+        let mut diff = StatementsDiff::new();
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 1".to_string()), statement(10, 15));
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 2".to_string()), statement(0, 3));
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 3".to_string()), statement(7, 0));
+        // an entry without calls, which pg_stat_statements creates at parse analysis, is not a statement that disappeared.
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 4".to_string()), statement(0, 0));
+        let all = Regex::new(".*").unwrap();
+
+        let rows = diff.rows(&all, &false);
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].change, rows[0].calls), (Change::Changed, 5));
+        assert_eq!((rows[1].change, rows[1].calls, rows[1].reset), (Change::Added, 3, false));
+        // the disappeared statement is not activity between the snapshots, and is reported with the first snapshot values.
+        let rows = diff.disappeared_rows(&all);
+        assert_eq!(rows.len(), 1);
+        assert_eq!((rows[0].change, rows[0].calls, rows[0].query.as_str()), (Change::Removed, 7, "select 3"));
+    }
+
+    fn grouped_statement(query_id: Option<i64>, calls: i64, total_time: f64) -> GroupedStatements {
+        let begin = Local::now();
+        GroupedStatements {
+            query_id,
+            first_snapshot_time: begin,
+            first_calls: 0,
+            first_total_time: 0_f64,
+            first_rows: 0,
+            second_snapshot_time: begin + chrono::Duration::seconds(10),
+            second_calls: calls,
+            second_total_time: total_time,
+            second_rows: calls,
+        }
+    }
+
+    #[test]
+    fn unit_top_sql_rows_grouped_by_query_id() {
+        let mut diff = StatementsDiff::new();
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 1".to_string()), grouped_statement(Some(1), 30, 60_f64));
+        // the same statement formatted differently on another server has the same query_id.
+        diff.btreestatementsdiff.insert(("yb-2.local:13000".to_string(), "select  1".to_string()), grouped_statement(Some(1), 10, 20_f64));
+        // without query_id, the statements are grouped by the query with the whitespace collapsed.
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select\n2".to_string()), grouped_statement(None, 5, 500_f64));
+        diff.btreestatementsdiff.insert(("yb-2.local:13000".to_string(), "select 2".to_string()), grouped_statement(None, 5, 500_f64));
+        let rows = diff.top_sql_rows(&Regex::new(".*").unwrap(), &SqlSort::TotalTime, None);
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].rank, rows[0].query_id, rows[0].calls, rows[0].total_time_ms, rows[0].mean_time_ms), (1, None, 10, 1000_f64, 100_f64));
+        assert_eq!((rows[1].rank, rows[1].query_id, rows[1].calls, rows[1].total_time_ms), (2, Some(1), 40, 80_f64));
+        assert_eq!(rows[1].servers.get("yb-1.local:13000"), Some(&30));
+        assert_eq!(rows[1].servers.get("yb-2.local:13000"), Some(&10));
+    }
+
+    #[test]
+    fn unit_top_sql_rows_sorted_and_limited() {
+        let mut diff = StatementsDiff::new();
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 1".to_string()), grouped_statement(Some(1), 100, 10_f64));
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 2".to_string()), grouped_statement(Some(2), 1, 50_f64));
+        diff.btreestatementsdiff.insert(("yb-1.local:13000".to_string(), "select 3".to_string()), grouped_statement(Some(3), 10, 20_f64));
+        let rows = diff.top_sql_rows(&Regex::new(".*").unwrap(), &SqlSort::Calls, Some(2));
+        assert_eq!(rows.iter().map(|row| row.query_id).collect::<Vec<_>>(), vec![Some(1), Some(3)]);
+        let rows = diff.top_sql_rows(&Regex::new(".*").unwrap(), &SqlSort::MeanTime, Some(1));
+        assert_eq!(rows.iter().map(|row| row.query_id).collect::<Vec<_>>(), vec![Some(2)]);
+    }
+
     #[tokio::test]
     async fn integration_parse_statements_ysql() {
         let hostname = utility::get_hostname_ysql();
@@ -2,6 +2,8 @@
 //!
 mod structs;
 mod functions;
+mod normalize;
 
 pub use structs::*;
 pub use functions::*;
+pub use normalize::*;
@@ -0,0 +1,137 @@
+//! Utility module for normalizing the SQL text of statements, and creating a fingerprint of it.
+//!
+//! Older YugabyteDB versions do not expose the query_id, and the text of a statement in `/statements` can still hold literals,
+//! such as for utility statements. The normalized text replaces the literals and parameters with `?`, collapses IN lists to `in (?)`,
+//! removes comments, collapses the whitespace into single spaces and lowercases the text outside of quoted identifiers.
+//! This makes the text of the same statement equal between servers, versions and snapshots.
+//! The fingerprint is a FNV-1a hash of the normalized text, which is stable between runs, so it can be used to find a statement
+//! when the text is truncated with `--sql-length`.
+use std::sync::OnceLock;
+use regex::Regex;
+
+/// Normalize the text of a statement.
+pub fn normalize_query(
+    query: &str,
+) -> String
+{
+    static IN_LIST: OnceLock<Regex> = OnceLock::new();
+    let in_list = IN_LIST.get_or_init(|| Regex::new(r"\bin ?\(\?(, \?)*\)").unwrap());
+
+    let mut normalized = String::with_capacity(query.len());
+    let mut characters = query.chars().peekable();
+    // a pending space is added before the next token, so the whitespace is collapsed, and not added at the start or end.
+    // inside parentheses and before a comma no space is added, and after a comma always, so lists are written the same.
+    let mut pending_space = false;
+    let push = |normalized: &mut String, text: &str, pending_space: &mut bool| {
+        let separate = normalized.ends_with(',') || (*pending_space && !normalized.is_empty() && !normalized.ends_with('('));
+        if separate && !text.starts_with([')', ',']) {
+            normalized.push(' ');
+        }
+        *pending_space = false;
+        normalized.push_str(text);
+    };
+    while let Some(character) = characters.next()
+    {
+        match character {
+            character if character.is_whitespace() => pending_space = true,
+            // a comment until the end of the line.
+            '-' if characters.peek() == Some(&'-') => {
+                for character in characters.by_ref() {
+                    if character == '\n' { break };
+                }
+                pending_space = true;
+            },
+            // a block comment.
+            '/' if characters.peek() == Some(&'*') => {
+                characters.next();
+                let mut previous = ' ';
+                for character in characters.by_ref() {
+                    if previous == '*' && character == '/' { break };
+                    previous = character;
+                }
+                pending_space = true;
+            },
+            // a string literal, in which a quote is escaped by doubling it.
+            '\'' => {
+                while let Some(character) = characters.next() {
+                    if character == '\'' {
+                        if characters.peek() == Some(&'\'') {
+                            characters.next();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                push(&mut normalized, "?", &mut pending_space);
+            },
+            // a quoted identifier is kept as is.
+            '"' => {
+                let mut identifier = String::from('"');
+                for character in characters.by_ref() {
+                    identifier.push(character);
+                    if character == '"' { break };
+                }
+                push(&mut normalized, &identifier, &mut pending_space);
+            },
+            // a parameter or a number, which is not part of an identifier.
+            '$' | '0'..='9' if !normalized.ends_with(|last: char| (last.is_alphanumeric() || last == '_') && !pending_space) => {
+                while characters.peek().is_some_and(|next| next.is_ascii_digit() || *next == '.') {
+                    characters.next();
+                }
+                push(&mut normalized, "?", &mut pending_space);
+            },
+            character if character.is_alphanumeric() || character == '_' || character == '$' => {
+                let mut word = character.to_lowercase().to_string();
+                while let Some(next) = characters.peek().filter(|next| next.is_alphanumeric() || **next == '_' || **next == '$') {
+                    word.extend(next.to_lowercase());
+                    characters.next();
+                }
+                push(&mut normalized, &word, &mut pending_space);
+            },
+            character => push(&mut normalized, &character.to_string(), &mut pending_space),
+        }
+    }
+    in_list.replace_all(&normalized, "in (?)").to_string()
+}
+
+/// Create the fingerprint of a statement: the 64 bits FNV-1a hash of the normalized text as hexadecimal number.
+pub fn fingerprint(
+    query: &str,
+) -> String
+{
+    let hash = normalize_query(query)
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    format!("{:016x}", hash)
+}
+
+/// These are the unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_normalize_query_literals_and_whitespace() {
+        assert_eq!(normalize_query("SELECT *\n  FROM t\tWHERE id = 42 AND name = 'it''s' -- comment\n"), "select * from t where id = ? and name = ?");
+        assert_eq!(normalize_query("select * from t where id = $1"), "select * from t where id = ?");
+        assert_eq!(normalize_query("select 1.5, col2 from t2 /* block\\n comment */ limit 10"), "select ?, col2 from t2 limit ?");
+        assert_eq!(normalize_query(r#"select "MixedCase" from "T 1""#), r#"select "MixedCase" from "T 1""#);
+    }
+
+    #[test]
+    fn unit_normalize_query_in_list() {
+        assert_eq!(normalize_query("select * from t where id in (1, 2,3)"), "select * from t where id in (?)");
+        assert_eq!(normalize_query("select * from t where id IN ( $1 , $2 )"), "select * from t where id in (?)");
+        assert_eq!(normalize_query("select * from t where id in(1)"), "select * from t where id in (?)");
+        assert_eq!(normalize_query("select * from t where id in (select id from t2)"), "select * from t where id in (select id from t2)");
+    }
+
+    #[test]
+    fn unit_fingerprint_is_stable() {
+        assert_eq!(fingerprint("select * from t where id in (1, 2, 3)"), fingerprint("SELECT * FROM t WHERE id IN ($1,$2)"));
+        assert_ne!(fingerprint("select * from t"), fingerprint("select * from t2"));
+        // the fingerprint must not change between versions, so it can be compared with earlier output.
+        assert_eq!(fingerprint(""), "cbf29ce484222325");
+        assert_eq!(fingerprint("select ?"), fingerprint("select 1"));
+    }
+}
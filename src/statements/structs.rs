@@ -2,6 +2,9 @@
 //!
 use chrono::{DateTime, Local};
 use std::collections::BTreeMap;
+use clap::ValueEnum;
+use crate::restarts::Restarts;
+use crate::output::Change;
 /// The root struct for deserializing `/statements`.
 ///
 /// This struct contains a single json object that holds a list:
@@ -42,6 +45,7 @@ pub struct Statements {
 /// - queryid
 /// - userid
 /// - dbid
+///
 /// As you can see from the fields, we do not expose userid and dbid at this time.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Statement {
@@ -55,6 +59,21 @@ pub struct Statement {
     pub stddev_time: f64,
     pub rows: i64,
 }
+/// A row of the statements print, with all the statistics of a statement of a YSQL server.
+#[derive(Debug, Serialize)]
+pub struct StatementRow {
+    pub hostname_port: String,
+    pub timestamp: Option<DateTime<Local>>,
+    pub query_id: Option<i64>,
+    pub calls: i64,
+    pub total_time: f64,
+    pub min_time: f64,
+    pub max_time: f64,
+    pub mean_time: f64,
+    pub stddev_time: f64,
+    pub rows: i64,
+    pub query: String,
+}
 /// Wrapper struct for holding the different statements structs
 #[derive(Debug, Default)]
 pub struct AllStatements {
@@ -62,19 +81,22 @@ pub struct AllStatements {
 }
 // diff
 /// BTreeMap for storing a statements diff struct per `hostname_port` and `query`.
+/// If the query_id is not exposed, the query is the normalized query text, so the variants of a statement with different literals are added up.
 ///
 /// This is not elegant not fully correct, but we are limited by the exposed fields.
 /// We need query_id, dbid, userid.
 /// And even if it would be available, it would take for all clients to get to that version.
 type BTreeStatementsDiff = BTreeMap<(String, String), GroupedStatements>;
-/// The wrapper struct for holding the btreemap holding the diff structs.
+/// The wrapper struct for holding the btreemap holding the diff structs, and the servers that restarted between the snapshots.
 #[derive(Debug, Default)]
 pub struct StatementsDiff {
     pub btreestatementsdiff: BTreeStatementsDiff,
+    pub restarts: Restarts,
 }
 /// The struct that holds the first and second snapshot statistics.
 #[derive(Debug, Default)]
 pub struct GroupedStatements {
+    pub query_id: Option<i64>,
     pub first_snapshot_time: DateTime<Local>,
     pub first_calls: i64,
     pub first_total_time: f64,
@@ -83,4 +105,54 @@ pub struct GroupedStatements {
     pub second_calls: i64,
     pub second_total_time: f64,
     pub second_rows: i64,
-}
\ No newline at end of file
+}
+/// A row of the statements diff report, used for the table and the machine readable output.
+///
+/// If the statement statistics are reset, because the server restarted or the calls went down, the figures are the values in the second snapshot, and `reset` is set.
+/// A statement that appeared between the snapshots is `added`, and its figures are the values in the second snapshot.
+/// A statement that disappeared between the snapshots is `removed`, and its figures are the values in the first snapshot.
+#[derive(Debug, Serialize)]
+pub struct StatementsDiffRow {
+    pub change: Change,
+    pub hostname_port: String,
+    pub query_id: Option<i64>,
+    pub calls: i64,
+    pub calls_per_second: f64,
+    pub average_time_ms: f64,
+    pub total_time_ms: f64,
+    pub average_rows: i64,
+    pub rows: i64,
+    /// The fingerprint of the normalized query text.
+    pub fingerprint: String,
+    pub query: String,
+    pub reset: bool,
+}
+/// The order of the top SQL report, set with `--sql-sort`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SqlSort {
+    /// The total time of the statement (default).
+    TotalTime,
+    Calls,
+    Rows,
+    /// The mean time of a call of the statement.
+    MeanTime,
+}
+/// A row of the top SQL report: a statement added up over all YSQL servers.
+///
+/// The statements are grouped by query_id, or by the normalized query text if the YSQL servers do not expose the query_id.
+#[derive(Debug, Default, Serialize)]
+pub struct TopSqlRow {
+    pub rank: usize,
+    pub query_id: Option<i64>,
+    pub calls: i64,
+    pub calls_per_second: f64,
+    pub total_time_ms: f64,
+    pub mean_time_ms: f64,
+    pub rows: i64,
+    /// The calls per YSQL server.
+    pub servers: BTreeMap<String, i64>,
+    pub reset: bool,
+    /// The fingerprint of the normalized query text.
+    pub fingerprint: String,
+    pub query: String,
+}
@@ -7,10 +7,11 @@ use log::*;
 use anyhow::Result;
 
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::table_detail::{AllTables, Column, Tablet, Table, TableBasic, TableDetail, Task};
+use crate::table_detail::{AllTables, TableDetailRow, Column, Tablet, Table, TableBasic, TableDetail, Task};
 use crate::Opts;
+use crate::output::{self, OutputFormat};
 
 impl Table {
     pub fn new() -> Self { Default::default() }
@@ -62,17 +63,21 @@ impl AllTables {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut tables = AllTables::read_http(host, port, extra_data);
-                        tables.timestamp = Some(detail_snapshot_time);
-                        tables.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(tables).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut tables = match AllTables::read_http(host, port, extra_data) {
+                        Ok(tables) => tables,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tables.timestamp = Some(detail_snapshot_time);
+                    tables.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(tables).expect("error sending data via tx");
+                });
             }
         });
 
@@ -91,15 +96,15 @@ impl AllTables {
         host: &str,
         port: &str,
         extra_data: &bool,
-    ) -> Table
+    ) -> Result<Table>
     {
-        let data_from_http = utility::http_get(host, port, "tables");
+        let data_from_http = utility::http_get(host, port, "tables").into_body()?;
         let mut table = AllTables::parse_tables(data_from_http);
         if *extra_data
         {
-            AllTables::parse_tables_add_detail(host, port, &mut table);
+            AllTables::parse_tables_add_detail(host, port, &mut table)?;
         }
-        table
+        Ok(table)
     }
     fn parse_tables(
         http_data: String
@@ -252,14 +257,15 @@ impl AllTables {
         host: &str,
         port: &str,
         tables: &mut Table
-    )
+    ) -> Result<()>
     {
         for row in tables.tablebasic.iter_mut()
         {
-            let data_from_http = utility::http_get(host, port, format!("table?id={}", row.uuid).as_str());
+            let data_from_http = utility::http_get(host, port, format!("table?id={}", row.uuid).as_str()).into_body()?;
             let detail = AllTables::parse_table_detail(data_from_http, &row.uuid);
             tables.tabledetail.push(Some(detail));
         }
+        Ok(())
     }
     fn parse_table_detail(
         data_from_http: String,
@@ -393,8 +399,23 @@ impl AllTables {
         &self,
         uuid: &str,
         leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.table.iter()
+                .filter(|alltables| alltables.hostname_port == Some(leader_hostname.clone()))
+                .flat_map(|alltables| alltables.tablebasic.iter()
+                    .filter(|row| row.uuid == *uuid)
+                    .map(|basic| TableDetailRow {
+                        hostname_port: alltables.hostname_port.as_ref().unwrap(),
+                        basic,
+                        detail: alltables.tabledetail.iter().flatten().find(|row| row.uuid == *uuid),
+                    }))
+                .collect();
+            return output::print_rows(output, "table_detail", &rows);
+        }
         for alltables in &self.table
         {
             if alltables.hostname_port != Some(leader_hostname.clone())
@@ -492,12 +513,12 @@ pub async fn print_table_detail(
             let mut alltables = AllTables::new();
             alltables.table = snapshot::read_snapshot_json(snapshot_number, "tables")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
-            alltables.print(&options.uuid, leader_hostname)?;
+            alltables.print(&options.uuid, leader_hostname, &options.output)?;
         },
         None => {
             let alltables = AllTables::read_tables(&hosts, &ports, parallel, &options.extra_data).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
-            alltables.print(&options.uuid, leader_hostname)?;
+            alltables.print(&options.uuid, leader_hostname, &options.output)?;
         },
     }
     Ok(())
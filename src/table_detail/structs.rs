@@ -69,3 +69,10 @@ pub struct Task {
     pub duration: String,
     pub description: String,
 }
+/// A row of the table detail report, used for the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct TableDetailRow<'a> {
+    pub hostname_port: &'a str,
+    pub basic: &'a TableBasic,
+    pub detail: Option<&'a TableDetail>,
+}
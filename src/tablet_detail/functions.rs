@@ -5,10 +5,11 @@ use std::{sync::mpsc::channel, time::Instant};
 use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::tablet_detail::{AllTablets, Tablet, TabletBasic, TabletDetail, Column, ConsensusStatus, Watermark, Message, TabletLogAnchor, Transactions, RocksDb, RocksDbFile};
+use crate::tablet_detail::{AllTablets, TabletDetailRow, Tablet, TabletBasic, TabletDetail, Column, ConsensusStatus, Watermark, Message, TabletLogAnchor, Transactions, RocksDb, RocksDbFile};
 use crate::Opts;
+use crate::output::{self, OutputFormat};
 
 impl Tablet {
     pub fn new() -> Self { Default::default() }
@@ -72,17 +73,21 @@ impl AllTablets {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut tablets = AllTablets::read_http(host, port, extra_data);
-                        tablets.timestamp = Some(detail_snapshot_time);
-                        tablets.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(tablets).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut tablets = match AllTablets::read_http(host, port, extra_data) {
+                        Ok(tablets) => tablets,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tablets.timestamp = Some(detail_snapshot_time);
+                    tablets.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(tablets).expect("error sending data via tx");
+                });
             }
         });
 
@@ -101,15 +106,15 @@ impl AllTablets {
         host: &str,
         port: &str,
         extra_data: &bool,
-    ) -> Tablet
+    ) -> Result<Tablet>
     {
-        let data_from_http = utility::http_get(host, port, "tablets");
+        let data_from_http = utility::http_get(host, port, "tablets").into_body()?;
         let mut tablet = AllTablets::parse_tablets(data_from_http);
         if *extra_data
         {
-            AllTablets::parse_tablets_add_detail(host, port, &mut tablet);
+            AllTablets::parse_tablets_add_detail(host, port, &mut tablet)?;
         }
-        tablet
+        Ok(tablet)
     }
     fn parse_tablets(
         http_data: String
@@ -231,31 +236,32 @@ impl AllTablets {
         host: &str,
         port: &str,
         tablets: &mut Tablet
-    )
+    ) -> Result<()>
     {
         for row in tablets.tabletbasic.iter_mut()
         {
-            let data_from_http = utility::http_get(host, port, format!("tablet?id={}", row.tablet_id).as_str());
+            let data_from_http = utility::http_get(host, port, format!("tablet?id={}", row.tablet_id).as_str()).into_body()?;
             // This construction is because a tablet that is still shown but tombstoned will not have any details.
             // The page will say 'Tablet <UUID> has not yet started'
             if let Ok(mut detail) = AllTablets::parse_tablet_detail(data_from_http, &row.tablet_id)
             {
-                let data_from_http = utility::http_get(host, port, format!("tablet-consensus-status?id={}", row.tablet_id).as_str());
+                let data_from_http = utility::http_get(host, port, format!("tablet-consensus-status?id={}", row.tablet_id).as_str()).into_body()?;
                 let consensus_status = AllTablets::parse_tablet_detail_consensus_status(data_from_http);
                 detail.consensus_status = consensus_status;
-                let data_from_http = utility::http_get(host, port, format!("log-anchors?id={}", row.tablet_id).as_str());
+                let data_from_http = utility::http_get(host, port, format!("log-anchors?id={}", row.tablet_id).as_str()).into_body()?;
                 let loganchor = AllTablets::parse_tablet_detail_log_anchors(data_from_http);
                 detail.tabletloganchor = loganchor;
-                let data_from_http = utility::http_get(host, port, format!("transactions?id={}", row.tablet_id).as_str());
+                let data_from_http = utility::http_get(host, port, format!("transactions?id={}", row.tablet_id).as_str()).into_body()?;
                 let transaction = AllTablets::parse_tablet_detail_transactions(data_from_http);
                 detail.transactions = transaction;
-                let data_from_http = utility::http_get(host, port, format!("rocksdb?id={}", row.tablet_id).as_str());
+                let data_from_http = utility::http_get(host, port, format!("rocksdb?id={}", row.tablet_id).as_str()).into_body()?;
                 let rocksdb = AllTablets::parse_tablet_detail_rocksdb(data_from_http);
                 detail.rocksdb = rocksdb;
                 //detail
                 tablets.tabletdetail.push(Some(detail));
             };
         }
+        Ok(())
     }
     fn parse_tablet_detail(
         data_from_http: String,
@@ -490,8 +496,22 @@ impl AllTablets {
     pub fn print(
         &self,
         uuid: &str,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.tablet.iter()
+                .flat_map(|alltablets| alltablets.tabletbasic.iter()
+                    .filter(|row| row.tablet_id == *uuid)
+                    .map(|basic| TabletDetailRow {
+                        hostname_port: alltablets.hostname_port.as_ref().unwrap(),
+                        basic,
+                        detail: alltablets.tabletdetail.iter().flatten().find(|row| row.tablet_id == *uuid),
+                    }))
+                .collect();
+            return output::print_rows(output, "tablet_detail", &rows);
+        }
         for alltablets in &self.tablet
         {
             for (keyspace, table_name, on_disk_size, state) in alltablets.tabletbasic.iter()
@@ -619,11 +639,11 @@ pub async fn print_tablet_detail(
         Some(snapshot_number) => {
             let mut alltablets = AllTablets::new();
             alltablets.tablet = snapshot::read_snapshot_json(snapshot_number, "tablets")?;
-            alltablets.print(&options.uuid)?;
+            alltablets.print(&options.uuid, &options.output)?;
         },
         None => {
             let alltablets = AllTablets::read_tablets(&hosts, &ports, parallel, &options.extra_data).await;
-            alltablets.print(&options.uuid)?;
+            alltablets.print(&options.uuid, &options.output)?;
         },
     }
     Ok(())
@@ -97,3 +97,10 @@ pub struct RocksDbFile {
     pub filename: String,
     pub details: Vec<String>,
 }
+/// A row of the tablet detail report, used for the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct TabletDetailRow<'a> {
+    pub hostname_port: &'a str,
+    pub basic: &'a TabletBasic,
+    pub detail: Option<&'a TabletDetail>,
+}
@@ -5,7 +5,7 @@ use std::{sync::mpsc::channel, time::Instant};
 use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::tablet_replication::{AllTabletReplication, LeaderlessTablet, TabletReplication, UnderReplicatedTablets};
 
@@ -44,17 +44,21 @@ impl AllTabletReplication {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut tabletreplication = AllTabletReplication::read_http(host, port);
-                        tabletreplication.timestamp = Some(detail_snapshot_time);
-                        tabletreplication.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(tabletreplication).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut tabletreplication = match AllTabletReplication::read_http(host, port) {
+                        Ok(tabletreplication) => tabletreplication,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tabletreplication.timestamp = Some(detail_snapshot_time);
+                    tabletreplication.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(tabletreplication).expect("error sending data via tx");
+                });
             }
         });
 
@@ -72,10 +76,10 @@ impl AllTabletReplication {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> TabletReplication
+    ) -> Result<TabletReplication>
     {
-        let data_from_http = utility::http_get(host, port, "tablet-replication");
-        AllTabletReplication::parse_tablet_replication(data_from_http)
+        let data_from_http = utility::http_get(host, port, "tablet-replication").into_body()?;
+        Ok(AllTabletReplication::parse_tablet_replication(data_from_http))
     }
     fn parse_tablet_replication(
         http_data: String
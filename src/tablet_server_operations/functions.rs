@@ -6,10 +6,11 @@ use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
 use regex::Regex;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::tablet_server_operations::{AllOperations, Operations, Operation};
 use crate::Opts;
+use crate::output::{self, HostRow, OutputFormat};
 
 impl Operations {
     pub fn new() -> Self{ Default::default() }
@@ -46,17 +47,21 @@ impl AllOperations {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut operations = AllOperations::read_http(host, port);
-                        operations.timestamp = Some(detail_snapshot_time);
-                        operations.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(operations).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut operations = match AllOperations::read_http(host, port) {
+                        Ok(operations) => operations,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    operations.timestamp = Some(detail_snapshot_time);
+                    operations.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(operations).expect("error sending data via tx");
+                });
             }
         });
 
@@ -74,10 +79,10 @@ impl AllOperations {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Operations
+    ) -> Result<Operations>
     {
-        let data_from_http = utility::http_get(host, port, "operations");
-        AllOperations::parse_tablet_server_operations(data_from_http)
+        let data_from_http = utility::http_get(host, port, "operations").into_body()?;
+        Ok(AllOperations::parse_tablet_server_operations(data_from_http))
     }
     fn parse_tablet_server_operations(
         http_data: String
@@ -129,9 +134,20 @@ impl AllOperations {
     }
     pub fn print(
         &self,
-        hostname_filter: &Regex
+        hostname_filter: &Regex,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.operations.iter()
+                .filter(|row| hostname_filter.is_match(row.hostname_port.as_ref().unwrap()))
+                .flat_map(|operations| operations.tasks.iter()
+                    .flatten()
+                    .map(|task| HostRow { hostname_port: operations.hostname_port.as_ref().unwrap(), row: task }))
+                .collect();
+            return output::print_rows(output, "tablet_server_operations", &rows);
+        }
         for operations in self.operations.iter().filter(|row| hostname_filter.is_match(row.hostname_port.as_ref().unwrap()))
         {
                 for task in &operations.tasks
@@ -162,11 +178,11 @@ pub async fn print_operations(
         Some(snapshot_number) => {
             let mut alloperations = AllOperations::new();
             alloperations.operations = snapshot::read_snapshot_json(snapshot_number, "tablet_server_operations")?;
-            alloperations.print(&hostname_filter)?;
+            alloperations.print(&hostname_filter, &options.output)?;
         },
         None => {
             let alloperations = AllOperations::read_tablet_server_operations(&hosts, &ports, parallel).await;
-            alloperations.print(&hostname_filter)?;
+            alloperations.print(&hostname_filter, &options.output)?;
         },
     }
     Ok(())
@@ -6,9 +6,10 @@ use log::*;
 use colored::*;
 use anyhow::Result;
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::tablet_servers::{TabletServers, AllTabletServers, TabletServersDiff, TabletServersDiffFields};
+use crate::tablet_servers::{TabletServers, AllTabletServers, TabletServersDiff, TabletServersDiffFields, TabletServersDiffRow};
+use crate::output::{self, Change, OutputFormat};
 use crate::Opts;
 
 impl TabletServers {
@@ -50,18 +51,22 @@ impl AllTabletServers {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut tablet_servers = AllTabletServers::read_http(host, port);
-                        tablet_servers.tabletservers.iter_mut().for_each(|(_,v)| v.timestamp = Some(detail_snapshot_time));
-                        tablet_servers.tabletservers.iter_mut().for_each(|(_,v)| v.hostname_port = Some(format!("{}:{}", host, port)));
-                        tablet_servers.tabletservers.iter_mut().for_each(|(k,v)| v.tablet_server_hostname_port = Some(k.to_string()));
-                        tx.send(tablet_servers).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut tablet_servers = match AllTabletServers::read_http(host, port) {
+                        Ok(tablet_servers) => tablet_servers,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tablet_servers.tabletservers.iter_mut().for_each(|(_,v)| v.timestamp = Some(detail_snapshot_time));
+                    tablet_servers.tabletservers.iter_mut().for_each(|(_,v)| v.hostname_port = Some(format!("{}:{}", host, port)));
+                    tablet_servers.tabletservers.iter_mut().for_each(|(k,v)| v.tablet_server_hostname_port = Some(k.to_string()));
+                    tx.send(tablet_servers).expect("error sending data via tx");
+                });
             }
         });
 
@@ -82,10 +87,10 @@ impl AllTabletServers {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> TabletServers
+    ) -> Result<TabletServers>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/tablet-servers");
-        AllTabletServers::parse_tabletservers(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "api/v1/tablet-servers").into_body()?;
+        Ok(AllTabletServers::parse_tabletservers(data_from_http, host, port))
     }
     fn parse_tabletservers(
         http_data: String,
@@ -102,8 +107,16 @@ impl AllTabletServers {
         &self,
         details_enable: &bool,
         leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.tabletservers.iter()
+                .filter(|row| row.hostname_port == Some(leader_hostname.clone()) || *details_enable)
+                .collect();
+            return output::print_rows(output, "tablet_servers", &rows);
+        }
         for row in &self.tabletservers {
             // if details_enable is true then always continue
             // if details_enable is false, then hostname_port must be equal to leader_hostname,
@@ -275,15 +288,12 @@ impl TabletServersDiff {
         }
         debug!("{:#?}", self.btreetabletserversdiff);
     }
-    pub fn print(
+    /// Create the rows for the tablet servers that are changed or restarted between the snapshots.
+    pub fn rows(
         &self,
-    )
+    ) -> Vec<TabletServersDiffRow<'_>>
     {
-        if ! self.master_found
-        {
-            println!("Master leader was not found, skipping tablet servers diff.");
-            return;
-        }
+        let mut rows = Vec::new();
         for (hostname, status) in self.btreetabletserversdiff.iter() {
             // If first and second snapshot status fields are identical,
             // and the first_uptime is lesser than or equal to the second uptime,
@@ -297,11 +307,38 @@ impl TabletServersDiff {
             {
                 continue;
             }
-            if status.second_status == *""
+            rows.push(TabletServersDiffRow {
+                change: Change::from_empty(status.first_status.is_empty(), status.second_status.is_empty()),
+                tablet_server_hostname_port: hostname,
+                fields: status,
+            });
+        }
+        rows
+    }
+    pub fn print(
+        &self,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        if ! self.master_found
+        {
+            if output.is_table()
+            {
+                println!("Master leader was not found, skipping tablet servers diff.");
+            }
+            return Ok(());
+        }
+        let rows = self.rows();
+        if !output.is_table()
+        {
+            return output::print_rows(output, "tablet_servers_diff", &rows);
+        }
+        for TabletServersDiffRow { change, tablet_server_hostname_port: hostname, fields: status } in rows {
+            if change == Change::Removed
             {
                 println!("{} Tserver:  {}, status: {}, uptime: {} s", "-".to_string().red(), hostname, status.first_status, status.first_uptime_seconds);
             }
-            else if status.first_status == *""
+            else if change == Change::Added
             {
                 println!("{} Tserver:  {}, status: {}, uptime: {} s", "+".to_string().green(), hostname, status.second_status, status.second_uptime_seconds);
             }
@@ -326,6 +363,7 @@ impl TabletServersDiff {
                 };
             };
         }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
@@ -356,14 +394,14 @@ pub async fn tablet_servers_diff(
 ) -> Result<()>
 {
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
     let (begin_snapshot, end_snapshot, _begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let tabletserversdiff = TabletServersDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    tabletserversdiff.print();
+    tabletserversdiff.print(&options.output)?;
 
     Ok(())
 }
@@ -382,7 +420,7 @@ pub async fn print_tablet_servers(
             alltabletservers.tabletservers = snapshot::read_snapshot_json(snapshot_number, "tablet_servers")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
 
-            alltabletservers.print(&options.details_enable, leader_hostname)?;
+            alltabletservers.print(&options.details_enable, leader_hostname, &options.output)?;
 
         }
         None => {
@@ -390,7 +428,7 @@ pub async fn print_tablet_servers(
             let alltabletservers = AllTabletServers::read_tabletservers(&hosts, &ports, parallel).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
 
-            alltabletservers.print(&options.details_enable, leader_hostname)?;
+            alltabletservers.print(&options.details_enable, leader_hostname, &options.output)?;
 
         }
     }
@@ -2,6 +2,7 @@
 //!
 use chrono::{DateTime, Local};
 use std::collections::BTreeMap;
+use crate::output::Change;
 
 /// The root struct for deserializing `/api/v1/tablet-servers`
 ///
@@ -56,7 +57,7 @@ pub struct TabletServers {
 ///
 /// This seems to have the majority of the information from `src/yb/master/master_types.proto`
 /// But have additional fields?
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct TabletServer {
     /// yb_stats added to allow understanding the source host.
     pub hostname_port: Option<String>,
@@ -136,10 +137,18 @@ pub struct TabletServersDiff {
 /// What is severely missing is the sequence_id field, to see an actual restart.
 /// The code and protobuf definitions say this is available for the tablet sever
 /// (and is what is used for the determination of master restarts).
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct TabletServersDiffFields {
     pub first_status: String,
     pub first_uptime_seconds: u64,
     pub second_status: String,
     pub second_uptime_seconds: u64,
-}
\ No newline at end of file
+}
+/// A row of the tablet servers diff report, used for the table and the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct TabletServersDiffRow<'a> {
+    pub change: Change,
+    pub tablet_server_hostname_port: &'a str,
+    #[serde(flatten)]
+    pub fields: &'a TabletServersDiffFields,
+}
@@ -6,10 +6,11 @@ use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
 use crate::isleader::AllIsLeader;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::tasks::{AllTasks, TaskDetail, Tasks};
 use crate::Opts;
+use crate::output::{self, HostRow, OutputFormat};
 
 impl Tasks {
     pub fn new() -> Self{ Default::default() }
@@ -46,17 +47,21 @@ impl AllTasks {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut tasks = AllTasks::read_http(host, port);
-                        tasks.timestamp = Some(detail_snapshot_time);
-                        tasks.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(tasks).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut tasks = match AllTasks::read_http(host, port) {
+                        Ok(tasks) => tasks,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    tasks.timestamp = Some(detail_snapshot_time);
+                    tasks.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(tasks).expect("error sending data via tx");
+                });
             }
         });
 
@@ -74,10 +79,10 @@ impl AllTasks {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Tasks
+    ) -> Result<Tasks>
     {
-        let data_from_http = utility::http_get(host, port, "tasks");
-        AllTasks::parse_tasks(data_from_http)
+        let data_from_http = utility::http_get(host, port, "tasks").into_body()?;
+        Ok(AllTasks::parse_tasks(data_from_http))
     }
     fn parse_tasks(
         http_data: String
@@ -206,8 +211,19 @@ impl AllTasks {
         &self,
         details_enable: &bool,
         leader_hostname: String,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.tasks.iter()
+                .filter(|master_row| master_row.hostname_port == Some(leader_hostname.clone()) || *details_enable)
+                .flat_map(|master_row| master_row.tasks.iter()
+                    .flatten()
+                    .map(|task| HostRow { hostname_port: master_row.hostname_port.as_ref().unwrap(), row: task }))
+                .collect();
+            return output::print_rows(output, "master_tasks", &rows);
+        }
         for master_row in &self.tasks {
             if master_row.hostname_port != Some(leader_hostname.clone())
                 && !*details_enable
@@ -248,12 +264,12 @@ pub async fn print_tasks(
             let mut alltasks = AllTasks::new();
             alltasks.tasks = snapshot::read_snapshot_json(snapshot_number, "tasks")?;
             let leader_hostname = AllIsLeader::return_leader_snapshot(snapshot_number)?;
-            alltasks.print(&options.details_enable, leader_hostname)?;
+            alltasks.print(&options.details_enable, leader_hostname, &options.output)?;
         },
         None => {
             let alltasks = AllTasks::read_tasks(&hosts, &ports, parallel).await;
             let leader_hostname = AllIsLeader::return_leader_http(&hosts, &ports, parallel).await;
-            alltasks.print(&options.details_enable, leader_hostname)?;
+            alltasks.print(&options.details_enable, leader_hostname, &options.output)?;
         },
     }
     Ok(())
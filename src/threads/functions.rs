@@ -6,10 +6,11 @@ use regex::Regex;
 use scraper::{Html, Selector};
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
 use crate::threads::{Threads, AllThreads};
 use crate::Opts;
+use crate::output::{self, OutputFormat};
 
 impl AllThreads {
     pub fn new() -> Self { Default::default() }
@@ -43,17 +44,21 @@ impl AllThreads {
         let (tx, rx) = channel();
 
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut threads = AllThreads::read_http(host, port);
-                        threads.iter_mut().for_each(|r| r.timestamp = detail_snapshot_time);
-                        threads.iter_mut().for_each(|r| r.hostname_port = format!("{}:{}", host, port));
-                        tx.send(threads).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut threads = match AllThreads::read_http(host, port) {
+                        Ok(threads) => threads,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    threads.iter_mut().for_each(|r| r.timestamp = detail_snapshot_time);
+                    threads.iter_mut().for_each(|r| r.hostname_port = format!("{}:{}", host, port));
+                    tx.send(threads).expect("error sending data via tx");
+                });
             }
         });
 
@@ -74,10 +79,10 @@ impl AllThreads {
     fn read_http(
         host: &str,
         port: &str,
-    ) -> Vec<Threads>
+    ) -> Result<Vec<Threads>>
     {
-        let data_from_http = utility::http_get(host, port, "threadz?group=all");
-        AllThreads::parse_threads(data_from_http)
+        let data_from_http = utility::http_get(host, port, "threadz?group=all").into_body()?;
+        Ok(AllThreads::parse_threads(data_from_http))
     }
     fn parse_threads(
         http_data: String
@@ -184,9 +189,17 @@ impl AllThreads {
     }
     pub fn print(
         &self,
-        hostname_filter: &Regex
+        hostname_filter: &Regex,
+        output: &OutputFormat,
     ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.threads.iter()
+                .filter(|row| hostname_filter.is_match(&row.hostname_port))
+                .collect();
+            return output::print_rows(output, "threads", &rows);
+        }
         let mut previous_hostname_port = String::from("");
         for row in &self.threads
         {
@@ -226,11 +239,11 @@ pub async fn print_threads(
         Some(snapshot_number) => {
             let mut allthreads = AllThreads::new();
             allthreads.threads = snapshot::read_snapshot_json(snapshot_number, "threads")?;
-            allthreads.print(&hostname_filter)?;
+            allthreads.print(&hostname_filter, &options.output)?;
         },
         None => {
             let allthreads = AllThreads::read_threads(&hosts, &ports, parallel).await;
-            allthreads.print(&hostname_filter)?;
+            allthreads.print(&hostname_filter, &options.output)?;
         },
     }
     Ok(())
@@ -1,9 +1,10 @@
 //! Utilities
 //use port_scanner::scan_port_addr;
 use log::*;
-use std::{collections::HashMap, env, fs, io::Write, time::Duration};
-use anyhow::{Context, Result};
+use std::{collections::HashMap, env, fmt, fs, io::Write, path::PathBuf, str::FromStr, sync::{atomic::{AtomicBool, Ordering}, Mutex, OnceLock}, time::{Duration, Instant}};
+use anyhow::{bail, Context, Result};
 use regex::Regex;
+use reqwest::{blocking::Client, Certificate, Identity};
 //use qscan::{QScanner, QScanResult, QScanType, QscanTcpConnectState, QScanTcpConnectState};
 //use tokio::runtime::Runtime;
 
@@ -14,6 +15,23 @@ use crate::ACCEPT_INVALID_CERTS;
 use crate::DEFAULT_HOSTS;
 use crate::DEFAULT_PORTS;
 use crate::DEFAULT_PARALLEL;
+use crate::utility::{Auth, Endpoint, FetchErrorKind, FetchResult, HttpSettings, Role, Scheme, SnapshotSettings};
+use crate::Opts;
+
+/// The settings for reading the http endpoints, set by [set_http_settings].
+static HTTP_SETTINGS: OnceLock<HttpSettings> = OnceLock::new();
+/// The http client, which is created upon first use, using [HTTP_SETTINGS].
+static HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
+/// The scheme that worked per `hostname:port` for [Scheme::Auto].
+static AUTO_SCHEMES: OnceLock<Mutex<HashMap<String, Scheme>>> = OnceLock::new();
+/// The snapshot settings, set by [set_snapshot_settings].
+static SNAPSHOT_SETTINGS: OnceLock<SnapshotSettings> = OnceLock::new();
+/// The endpoint list, set by [set_endpoints]. If it's not set, all hosts and ports combinations are used.
+static ENDPOINTS: OnceLock<Vec<Endpoint>> = OnceLock::new();
+/// Are the fetch results recorded? See [start_fetch_results].
+static RECORD_FETCH_RESULTS: AtomicBool = AtomicBool::new(false);
+/// The recorded fetch results, taken with [take_fetch_results].
+static FETCH_RESULTS: Mutex<Vec<FetchResult>> = Mutex::new(Vec::new());
 
 /*
 /// Scan the given host and port combination to see if it's reachable.
@@ -56,34 +74,486 @@ pub fn scan_host_port(
 
  */
 
-/// Reads the http endpoint as specified by the caller, and returns the result as String.
+/// Reads the http endpoint as specified by the caller, and returns the [FetchResult].
+///
+/// If the endpoint cannot be read, the body is empty, and the error kind and error are set.
+/// The [FetchResult] with the status, latency and error, without the body, is recorded if recording is started with [start_fetch_results].
 pub fn http_get(
     host: &str,
     port: &str,
     url: &str,
-) -> String
+) -> FetchResult
+{
+    let result = http_fetch(host, port, url);
+    if RECORD_FETCH_RESULTS.load(Ordering::Relaxed) {
+        FETCH_RESULTS.lock().unwrap().push(FetchResult {
+            hostname_port: result.hostname_port.clone(),
+            endpoint: result.endpoint.clone(),
+            scheme: result.scheme.clone(),
+            status: result.status,
+            latency_ms: result.latency_ms,
+            error_kind: result.error_kind,
+            error: result.error.clone(),
+            body: String::new(),
+        });
+    }
+    result
+}
+
+/// Reads the http endpoint as specified by the caller, and returns the [FetchResult].
+///
+/// The scheme, certificates and authentication are taken from the settings set by [set_http_settings].
+pub fn http_fetch(
+    host: &str,
+    port: &str,
+    url: &str,
+) -> FetchResult
+{
+    let settings = HTTP_SETTINGS.get_or_init(HttpSettings::default);
+    let client = HTTP_CLIENT.get_or_init(|| settings.client());
+
+    match settings.scheme {
+        Scheme::Auto => {
+            let hostname_port = format!("{}:{}", host, port);
+            let auto_schemes = AUTO_SCHEMES.get_or_init(|| Mutex::new(HashMap::new()));
+            let known_scheme = auto_schemes.lock().unwrap().get(&hostname_port).copied();
+            match known_scheme {
+                Some(scheme) => settings.read_url(client, scheme, host, port, url),
+                None => {
+                    // try https first: a plain http endpoint fails the tls handshake fast.
+                    // with authentication, the credentials must not be sent over plain http, so there is no fall back.
+                    let schemes: &[Scheme] = if settings.auth == Auth::None { &[Scheme::Https, Scheme::Http] } else { &[Scheme::Https] };
+                    let mut result = FetchResult::default();
+                    for &scheme in schemes {
+                        result = settings.read_url(client, scheme, host, port, url);
+                        if result.status.is_some() {
+                            debug!("Auto scheme: {} uses {}", hostname_port, scheme.as_str());
+                            auto_schemes.lock().unwrap().insert(hostname_port, scheme);
+                            break;
+                        }
+                    }
+                    result
+                },
+            }
+        },
+        scheme => settings.read_url(client, scheme, host, port, url),
+    }
+}
+
+/// Start recording the fetch results of [http_get], and remove any results recorded before.
+pub fn start_fetch_results()
+{
+    FETCH_RESULTS.lock().unwrap().clear();
+    RECORD_FETCH_RESULTS.store(true, Ordering::Relaxed);
+}
+
+/// Stop recording the fetch results of [http_get], and return the recorded results.
+pub fn take_fetch_results() -> Vec<FetchResult>
 {
-    if let Ok(data_from_web_request) = reqwest::blocking::Client::builder()
-        .connect_timeout(Duration::from_millis(200))
-        .danger_accept_invalid_certs(ACCEPT_INVALID_CERTS)
-        .build()
-        .unwrap()
-        .get(format!("http://{}:{}/{}", host, port, url))
-        .send()
+    RECORD_FETCH_RESULTS.store(false, Ordering::Relaxed);
+    std::mem::take(&mut *FETCH_RESULTS.lock().unwrap())
+}
+
+impl FetchResult {
+    /// Returns the body, or an error if the endpoint could not be read.
+    ///
+    /// This allows the caller to tell a host that could not be read from a host that has no data.
+    /// A non-success http status is not an error: endpoints such as `api/v1/is-leader` return a non-success status with a body that is parsed,
+    /// and most endpoints only exist on some server types.
+    pub fn into_body(self) -> Result<String> {
+        match self.error_kind {
+            None | Some(FetchErrorKind::Status) => Ok(self.body),
+            Some(error_kind) => bail!("{}/{} could not be read, {:?}: {}", self.hostname_port, self.endpoint, error_kind, self.error.unwrap_or_default()),
+        }
+    }
+}
+
+impl Scheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https | Scheme::Auto => "https",
+        }
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = anyhow::Error;
+
+    fn from_str(scheme: &str) -> Result<Self>
     {
-        if ! &data_from_web_request.status().is_success()
-        {
-            debug!("Non success response: {}:{}/{} = {}", host, port, url, &data_from_web_request.status());
+        match scheme.to_lowercase().as_str() {
+            "http" => Ok(Scheme::Http),
+            "https" => Ok(Scheme::Https),
+            "auto" => Ok(Scheme::Auto),
+            _ => bail!("Invalid scheme: {}, use http, https or auto", scheme),
         }
-        else
+    }
+}
+
+impl HttpSettings {
+    /// Create the http client.
+    /// If a CA certificate bundle is set, the certificates of the endpoints are verified,
+    /// otherwise invalid certificates are accepted depending on [ACCEPT_INVALID_CERTS].
+    fn client(&self) -> Client
+    {
+        let mut builder = Client::builder()
+            .connect_timeout(Duration::from_millis(200))
+            .danger_accept_invalid_certs(ACCEPT_INVALID_CERTS && self.ca_certificate.is_none());
+        if let Some(ca_certificate) = &self.ca_certificate {
+            builder = builder.add_root_certificate(ca_certificate.clone());
+        }
+        if let Some(identity) = &self.identity {
+            builder = builder.identity(identity.clone());
+        }
+        builder.build().expect("The http client settings should be valid")
+    }
+    /// Read a single url with the given scheme.
+    /// A non-success response still returns the body.
+    fn read_url(
+        &self,
+        client: &Client,
+        scheme: Scheme,
+        host: &str,
+        port: &str,
+        url: &str,
+    ) -> FetchResult
+    {
+        let timer = Instant::now();
+        let mut result = FetchResult {
+            hostname_port: format!("{}:{}", host, port),
+            endpoint: url.to_string(),
+            scheme: scheme.as_str().to_string(),
+            ..Default::default()
+        };
+        let mut request = client.get(format!("{}://{}:{}/{}", scheme.as_str(), host, port, url));
+        request = match &self.auth {
+            Auth::None => request,
+            Auth::Basic { user, password } => request.basic_auth(user, password.as_ref()),
+            Auth::Bearer(token) => request.bearer_auth(token),
+        };
+        match request.send()
         {
-           debug!("Success response: {}:{}/{} = {}", host, port, url, &data_from_web_request.status());
+            Ok(data_from_web_request) => {
+                result.status = Some(data_from_web_request.status().as_u16());
+                if ! &data_from_web_request.status().is_success()
+                {
+                    debug!("Non success response: {}://{}:{}/{} = {}", scheme.as_str(), host, port, url, &data_from_web_request.status());
+                    result.error_kind = Some(FetchErrorKind::Status);
+                    result.error = Some(data_from_web_request.status().to_string());
+                }
+                else
+                {
+                    debug!("Success response: {}://{}:{}/{} = {}", scheme.as_str(), host, port, url, &data_from_web_request.status());
+                }
+                match data_from_web_request.text() {
+                    Ok(body) => result.body = body,
+                    Err(error) => {
+                        result.error_kind = Some(FetchErrorKind::Body);
+                        result.error = Some(error.to_string());
+                    },
+                }
+            },
+            Err(error) => {
+                debug!("Non-Ok success response: {}://{}:{}/{}: {}", scheme.as_str(), host, port, url, error);
+                result.error_kind = Some(if error.is_timeout() {
+                    FetchErrorKind::Timeout
+                } else if error.is_connect() {
+                    FetchErrorKind::Connect
+                } else {
+                    FetchErrorKind::Request
+                });
+                result.error = Some(error.to_string());
+            },
         }
-        data_from_web_request.text().unwrap()
+        result.latency_ms = timer.elapsed().as_secs_f64() * 1000.0;
+        result
+    }
+}
+
+/// Take the http settings from the [Opts] struct or the '.env' file, and:
+/// - adds them to the changed_options hashmap if necessary.
+/// - reads the certificate files.
+/// - sets the settings used by [http_get].
+///
+/// The settings are:
+/// - `--scheme` / YBSTATS_SCHEME: http (default), https or auto.
+/// - `--ca-cert` / YBSTATS_CA_CERT: PEM CA certificate bundle to verify the endpoint certificates.
+/// - `--client-cert` / YBSTATS_CLIENT_CERT and `--client-key` / YBSTATS_CLIENT_KEY: PEM client certificate and PKCS#8 PEM key.
+/// - `--auth-user` / YBSTATS_AUTH_USER and `--auth-password` / YBSTATS_AUTH_PASSWORD: basic authentication.
+/// - `--auth-token` / YBSTATS_AUTH_TOKEN: bearer authentication.
+///
+/// The password and token are not added to the changed_options hashmap, so these are never written to '.env'.
+pub fn set_http_settings(
+    options: &Opts,
+    changed_options: &mut HashMap<&str, String>,
+) -> Result<()>
+{
+    let scheme = match set_option(&options.scheme, "YBSTATS_SCHEME", changed_options) {
+        Some(scheme) => Scheme::from_str(&scheme)?,
+        None => Scheme::default(),
+    };
+    let ca_certificate = match set_option(&options.ca_cert, "YBSTATS_CA_CERT", changed_options) {
+        Some(ca_cert) => {
+            let pem = fs::read(&ca_cert)
+                .with_context(|| format!("Error reading CA certificate file: {}", ca_cert))?;
+            Some(Certificate::from_pem(&pem)
+                .with_context(|| format!("Error parsing CA certificate file: {}", ca_cert))?)
+        },
+        None => None,
+    };
+    let identity = match (
+        set_option(&options.client_cert, "YBSTATS_CLIENT_CERT", changed_options),
+        set_option(&options.client_key, "YBSTATS_CLIENT_KEY", changed_options),
+    ) {
+        (Some(client_cert), Some(client_key)) => {
+            let certificate_pem = fs::read(&client_cert)
+                .with_context(|| format!("Error reading client certificate file: {}", client_cert))?;
+            let key_pem = fs::read(&client_key)
+                .with_context(|| format!("Error reading client key file: {}", client_key))?;
+            Some(Identity::from_pkcs8_pem(&certificate_pem, &key_pem)
+                .with_context(|| format!("Error parsing client certificate: {} and key: {}", client_cert, client_key))?)
+        },
+        (None, None) => None,
+        _ => bail!("A client certificate requires both --client-cert and --client-key"),
+    };
+    let auth = match (
+        set_option(&options.auth_user, "YBSTATS_AUTH_USER", changed_options),
+        secret_option(&options.auth_password, "YBSTATS_AUTH_PASSWORD"),
+        secret_option(&options.auth_token, "YBSTATS_AUTH_TOKEN"),
+    ) {
+        (None, None, None) => Auth::None,
+        (Some(user), password, None) => Auth::Basic { user, password },
+        (None, None, Some(token)) => Auth::Bearer(token),
+        (None, Some(_), _) => bail!("A basic authentication password requires --auth-user"),
+        (_, _, Some(_)) => bail!("Use either basic authentication (--auth-user) or bearer authentication (--auth-token), not both"),
+    };
+
+    if HTTP_SETTINGS.set(HttpSettings { scheme, ca_certificate, identity, auth }).is_err() {
+        bail!("The http settings are already set");
+    }
+    Ok(())
+}
+
+/// Take the snapshot settings from the [Opts] struct, or if these are not set, from the environment (via '.env'), and:
+/// - adds these to the changed_options hashmap if these are set.
+/// - sets the settings used by [snapshot_directory] and [snapshot_cluster].
+///
+/// The settings are:
+/// - `--snapshot-dir` / YBSTATS_SNAPSHOT_DIR: the directory to store the snapshots, by default yb_stats.snapshots in the current directory.
+///   A relative directory is taken relative to the current directory.
+/// - `--cluster` / YBSTATS_CLUSTER: the cluster name to save new snapshots with, so one snapshot directory can hold several clusters.
+pub fn set_snapshot_settings(
+    options: &Opts,
+    changed_options: &mut HashMap<&str, String>,
+) -> Result<()>
+{
+    let current_directory = env::current_dir()
+        .with_context(|| "Cannot evaluate current working directory" )?;
+    let directory = match set_option(&options.snapshot_dir, "YBSTATS_SNAPSHOT_DIR", changed_options) {
+        Some(directory) => current_directory.join(directory),
+        None => current_directory.join("yb_stats.snapshots"),
+    };
+    let cluster = set_option(&options.cluster, "YBSTATS_CLUSTER", changed_options).unwrap_or_default();
+    info!("snapshot directory: {}, cluster: {}", directory.display(), cluster);
+
+    if SNAPSHOT_SETTINGS.set(SnapshotSettings { directory, cluster }).is_err() {
+        bail!("The snapshot settings are already set");
+    }
+    Ok(())
+}
+
+/// Return the directory holding the snapshots, set by [set_snapshot_settings].
+/// If it's not set, it's yb_stats.snapshots in the current directory.
+pub fn snapshot_directory() -> Result<PathBuf>
+{
+    match SNAPSHOT_SETTINGS.get() {
+        Some(settings) => Ok(settings.directory.clone()),
+        None => Ok(env::current_dir()
+            .with_context(|| "Cannot evaluate current working directory" )?
+            .join("yb_stats.snapshots")),
+    }
+}
+
+/// Return the cluster name to save new snapshots with, set by [set_snapshot_settings].
+pub fn snapshot_cluster() -> String
+{
+    SNAPSHOT_SETTINGS.get()
+        .map(|settings| settings.cluster.clone())
+        .unwrap_or_default()
+}
+
+/// Take the statistics file from the [Opts] struct, or if it's not set, from the environment (via '.env'), and:
+/// - adds it to the changed_options hashmap if it's set.
+/// - returns it, to be applied to the statistic catalog with [crate::metrics::set_statistic_catalog].
+pub fn set_statistics_file(
+    options: &Opts,
+    changed_options: &mut HashMap<&str, String>,
+) -> Option<String>
+{
+    set_option(&options.statistics_file, "YBSTATS_STATISTICS_FILE", changed_options)
+}
+
+/// Take an optional setting from the [Opts] struct, or if it's not set, from the environment (via '.env'), and:
+/// - adds it to the changed_options hashmap if it's set.
+/// - returns the setting.
+fn set_option(
+    option: &Option<String>,
+    name: &'static str,
+    changed_options: &mut HashMap<&str, String>,
+) -> Option<String>
+{
+    let setting = match option {
+        Some(value) => {
+            // the value is not logged, because it can be a password or token.
+            info!("{} argument set", name);
+            Some(value.clone())
+        },
+        None => env::var(name).ok(),
+    };
+    if let Some(value) = &setting {
+        changed_options.insert(name, value.clone());
+    }
+    setting
+}
+
+/// Take a secret setting from the option, or if it's not set, from the environment.
+/// Unlike [set_option], the setting is not added to changed_options, so it's not written to '.env'.
+fn secret_option(
+    option: &Option<String>,
+    name: &'static str,
+) -> Option<String>
+{
+    match option {
+        Some(value) => {
+            info!("{} argument set", name);
+            Some(value.clone())
+        },
+        None => env::var(name).ok(),
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Role::Master => write!(f, "master"),
+            Role::Tserver => write!(f, "tserver"),
+            Role::Ysql => write!(f, "ysql"),
+            Role::Ycql => write!(f, "ycql"),
+            Role::NodeExporter => write!(f, "node_exporter"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    fn from_str(role: &str) -> Result<Self>
+    {
+        match role {
+            "master" => Ok(Role::Master),
+            "tserver" => Ok(Role::Tserver),
+            "ysql" => Ok(Role::Ysql),
+            "ycql" => Ok(Role::Ycql),
+            "node_exporter" => Ok(Role::NodeExporter),
+            _ => bail!("Invalid role: {}, use master, tserver, ysql, ycql or node_exporter", role),
+        }
+    }
+}
+
+impl Endpoint {
+    pub fn new(
+        host: &str,
+        port: &str,
+        role: Role,
+    ) -> Self
+    {
+        Endpoint { host: host.to_string(), port: port.to_string(), role }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.host, self.port, self.role)
+    }
+}
+
+impl FromStr for Endpoint {
+    type Err = anyhow::Error;
+
+    /// Parse `host:port:role`. The split is done from the end, so an IPv6 address keeps its colons.
+    fn from_str(endpoint: &str) -> Result<Self>
+    {
+        let mut fields = endpoint.trim().rsplitn(3, ':');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(role), Some(port), Some(host)) if !host.is_empty() && !port.is_empty() => Ok(Endpoint::new(host, port, Role::from_str(role)?)),
+            _ => bail!("Invalid endpoint: {}, use host:port:role", endpoint),
+        }
+    }
+}
+
+/// Return the `hostname` and `port` combinations to read for an endpoint that is available on the given roles.
+///
+/// If an endpoint list is set with [set_endpoints], the endpoints with one of the roles are returned.
+/// Otherwise, all combinations of the hosts and ports are returned, because the role of a port is unknown.
+pub fn endpoints<'a>(
+    hosts: &'a [&'a str],
+    ports: &'a [&'a str],
+    roles: &[Role],
+) -> Vec<(&'a str, &'a str)>
+{
+    match ENDPOINTS.get() {
+        Some(endpoints) => select_endpoints(endpoints, roles),
+        None => hosts.iter()
+            .flat_map(|host| ports.iter().map(move |port| (*host, *port)))
+            .collect(),
+    }
+}
+
+/// Select the unique `hostname` and `port` combinations of the endpoints with one of the roles.
+fn select_endpoints<'a>(
+    endpoints: &'a [Endpoint],
+    roles: &[Role],
+) -> Vec<(&'a str, &'a str)>
+{
+    let mut selected: Vec<(&str, &str)> = Vec::new();
+    for endpoint in endpoints.iter().filter(|endpoint| roles.contains(&endpoint.role)) {
+        if !selected.contains(&(&endpoint.host, &endpoint.port)) {
+            selected.push((&endpoint.host, &endpoint.port));
+        }
+    }
+    selected
+}
+
+/// Take the endpoints from the [Opts] struct or the '.env' file, and:
+/// - adds it to the changed_options hashmap if necessary.
+/// - sets the endpoint list used by [endpoints].
+///
+/// If `--endpoints` is not set, but `--hosts` or `--ports` is, the endpoints from '.env' are not used,
+/// and these are removed from '.env' by adding these to changed_options with an empty value.
+pub fn set_endpoints(
+    options: &Opts,
+    changed_options: &mut HashMap<&str, String>,
+) -> Result<()>
+{
+    let endpoints_string = if options.endpoints.is_none() && (options.hosts.is_some() || options.ports.is_some()) {
+        info!("hosts or ports argument set: not using endpoints");
+        changed_options.insert("YBSTATS_ENDPOINTS", String::new());
+        None
     } else {
-        debug!("Non-Ok success response: {}:{}/{}", host, port, url);
-        String::new()
+        set_option(&options.endpoints, "YBSTATS_ENDPOINTS", changed_options)
+    };
+    if let Some(endpoints_string) = endpoints_string {
+        let endpoints = endpoints_string.split(',')
+            .filter(|endpoint| !endpoint.trim().is_empty())
+            .map(Endpoint::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        info!("using {} endpoints", endpoints.len());
+        if ENDPOINTS.set(endpoints).is_err() {
+            bail!("The endpoints are already set");
+        }
     }
+    Ok(())
 }
 
 /// Take the hosts from the [Option] struct, and:
@@ -204,6 +674,27 @@ pub fn set_regex(
     }
 }
 
+/// Parse a duration such as `60s`, `5m`, `1h` or `7d`.
+/// A number without a unit is taken as seconds.
+pub fn parse_duration(
+    duration: &str,
+) -> Result<Duration>
+{
+    let duration = duration.trim();
+    let (number, multiplier) = match duration.chars().last() {
+        Some('s') => (&duration[..duration.len() - 1], 1),
+        Some('m') => (&duration[..duration.len() - 1], 60),
+        Some('h') => (&duration[..duration.len() - 1], 60 * 60),
+        Some('d') => (&duration[..duration.len() - 1], 60 * 60 * 24),
+        _ => (duration, 1),
+    };
+    let number: u64 = number.parse()
+        .with_context(|| format!("Invalid duration: {}, use a number with unit s, m, h or d", duration))?;
+    let seconds = number.checked_mul(multiplier)
+        .with_context(|| format!("Invalid duration: {}, the duration is too large", duration))?;
+    Ok(Duration::from_secs(seconds))
+}
+
 /// If writing the '.env' file is allowed via write_dotenv,
 /// take the changed_options hashmap, and write it.
 pub fn dotenv_writer(
@@ -213,18 +704,47 @@ pub fn dotenv_writer(
 {
     if !changed_options.is_empty() && write_dotenv {
         info!("Writing .env file");
-        let mut file = fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(".env")
+        write_dotenv_file(&PathBuf::from(".env"), changed_options)
             .with_context(|| "Error writing .env file in current directory")?;
+    }
+    Ok(())
+}
 
-        for (key, value) in changed_options {
-            file.write_all(format!("{}={}\n", key, value).as_bytes())?;
-            info!("{}={}", key, value);
+/// Write the changed_options to the dotenv file at path.
+///
+/// The lines of an existing file with a key that is not in changed_options are kept, such as the secrets
+/// YBSTATS_AUTH_PASSWORD and YBSTATS_AUTH_TOKEN, which are never in changed_options, and comments.
+/// A key in changed_options with an empty value is removed from the file.
+fn write_dotenv_file(
+    path: &PathBuf,
+    changed_options: HashMap<&str, String>,
+) -> Result<()>
+{
+    let existing = match fs::read_to_string(path) {
+        Ok(existing) => existing,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+    let mut contents = String::new();
+    for line in existing.lines() {
+        let key = line.split_once('=').map(|(key, _)| key.trim()).unwrap_or_default();
+        if !changed_options.contains_key(key) {
+            contents.push_str(line);
+            contents.push('\n');
         }
     }
+    let mut changed_options: Vec<_> = changed_options.into_iter().collect();
+    changed_options.sort();
+    for (key, value) in changed_options.into_iter().filter(|(_, value)| !value.is_empty()) {
+        contents.push_str(&format!("{}={}\n", key, value));
+        info!("{}={}", key, value);
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)?;
+    file.write_all(contents.as_bytes())?;
     Ok(())
 }
 
@@ -323,4 +843,102 @@ pub fn get_port_node_exporter() -> String {
         Ok(value) => value,
         Err(_e) => { panic!("The environment variable PORT_NODE_EXPORTER should be set") },
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_endpoint_from_str() {
+        assert_eq!(Endpoint::from_str("yb-1.local:7000:master").unwrap(), Endpoint::new("yb-1.local", "7000", Role::Master));
+        assert_eq!(Endpoint::from_str("fe80::1:9300:node_exporter").unwrap(), Endpoint::new("fe80::1", "9300", Role::NodeExporter));
+        assert_eq!(Endpoint::new("yb-1.local", "13000", Role::Ysql).to_string(), "yb-1.local:13000:ysql");
+        assert!(Endpoint::from_str("yb-1.local:7000").is_err());
+        assert!(Endpoint::from_str("yb-1.local:7000:yedis").is_err());
+    }
+
+    #[test]
+    fn unit_select_endpoints_by_role() {
+        let endpoints = vec![
+            Endpoint::new("yb-1.local", "7000", Role::Master),
+            Endpoint::new("yb-1.local", "9000", Role::Tserver),
+            Endpoint::new("yb-1.local", "13000", Role::Ysql),
+            Endpoint::new("yb-1.local", "9300", Role::NodeExporter),
+        ];
+        assert_eq!(select_endpoints(&endpoints, &[Role::Master, Role::Tserver]), vec![("yb-1.local", "7000"), ("yb-1.local", "9000")]);
+        assert_eq!(select_endpoints(&endpoints, &[Role::NodeExporter]), vec![("yb-1.local", "9300")]);
+    }
+
+    #[test]
+    fn unit_scheme_from_str() {
+        assert_eq!(Scheme::from_str("http").unwrap(), Scheme::Http);
+        assert_eq!(Scheme::from_str("HTTPS").unwrap(), Scheme::Https);
+        assert_eq!(Scheme::from_str("auto").unwrap(), Scheme::Auto);
+        assert!(Scheme::from_str("ftp").is_err());
+    }
+
+    #[test]
+    fn unit_set_option_argument_overrides_environment() {
+        env::set_var("YBSTATS_UNIT_TEST_OPTION_ARGUMENT", "environment");
+        let mut changed_options = HashMap::new();
+        let result = set_option(&Some("argument".to_string()), "YBSTATS_UNIT_TEST_OPTION_ARGUMENT", &mut changed_options);
+        assert_eq!(result, Some("argument".to_string()));
+        assert_eq!(changed_options["YBSTATS_UNIT_TEST_OPTION_ARGUMENT"], "argument");
+    }
+
+    #[test]
+    fn unit_set_option_from_environment() {
+        env::set_var("YBSTATS_UNIT_TEST_OPTION_ENVIRONMENT", "environment");
+        let mut changed_options = HashMap::new();
+        let result = set_option(&None, "YBSTATS_UNIT_TEST_OPTION_ENVIRONMENT", &mut changed_options);
+        assert_eq!(result, Some("environment".to_string()));
+        let result = set_option(&None, "YBSTATS_UNIT_TEST_OPTION_NOT_SET", &mut changed_options);
+        assert_eq!(result, None);
+        assert_eq!(changed_options.len(), 1);
+    }
+
+    #[test]
+    fn unit_write_dotenv_file_keeps_secrets() {
+        let path = env::temp_dir().join(format!("yb_stats_unit_dotenv_{}", std::process::id()));
+        fs::write(&path, "YBSTATS_HOSTS=old\nYBSTATS_AUTH_PASSWORD=secret\nYBSTATS_ENDPOINTS=yb-1.local:9000:tserver\n").unwrap();
+        let mut changed_options = HashMap::new();
+        changed_options.insert("YBSTATS_ENDPOINTS", String::new());
+        changed_options.insert("YBSTATS_HOSTS", "new".to_string());
+        changed_options.insert("YBSTATS_PORTS", "9000".to_string());
+        write_dotenv_file(&path, changed_options).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, "YBSTATS_AUTH_PASSWORD=secret\nYBSTATS_HOSTS=new\nYBSTATS_PORTS=9000\n");
+    }
+
+    #[test]
+    fn unit_parse_duration() {
+        assert_eq!(parse_duration("60").unwrap(), Duration::from_secs(60));
+        assert_eq!(parse_duration("60s").unwrap(), Duration::from_secs(60));
+        assert_eq!(parse_duration("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3600));
+        assert_eq!(parse_duration("7d").unwrap(), Duration::from_secs(604800));
+        assert!(parse_duration("7w").is_err());
+        assert!(parse_duration("999999999999999999d").is_err());
+        assert_eq!(parse_duration("18446744073709551615s").unwrap(), Duration::from_secs(u64::MAX));
+    }
+
+    #[test]
+    fn unit_http_get_returns_error_kind() {
+        start_fetch_results();
+        // nothing listens on port 1, so the connection is refused.
+        let result = http_get("127.0.0.1", "1", "metrics");
+        let recorded = take_fetch_results();
+        assert_eq!(result.error_kind, Some(FetchErrorKind::Connect));
+        assert!(result.body.is_empty());
+        assert_eq!(recorded.len(), 1);
+        assert_eq!((recorded[0].endpoint.as_str(), recorded[0].error_kind), ("metrics", Some(FetchErrorKind::Connect)));
+    }
+    #[test]
+    fn unit_fetch_result_into_body() {
+        let status = FetchResult { hostname_port: "yb-1:7000".to_string(), endpoint: "api/v1/is-leader".to_string(), status: Some(503), error_kind: Some(FetchErrorKind::Status), body: "{\"STATUS\":\"NOT LEADER\"}".to_string(), ..Default::default() };
+        assert_eq!(status.into_body().unwrap(), "{\"STATUS\":\"NOT LEADER\"}");
+        let failed = FetchResult { hostname_port: "yb-2:7000".to_string(), endpoint: "api/v1/is-leader".to_string(), error_kind: Some(FetchErrorKind::Connect), error: Some("connection refused".to_string()), ..Default::default() };
+        assert_eq!(failed.into_body().unwrap_err().to_string(), "yb-2:7000/api/v1/is-leader could not be read, Connect: connection refused");
+    }
+}
@@ -0,0 +1,96 @@
+//! The structs
+//!
+use std::path::PathBuf;
+use reqwest::{Certificate, Identity};
+/// The scheme used to read the http endpoints, set with `--scheme`.
+///
+/// With `Auto`, https is tried first, and if the endpoint cannot be read with https, http is tried.
+/// The scheme that worked is remembered per endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scheme {
+    #[default]
+    Http,
+    Https,
+    Auto,
+}
+/// The authentication used for reading the http endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Auth {
+    #[default]
+    None,
+    Basic {
+        user: String,
+        password: Option<String>,
+    },
+    Bearer(String),
+}
+/// The settings for reading the http endpoints, set once at startup.
+#[derive(Default)]
+pub struct HttpSettings {
+    pub scheme: Scheme,
+    /// CA certificate bundle to verify the endpoint certificates.
+    pub ca_certificate: Option<Certificate>,
+    /// Client certificate and key to authenticate to the endpoints.
+    pub identity: Option<Identity>,
+    pub auth: Auth,
+}
+/// The settings for storing snapshots, set once at startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotSettings {
+    /// The directory holding the snapshot.index and the snapshot directories, by default yb_stats.snapshots in the current directory.
+    pub directory: PathBuf,
+    /// The cluster name that new snapshots are saved with, which can be empty.
+    pub cluster: String,
+}
+/// The kind of error of reading an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FetchErrorKind {
+    /// The endpoint could not be connected to, such as a node that is down.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// The endpoint responded with a non-success http status, such as 404 for an endpoint that doesn't exist on the server type.
+    Status,
+    /// The response body could not be read.
+    Body,
+    /// Any other error sending the request.
+    Request,
+}
+/// The result of reading an endpoint, as returned by [http_fetch](crate::utility::http_fetch).
+///
+/// The results are saved per snapshot as the collection report (`collection_report.json`).
+/// The body is not saved in the collection report, the data is saved by the module that requested it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FetchResult {
+    pub hostname_port: String,
+    pub endpoint: String,
+    pub scheme: String,
+    pub status: Option<u16>,
+    pub latency_ms: f64,
+    pub error_kind: Option<FetchErrorKind>,
+    pub error: Option<String>,
+    #[serde(skip)]
+    pub body: String,
+}
+/// The role of an endpoint: the type of server that serves the http port.
+///
+/// Each module reads its endpoint only from the roles that provide it, see [endpoints](crate::utility::endpoints).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Master,
+    Tserver,
+    Ysql,
+    Ycql,
+    NodeExporter,
+}
+/// An endpoint: a `hostname:port` combination with its role.
+///
+/// The endpoint list is set with `--endpoints` as `host:port:role,host:port:role`, or written by `--discover`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    pub host: String,
+    pub port: String,
+    pub role: Role,
+}
@@ -6,9 +6,10 @@ use std::{sync::mpsc::channel, time::Instant};
 use log::*;
 use colored::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::vars::{AllVars, Vars, VarsDiff, VarsDiffFields};
+use crate::vars::{AllVars, Vars, VarsDiff, VarsDiffFields, VarsDiffRow};
+use crate::output::{self, Change, HostRow, OutputFormat};
 use crate::Opts;
 
 impl AllVars {
@@ -41,17 +42,21 @@ impl AllVars {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut vars = AllVars::read_http(host, port);
-                        vars.timestamp = Some(detail_snapshot_time);
-                        vars.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(vars).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut vars = match AllVars::read_http(host, port) {
+                        Ok(vars) => vars,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    vars.timestamp = Some(detail_snapshot_time);
+                    vars.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(vars).expect("error sending data via tx");
+                });
             }
         });
 
@@ -69,10 +74,10 @@ impl AllVars {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Vars
+    ) -> Result<Vars>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/varz");
-        AllVars::parse_vars(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "api/v1/varz").into_body()?;
+        Ok(AllVars::parse_vars(data_from_http, host, port))
     }
     fn parse_vars(
         http_data: String,
@@ -91,8 +96,20 @@ impl AllVars {
         details_enable: &bool,
         hostname_filter: &Regex,
         stat_name_filter: &Regex,
-    )
+        output: &OutputFormat,
+    ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.vars.iter()
+                .filter(|host_entry| hostname_filter.is_match(host_entry.hostname_port.as_ref().expect("hostname:port should be set")))
+                .flat_map(|host_entry| host_entry.flags.iter()
+                    .filter(|flag| stat_name_filter.is_match(&flag.name)
+                        && (flag.vars_type != *"Default" || *details_enable))
+                    .map(|flag| HostRow { hostname_port: host_entry.hostname_port.as_ref().expect("hostname:port should be set"), row: flag }))
+                .collect();
+            return output::print_rows(output, "vars", &rows);
+        }
         for host_entry in self.vars.iter()
         {
             if hostname_filter.is_match(&host_entry.hostname_port.clone().expect("hostname:port should be set"))
@@ -115,6 +132,7 @@ impl AllVars {
                 };
             };
         };
+        Ok(())
     }
 }
 
@@ -180,39 +198,55 @@ impl VarsDiff {
             }
         }
     }
-    pub fn print(
+    /// Create the rows for the vars that are changed between the snapshots.
+    pub fn rows(
         &self,
-    )
+    ) -> Vec<VarsDiffRow<'_>>
     {
-        for ((hostname_port, name), row) in self.btreevarsdiff.iter() {
-            if row.first_value == row.second_value
+        self.btreevarsdiff.iter()
+            .filter(|(_, row)| !(row.first_value == row.second_value
                 && row.first_vars_type == row.second_vars_type
                 || row.first_value.is_empty()
-                || row.second_value.is_empty()
+                || row.second_value.is_empty()))
+            .map(|((hostname_port, name), row)| VarsDiffRow {
+                change: Change::Changed,
+                hostname_port,
+                name,
+                fields: row,
+            })
+            .collect()
+    }
+    pub fn print(
+        &self,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let rows = self.rows();
+        if !output.is_table()
+        {
+            return output::print_rows(output, "vars_diff", &rows);
+        }
+        for VarsDiffRow { hostname_port, name, fields: row, .. } in rows
+        {
+            print!("{} {:20} Vars: {:50} ", "=".to_string().yellow(), hostname_port, name);
+            if row.first_value != row.second_value
             {
-                continue;
+                print!("{}->{} ", row.first_value.yellow(), row.second_value.yellow());
             }
             else
             {
-                print!("{} {:20} Vars: {:50} ", "=".to_string().yellow(), hostname_port, name);
-                if row.first_value != row.second_value
-                {
-                    print!("{}->{} ", row.first_value.yellow(), row.second_value.yellow());
-                }
-                else
-                {
-                    print!("{} ", row.second_value);
-                };
-                if row.first_vars_type != row.second_vars_type
-                {
-                    println!("{}->{}", row.first_vars_type.yellow(), row.second_vars_type.yellow());
-                }
-                else
-                {
-                    println!("{}", row.second_vars_type);
-                };
+                print!("{} ", row.second_value);
+            };
+            if row.first_vars_type != row.second_vars_type
+            {
+                println!("{}->{}", row.first_vars_type.yellow(), row.second_vars_type.yellow());
+            }
+            else
+            {
+                println!("{}", row.second_vars_type);
             };
         }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
@@ -241,14 +275,14 @@ pub async fn vars_diff(
 ) -> Result<()>
 {
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
     let (begin_snapshot, end_snapshot, _begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
 
     let varsdiff = VarsDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    varsdiff.print();
+    varsdiff.print(&options.output)?;
 
     Ok(())
 }
@@ -268,11 +302,11 @@ pub async fn print_vars(
 
             let mut allvars = AllVars::new();
             allvars.vars = snapshot::read_snapshot_json(snapshot_number, "vars")?;
-            allvars.print(&options.details_enable, &hostname_filter, &stat_name_filter).await;
+            allvars.print(&options.details_enable, &hostname_filter, &stat_name_filter, &options.output).await?;
         }
         None => {
             let allvars = AllVars::read_vars(&hosts, &ports, parallel).await;
-            allvars.print(&options.details_enable, &hostname_filter, &stat_name_filter).await;
+            allvars.print(&options.details_enable, &hostname_filter, &stat_name_filter, &options.output).await?;
         }
     }
     Ok(())
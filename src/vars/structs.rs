@@ -2,6 +2,7 @@
 //!
 use std::collections::BTreeMap;
 use chrono::{DateTime, Local};
+use crate::output::Change;
 /// The root struct for deserializing `/api/v1/varz`
 ///
 /// ```text
@@ -58,10 +59,19 @@ pub struct VarsDiff {
 ///
 /// The hostname:port and variable is the key of the btreemap,
 /// This struct holds the first and second value and type.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct VarsDiffFields {
     pub first_value: String,
     pub first_vars_type: String,
     pub second_value: String,
     pub second_vars_type: String,
 }
+/// A row of the vars diff report, used for the table and the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct VarsDiffRow<'a> {
+    pub change: Change,
+    pub hostname_port: &'a str,
+    pub name: &'a str,
+    #[serde(flatten)]
+    pub fields: &'a VarsDiffFields,
+}
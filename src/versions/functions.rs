@@ -6,9 +6,10 @@ use colored::Colorize;
 use regex::Regex;
 use log::*;
 use anyhow::Result;
-use crate::utility;
+use crate::utility::{self, Role};
 use crate::snapshot;
-use crate::versions::{Version, AllVersions, VersionsDiff, VersionsDiffFields};
+use crate::versions::{Version, AllVersions, VersionsDiff, VersionsDiffFields, VersionsDiffRow};
+use crate::output::{self, Change, OutputFormat};
 use crate::Opts;
 
 impl AllVersions {
@@ -43,17 +44,21 @@ impl AllVersions {
         let pool = rayon::ThreadPoolBuilder::new().num_threads(parallel).build().unwrap();
         let (tx, rx) = channel();
         pool.scope(move |s| {
-            for host in hosts {
-                for port in ports {
-                    let tx = tx.clone();
-                    s.spawn(move |_| {
-                        let detail_snapshot_time = Local::now();
-                        let mut version = AllVersions::read_http(host, port);
-                        version.timestamp = Some(detail_snapshot_time);
-                        version.hostname_port = Some(format!("{}:{}", host, port));
-                        tx.send(version).expect("error sending data via tx");
-                    });
-                }
+            for (host, port) in utility::endpoints(hosts, ports, &[Role::Master, Role::Tserver]) {
+                let tx = tx.clone();
+                s.spawn(move |_| {
+                    let detail_snapshot_time = Local::now();
+                    let mut version = match AllVersions::read_http(host, port) {
+                        Ok(version) => version,
+                        Err(error) => {
+                            warn!("{:#}", error);
+                            return;
+                        },
+                    };
+                    version.timestamp = Some(detail_snapshot_time);
+                    version.hostname_port = Some(format!("{}:{}", host, port));
+                    tx.send(version).expect("error sending data via tx");
+                });
             }
         });
 
@@ -72,10 +77,10 @@ impl AllVersions {
     pub fn read_http(
         host: &str,
         port: &str,
-    ) -> Version
+    ) -> Result<Version>
     {
-        let data_from_http = utility::http_get(host, port, "api/v1/version");
-        AllVersions::parse_version(data_from_http, host, port)
+        let data_from_http = utility::http_get(host, port, "api/v1/version").into_body()?;
+        Ok(AllVersions::parse_version(data_from_http, host, port))
     }
     fn parse_version(
         http_data: String,
@@ -92,8 +97,16 @@ impl AllVersions {
     pub fn print(
         &self,
         hostname_filter: &Regex,
-    )
+        output: &OutputFormat,
+    ) -> Result<()>
     {
+        if !output.is_table()
+        {
+            let rows: Vec<_> = self.versions.iter()
+                .filter(|row| hostname_filter.is_match(row.hostname_port.as_ref().expect("hostname:port should be set")))
+                .collect();
+            return output::print_rows(output, "versions", &rows);
+        }
         println!("{:20} {:15} {:10} {:10} {:24} {:10}",
                  "hostname_port",
                  "version_number",
@@ -114,6 +127,7 @@ impl AllVersions {
                 );
             }
         }
+        Ok(())
     }
 }
 
@@ -193,11 +207,13 @@ impl VersionsDiff {
                 });
         }
     }
-    pub fn print(
+    /// Create the rows for the servers of which the version is changed between the snapshots.
+    pub fn rows(
         &self,
         hostname_filter: &Regex,
-    )
+    ) -> Vec<VersionsDiffRow<'_>>
     {
+        let mut rows = Vec::new();
         for (hostname, row) in self.btreeversionsdiff.iter().filter(|(k,_v)| hostname_filter.is_match(k))
         {
             #[allow(clippy::nonminimal_bool)]
@@ -215,9 +231,29 @@ impl VersionsDiff {
                 debug!("equal, next server");
                 continue;
             }
-            // okay, first and second fields are not equal
+            rows.push(VersionsDiffRow {
+                change: Change::from_empty(row.first_git_hash.is_empty(), row.second_git_hash.is_empty()),
+                hostname_port: hostname,
+                fields: row,
+            });
+        }
+        rows
+    }
+    pub fn print(
+        &self,
+        hostname_filter: &Regex,
+        output: &OutputFormat,
+    ) -> Result<()>
+    {
+        let rows = self.rows(hostname_filter);
+        if !output.is_table()
+        {
+            return output::print_rows(output, "versions_diff", &rows);
+        }
+        for VersionsDiffRow { change, hostname_port: hostname, fields: row } in rows
+        {
             // is the a "first" entry empty, indicating it appeared between snapshots
-            else if row.first_git_hash.is_empty()
+            if change == Change::Added
             {
                 print!("{} Versions: {:20}", "+".to_string().green(), hostname);
                 println!("{} b{} {} {} {}",
@@ -229,7 +265,7 @@ impl VersionsDiff {
                 );
             }
             // is a "second" entry empty, indicating it disappeared between snapshots
-            else if row.second_git_hash.is_empty()
+            else if change == Change::Removed
             {
                 print!("{} Versions: {:20}", "-".to_string().red(), hostname);
                 println!("{} b{} {} {} {}",
@@ -286,6 +322,7 @@ impl VersionsDiff {
                 };
             }
         }
+        Ok(())
     }
     pub async fn adhoc_read_first_snapshot(
         &mut self,
@@ -316,7 +353,7 @@ pub async fn versions_diff(
     info!("versions diff");
 
     if options.begin.is_none() || options.end.is_none() {
-        snapshot::Snapshot::print()?;
+        snapshot::Snapshot::print(&utility::set_regex(&options.cluster_match), &options.output)?;
     }
     if options.snapshot_list { return Ok(()) };
 
@@ -324,7 +361,7 @@ pub async fn versions_diff(
 
     let (begin_snapshot, end_snapshot, _begin_snapshot_row) = snapshot::Snapshot::read_begin_end_snapshot_from_user(options.begin, options.end)?;
     let versions_diff = VersionsDiff::snapshot_diff(&begin_snapshot, &end_snapshot)?;
-    versions_diff.print(&hostname_filter);
+    versions_diff.print(&hostname_filter, &options.output)?;
 
     Ok(())
 }
@@ -344,11 +381,11 @@ pub async fn print_version(
             let mut allversions = AllVersions::new();
             allversions.versions = snapshot::read_snapshot_json(snapshot_number, "versions")?;
 
-            allversions.print(&hostname_filter);
+            allversions.print(&hostname_filter, &options.output)?;
         },
         None => {
             let allversions = AllVersions::read_versions(&hosts, &ports, parallel).await;
-            allversions.print(&hostname_filter);
+            allversions.print(&hostname_filter, &options.output)?;
         },
     }
     Ok(())
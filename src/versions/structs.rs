@@ -2,6 +2,7 @@
 //!
 use chrono::{DateTime, Local};
 use std::collections::BTreeMap;
+use crate::output::Change;
 /// The root struct for deserializing `/api/v1/version`.
 ///
 /// This struct is a very simple json object,
@@ -52,7 +53,7 @@ pub struct VersionsDiff {
 /// The versions diff struct.
 ///
 /// Every property above is listed as 'first' and 'second', to find the differences.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct VersionsDiffFields {
     pub first_git_hash: String,
     pub first_build_hostname: String,
@@ -73,3 +74,11 @@ pub struct VersionsDiffFields {
     pub second_version_number: String,
     pub second_build_number: String,
 }
+/// A row of the versions diff report, used for the table and the machine readable output.
+#[derive(Debug, Serialize)]
+pub struct VersionsDiffRow<'a> {
+    pub change: Change,
+    pub hostname_port: &'a str,
+    #[serde(flatten)]
+    pub fields: &'a VersionsDiffFields,
+}